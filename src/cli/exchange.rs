@@ -0,0 +1,475 @@
+//! Export and import commands
+//!
+//! Move records in and out of other tools' file formats, e.g. to show time in a
+//! calendar or to turn calendar meetings into records.
+
+use super::OutputFormat;
+use super::record::parse_date_arg;
+use crate::formats::{self, ProposedRecord, ics, timewarrior, toggl};
+use crate::models::{DayData, LocalZone};
+use crate::storage::{Storage, StorageManager};
+use anyhow::{Context, Result, anyhow};
+use clap::{Args, Subcommand};
+use serde::Serialize;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use time::{Date, OffsetDateTime};
+
+/// Export commands
+#[derive(Subcommand)]
+pub enum ExportCommands {
+    /// Export records as iCalendar (.ics) events
+    Ics {
+        #[command(flatten)]
+        range: RangeArgs,
+
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Export records as Timewarrior data file lines (tags = name + #tags)
+    Timewarrior {
+        #[command(flatten)]
+        range: RangeArgs,
+
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Write `timew export` JSON instead of data file lines
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Export records as a Toggl CSV import file (description = name)
+    Toggl {
+        #[command(flatten)]
+        range: RangeArgs,
+
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Write Toggl time entry JSON instead of CSV
+        #[arg(long)]
+        json: bool,
+
+        /// Your Toggl account email (required by Toggl's CSV importer)
+        #[arg(long, default_value = "")]
+        email: String,
+    },
+}
+
+/// Import commands
+#[derive(Subcommand)]
+pub enum ImportCommands {
+    /// Propose records for the events of an iCalendar (.ics) file
+    ///
+    /// Only previews the records unless --apply is given.
+    Ics {
+        /// Path to the .ics file
+        file: PathBuf,
+
+        #[command(flatten)]
+        range: RangeArgs,
+
+        /// Your calendar email: events you were invited to are only imported if accepted
+        #[arg(long)]
+        email: Option<String>,
+
+        /// Add the proposed records
+        #[arg(long)]
+        apply: bool,
+    },
+
+    /// Import Timewarrior intervals (data files or `timew export` JSON)
+    ///
+    /// Imports every finished interval unless a range is given. Only previews the records
+    /// unless --apply is given.
+    Timewarrior {
+        /// A .data file, a `timew export` JSON file, or a data directory
+        /// (e.g. ~/.timewarrior/data)
+        path: PathBuf,
+
+        #[command(flatten)]
+        range: RangeArgs,
+
+        /// Add the proposed records
+        #[arg(long)]
+        apply: bool,
+    },
+
+    /// Import Toggl time entries (CSV report or JSON export)
+    ///
+    /// Imports every finished entry unless a range is given. Only previews the records
+    /// unless --apply is given.
+    Toggl {
+        /// Path to the .csv or .json file
+        file: PathBuf,
+
+        #[command(flatten)]
+        range: RangeArgs,
+
+        /// Add the proposed records
+        #[arg(long)]
+        apply: bool,
+    },
+}
+
+/// `--from`/`--to` day range
+#[derive(Args)]
+pub struct RangeArgs {
+    /// First day (YYYY-MM-DD; defaults to today, or to all days for Timewarrior and
+    /// Toggl imports)
+    #[arg(long)]
+    from: Option<String>,
+
+    /// Last day (YYYY-MM-DD, defaults to --from)
+    #[arg(long)]
+    to: Option<String>,
+}
+
+/// Handle an export subcommand
+pub fn handle_export_command(cmd: ExportCommands, storage: Storage) -> Result<()> {
+    let zone = LocalZone::System;
+    match cmd {
+        ExportCommands::Ics { range, output } => {
+            let (from, to) = range.days()?;
+            let days = storage.load_range(from, to)?;
+            let ics = ics::export(&days, OffsetDateTime::now_utc());
+            write_output(&ics, output.as_deref())
+        }
+        ExportCommands::Timewarrior {
+            range,
+            output,
+            json,
+        } => {
+            let (from, to) = range.days()?;
+            let days = storage.load_range(from, to)?;
+            let contents = match json {
+                true => timewarrior::export_json(&days, zone),
+                false => timewarrior::export_data(&days, zone),
+            };
+            write_output(&contents, output.as_deref())
+        }
+        ExportCommands::Toggl {
+            range,
+            output,
+            json,
+            email,
+        } => {
+            let (from, to) = range.days()?;
+            let days = storage.load_range(from, to)?;
+            let contents = match json {
+                true => toggl::export_json(&days, zone),
+                false => toggl::export_csv(&days, &email),
+            };
+            write_output(&contents, output.as_deref())
+        }
+    }
+}
+
+/// Handle an import subcommand
+pub fn handle_import_command(
+    cmd: ImportCommands,
+    storage: Storage,
+    format: OutputFormat,
+) -> Result<()> {
+    let zone = LocalZone::System;
+    let (proposals, apply) = match cmd {
+        ImportCommands::Ics {
+            file,
+            range,
+            email,
+            apply,
+        } => {
+            let (from, to) = range.days()?;
+            let calendar = ics::parse(&read_file(&file)?, zone).map_err(|e| anyhow!(e))?;
+            for skipped in &calendar.skipped {
+                eprintln!("Warning: skipped event {}", skipped);
+            }
+            let proposals = ics::propose_records(&calendar.events, from, to, email.as_deref());
+            (proposals, apply)
+        }
+        ImportCommands::Timewarrior { path, range, apply } => {
+            let (from, to) = range.days_or_all()?;
+            let mut intervals = Vec::new();
+            for file in timewarrior_files(&path)? {
+                let parsed = timewarrior::parse(&read_file(&file)?)
+                    .map_err(|e| anyhow!("{}: {}", file.display(), e))?;
+                intervals.extend(parsed);
+            }
+            let proposals = timewarrior::propose_records(&intervals, zone);
+            (formats::in_range(proposals, from, to), apply)
+        }
+        ImportCommands::Toggl { file, range, apply } => {
+            let (from, to) = range.days_or_all()?;
+            let entries = toggl::parse(&read_file(&file)?, zone).map_err(|e| anyhow!(e))?;
+            let proposals = toggl::propose_records(&entries);
+            (formats::in_range(proposals, from, to), apply)
+        }
+    };
+
+    let mut manager = StorageManager::from_storage(storage);
+    let imports = import_records(&mut manager, proposals, apply)?;
+    print_imports(&imports, apply, format)
+}
+
+impl RangeArgs {
+    /// The range, defaulting to today
+    fn days(&self) -> Result<(Date, Date)> {
+        parse_range(self.from.as_deref(), self.to.as_deref())
+    }
+
+    /// The range, or all days if neither end is given
+    fn days_or_all(&self) -> Result<(Date, Date)> {
+        match (&self.from, &self.to) {
+            (None, None) => Ok((Date::MIN, Date::MAX)),
+            _ => self.days(),
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).context(format!("Failed to read file: {:?}", path))
+}
+
+/// The file itself, or the monthly `YYYY-MM.data` files of a Timewarrior data directory
+fn timewarrior_files(path: &Path) -> Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let mut files: Vec<PathBuf> = fs::read_dir(path)
+        .context(format!("Failed to read directory: {:?}", path))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|file| is_timewarrior_month(file))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Whether a file holds a month of intervals (`tags.data` and `undo.data` don't)
+fn is_timewarrior_month(file: &Path) -> bool {
+    let Some(name) = file.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    name.strip_suffix(".data").is_some_and(|month| {
+        month.len() == 7
+            && month
+                .char_indices()
+                .all(|(i, c)| if i == 4 { c == '-' } else { c.is_ascii_digit() })
+    })
+}
+
+/// Parse `--from`/`--to`, where `--to` defaults to `--from`
+fn parse_range(from: Option<&str>, to: Option<&str>) -> Result<(Date, Date)> {
+    let from = parse_date_arg(from)?;
+    let to = match to {
+        Some(to) => parse_date_arg(Some(to))?,
+        None => from,
+    };
+    if to < from {
+        return Err(anyhow!("--to ({}) is before --from ({})", to, from));
+    }
+    Ok((from, to))
+}
+
+/// Write `contents` to `output`, or to stdout if none is given
+pub(super) fn write_output(contents: &str, output: Option<&Path>) -> Result<()> {
+    match output {
+        Some(path) => {
+            fs::write(path, contents).context(format!("Failed to write file: {:?}", path))
+        }
+        None => {
+            print!("{}", contents);
+            Ok(())
+        }
+    }
+}
+
+/// A proposed record and whether it is (or would be) added
+#[derive(Serialize)]
+struct Import {
+    #[serde(flatten)]
+    record: ProposedRecord,
+    /// False if the day already has the same record
+    added: bool,
+}
+
+/// Merge proposals into their days, skipping ones already recorded
+///
+/// Each day is loaded once and saved once; nothing is saved unless `apply`.
+fn import_records(
+    manager: &mut StorageManager,
+    proposals: Vec<ProposedRecord>,
+    apply: bool,
+) -> Result<Vec<Import>> {
+    let mut days: BTreeMap<Date, DayData> = BTreeMap::new();
+    let mut changed = BTreeSet::new();
+    let mut imports = Vec::new();
+
+    for proposal in proposals {
+        let day_data = match days.entry(proposal.date) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(manager.load_with_tracking(proposal.date)?),
+        };
+        let added = !day_data.work_records.values().any(|r| proposal.matches(r));
+        if added {
+            let record = proposal.clone().into_record(day_data.next_id());
+            day_data.add_record(record);
+            changed.insert(proposal.date);
+        }
+        imports.push(Import {
+            record: proposal,
+            added,
+        });
+    }
+
+    if apply {
+        for date in changed {
+            manager.save(&days[&date])?;
+        }
+    }
+    Ok(imports)
+}
+
+fn print_imports(imports: &[Import], apply: bool, format: OutputFormat) -> Result<()> {
+    if format == OutputFormat::Json {
+        println!("{}", serde_json::to_string(imports)?);
+        return Ok(());
+    }
+
+    if imports.is_empty() {
+        println!("No events to import");
+        return Ok(());
+    }
+
+    for import in imports {
+        let record = &import.record;
+        let (icon, verb) = match (import.added, apply) {
+            (true, true) => ("✓", "added"),
+            (true, false) => ("+", "would add"),
+            (false, _) => ("-", "already recorded"),
+        };
+        println!(
+            "  {} {} {}-{}  {} — {}",
+            icon, record.date, record.start, record.end, record.name, verb
+        );
+    }
+    if !apply && imports.iter().any(|i| i.added) {
+        println!("Run again with --apply to add these records");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TimePoint;
+    use tempfile::TempDir;
+    use time::macros::date;
+
+    fn proposal(date: Date, name: &str, start: &str, end: &str) -> ProposedRecord {
+        ProposedRecord {
+            date,
+            name: name.to_string(),
+            start: TimePoint::parse(start).unwrap(),
+            end: TimePoint::parse(end).unwrap(),
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(
+            parse_range(Some("2026-10-01"), None).unwrap(),
+            (date!(2026 - 10 - 01), date!(2026 - 10 - 01))
+        );
+        assert!(parse_range(Some("2026-10-02"), Some("2026-10-01")).is_err());
+
+        let all = RangeArgs {
+            from: None,
+            to: None,
+        };
+        assert_eq!(all.days_or_all().unwrap(), (Date::MIN, Date::MAX));
+    }
+
+    #[test]
+    fn test_import_merges_by_date() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = StorageManager::new_with_dir(temp_dir.path().to_path_buf()).unwrap();
+        let (first, second) = (date!(2026 - 10 - 16), date!(2026 - 10 - 17));
+        manager
+            .add_record(
+                first,
+                proposal(first, "Existing", "08:00", "09:00").into_record(1),
+            )
+            .unwrap();
+
+        let proposals = vec![
+            proposal(first, "Review", "09:00", "10:00"),
+            proposal(second, "Standup", "09:00", "09:15"),
+            // Duplicates within one import are added once
+            proposal(second, "Standup", "09:00", "09:15"),
+        ];
+        let imports = import_records(&mut manager, proposals, true).unwrap();
+
+        let added: Vec<bool> = imports.iter().map(|i| i.added).collect();
+        assert_eq!(added, vec![true, true, false]);
+        let first_day = manager.load_with_tracking(first).unwrap();
+        assert_eq!(first_day.work_records.len(), 2);
+        assert_eq!(first_day.work_records[&2].name, "Review");
+        assert_eq!(
+            manager
+                .load_with_tracking(second)
+                .unwrap()
+                .work_records
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_timewarrior_files_reads_data_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        for name in ["2026-10.data", "2026-09.data", "undo.data", "tags.data"] {
+            fs::write(temp_dir.path().join(name), "").unwrap();
+        }
+
+        let files = timewarrior_files(temp_dir.path()).unwrap();
+        let names: Vec<_> = files.iter().map(|f| f.file_name().unwrap()).collect();
+        assert_eq!(names, vec!["2026-09.data", "2026-10.data"]);
+    }
+
+    #[test]
+    fn test_import_skips_existing_records() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut manager = StorageManager::new_with_dir(temp_dir.path().to_path_buf()).unwrap();
+        let day = date!(2026 - 10 - 17);
+        let proposals = vec![
+            proposal(day, "Standup", "09:00", "09:15"),
+            proposal(day, "Planning", "10:00", "11:00"),
+        ];
+
+        let preview = import_records(&mut manager, proposals.clone(), false).unwrap();
+        assert!(preview.iter().all(|i| i.added));
+        assert!(
+            manager
+                .load_with_tracking(day)
+                .unwrap()
+                .work_records
+                .is_empty()
+        );
+
+        import_records(&mut manager, proposals[..1].to_vec(), true).unwrap();
+        let imports = import_records(&mut manager, proposals, true).unwrap();
+
+        assert!(!imports[0].added);
+        assert!(imports[1].added);
+        let day_data = manager.load_with_tracking(day).unwrap();
+        assert_eq!(day_data.work_records.len(), 2);
+    }
+}
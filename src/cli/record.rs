@@ -0,0 +1,460 @@
+//! Record management commands
+//!
+//! Add, list, edit and delete work records without opening the TUI, so corrections
+//! can be scripted and time can be logged from other tools.
+
+use super::OutputFormat;
+use crate::models::{QuickEntry, TimePoint, WorkRecord};
+use crate::storage::{Storage, StorageManager};
+use anyhow::{Context, Result, anyhow};
+use clap::Subcommand;
+use time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+/// Record management commands
+#[derive(Subcommand)]
+pub enum RecordCommands {
+    /// Add a work record
+    Add {
+        /// Task name
+        name: String,
+
+        /// Date of the record (YYYY-MM-DD, defaults to today)
+        #[arg(long)]
+        date: Option<String>,
+
+        /// Start time (HH:MM)
+        #[arg(long)]
+        start: String,
+
+        /// End time (HH:MM)
+        #[arg(long)]
+        end: String,
+
+        /// Optional description
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+
+    /// List work records for a day
+    List {
+        /// Date to list (YYYY-MM-DD, defaults to today)
+        #[arg(long)]
+        date: Option<String>,
+    },
+
+    /// Edit fields of an existing work record
+    Edit {
+        /// Record ID (see `record list`)
+        id: u32,
+
+        /// Date of the record (YYYY-MM-DD, defaults to today)
+        #[arg(long)]
+        date: Option<String>,
+
+        /// New task name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// New start time (HH:MM)
+        #[arg(long)]
+        start: Option<String>,
+
+        /// New end time (HH:MM)
+        #[arg(long)]
+        end: Option<String>,
+
+        /// New description (empty string clears it)
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+
+    /// Delete a work record
+    Delete {
+        /// Record ID (see `record list`)
+        id: u32,
+
+        /// Date of the record (YYYY-MM-DD, defaults to today)
+        #[arg(long)]
+        date: Option<String>,
+    },
+}
+
+/// Handle a record subcommand
+pub fn handle_record_command(
+    cmd: RecordCommands,
+    storage: Storage,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut manager = StorageManager::from_storage(storage);
+
+    match cmd {
+        RecordCommands::Add {
+            name,
+            date,
+            start,
+            end,
+            description,
+        } => {
+            let date = parse_date_arg(date.as_deref())?;
+            let record = add_record(&mut manager, date, &name, &start, &end, description)?;
+            print_record(&record, date, "✓ Record added", format)
+        }
+        RecordCommands::List { date } => {
+            let date = parse_date_arg(date.as_deref())?;
+            let records = list_records(&mut manager, date)?;
+            print_record_list(&records, date, format)
+        }
+        RecordCommands::Edit {
+            id,
+            date,
+            name,
+            start,
+            end,
+            description,
+        } => {
+            let date = parse_date_arg(date.as_deref())?;
+            let changes = RecordChanges {
+                name,
+                start,
+                end,
+                description,
+            };
+            let record = edit_record(&mut manager, date, id, changes)?;
+            print_record(&record, date, "✓ Record updated", format)
+        }
+        RecordCommands::Delete { id, date } => {
+            let date = parse_date_arg(date.as_deref())?;
+            let record = manager.remove_record(date, id)?;
+            print_record(&record, date, "✓ Record deleted", format)
+        }
+    }
+}
+
+/// Handle `log`: parse a natural-language entry and add it as a record
+pub fn handle_log(
+    entry: &str,
+    description: Option<String>,
+    storage: Storage,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut manager = StorageManager::from_storage(storage);
+    let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
+    let now = PrimitiveDateTime::new(now.date(), now.time());
+
+    let (date, record) = log_entry(&mut manager, entry, description, now)?;
+    print_record(&record, date, "✓ Logged", format)
+}
+
+fn log_entry(
+    manager: &mut StorageManager,
+    entry: &str,
+    description: Option<String>,
+    now: PrimitiveDateTime,
+) -> Result<(Date, WorkRecord)> {
+    let entry = QuickEntry::parse(entry, now, now.date()).map_err(|e| anyhow!(e))?;
+    let date = entry.date;
+
+    let mut day_data = manager.load_with_tracking(date)?;
+    let mut record = entry.into_record(day_data.next_id());
+    record.description = description.unwrap_or_default();
+
+    manager.add_record(date, record.clone())?;
+    Ok((date, record))
+}
+
+/// Field changes requested by `record edit`
+#[derive(Default)]
+struct RecordChanges {
+    name: Option<String>,
+    start: Option<String>,
+    end: Option<String>,
+    description: Option<String>,
+}
+
+impl RecordChanges {
+    fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.start.is_none()
+            && self.end.is_none()
+            && self.description.is_none()
+    }
+}
+
+fn add_record(
+    manager: &mut StorageManager,
+    date: Date,
+    name: &str,
+    start: &str,
+    end: &str,
+    description: Option<String>,
+) -> Result<WorkRecord> {
+    let name = parse_name(name)?;
+    let start = parse_time(start)?;
+    let end = parse_time(end)?;
+
+    let mut day_data = manager.load_with_tracking(date)?;
+    let mut record = WorkRecord::new(day_data.next_id(), name, start, end);
+    record.description = description.unwrap_or_default();
+
+    manager.add_record(date, record.clone())?;
+    Ok(record)
+}
+
+fn list_records(manager: &mut StorageManager, date: Date) -> Result<Vec<WorkRecord>> {
+    let day_data = manager.load_with_tracking(date)?;
+    Ok(day_data.get_sorted_records().into_iter().cloned().collect())
+}
+
+fn edit_record(
+    manager: &mut StorageManager,
+    date: Date,
+    id: u32,
+    changes: RecordChanges,
+) -> Result<WorkRecord> {
+    if changes.is_empty() {
+        return Err(anyhow!(
+            "Nothing to change (use --name, --start, --end or --description)"
+        ));
+    }
+
+    let day_data = manager.load_with_tracking(date)?;
+    let mut record = day_data
+        .work_records
+        .get(&id)
+        .cloned()
+        .context(format!("Record with ID {} not found on {}", id, date))?;
+
+    if let Some(name) = changes.name {
+        record.name = parse_name(&name)?;
+    }
+    if let Some(start) = changes.start {
+        record.start = parse_time(&start)?;
+    }
+    if let Some(end) = changes.end {
+        record.end = parse_time(&end)?;
+    }
+    if let Some(description) = changes.description {
+        record.description = description;
+    }
+    record.update_duration();
+
+    manager.update_record(date, record.clone())?;
+    Ok(record)
+}
+
+fn parse_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(anyhow!("Task name cannot be empty"));
+    }
+    Ok(name.to_string())
+}
+
+fn parse_time(value: &str) -> Result<TimePoint> {
+    TimePoint::parse(value.trim()).map_err(|e| anyhow!(e))
+}
+
+/// Parse a `--date` argument, defaulting to today (local time)
+pub(crate) fn parse_date_arg(value: Option<&str>) -> Result<Date> {
+    match value {
+        Some(value) => {
+            let format = time::macros::format_description!("[year]-[month]-[day]");
+            Date::parse(value.trim(), &format)
+                .context(format!("Invalid date '{}', expected YYYY-MM-DD", value))
+        }
+        None => Ok(OffsetDateTime::now_local()
+            .unwrap_or_else(|_| OffsetDateTime::now_utc())
+            .date()),
+    }
+}
+
+fn format_record_line(record: &WorkRecord) -> String {
+    let mut line = format!(
+        "  #{:<3} {}-{}  {:>7}  {}",
+        record.id,
+        record.start,
+        record.end,
+        record.format_duration(),
+        record.name
+    );
+    if !record.description.is_empty() {
+        line.push_str(&format!(" — {}", record.description));
+    }
+    line
+}
+
+fn print_record(
+    record: &WorkRecord,
+    date: Date,
+    heading: &str,
+    format: OutputFormat,
+) -> Result<()> {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string(record)?),
+        OutputFormat::Text => {
+            println!("{} ({})", heading, date);
+            println!("{}", format_record_line(record));
+        }
+    }
+    Ok(())
+}
+
+fn print_record_list(records: &[WorkRecord], date: Date, format: OutputFormat) -> Result<()> {
+    match format {
+        OutputFormat::Json => println!("{}", serde_json::to_string(records)?),
+        OutputFormat::Text => {
+            if records.is_empty() {
+                println!("No records on {}", date);
+                return Ok(());
+            }
+
+            println!("Records for {}", date);
+            for record in records {
+                println!("{}", format_record_line(record));
+            }
+            let total: u32 = records.iter().map(|r| r.total_minutes).sum();
+            println!("  Total: {}h {:02}m", total / 60, total % 60);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use time::macros::date;
+
+    fn create_test_manager() -> (TempDir, StorageManager) {
+        let temp_dir = TempDir::new().unwrap();
+        let manager = StorageManager::new_with_dir(temp_dir.path().to_path_buf()).unwrap();
+        (temp_dir, manager)
+    }
+
+    #[test]
+    fn test_parse_date_arg() {
+        assert_eq!(
+            parse_date_arg(Some("2026-10-17")).unwrap(),
+            date!(2026 - 10 - 17)
+        );
+        assert!(parse_date_arg(Some("17/10/2026")).is_err());
+        assert!(parse_date_arg(None).is_ok());
+    }
+
+    #[test]
+    fn test_add_and_list_records() {
+        let (_temp_dir, mut manager) = create_test_manager();
+        let day = date!(2026 - 10 - 17);
+
+        let second = add_record(&mut manager, day, "Meeting", "15:00", "15:30", None).unwrap();
+        let first = add_record(
+            &mut manager,
+            day,
+            " PROJ-1 Review ",
+            "13:00",
+            "14:30",
+            Some("PR #42".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(second.id, 1);
+        assert_eq!(first.id, 2);
+        assert_eq!(first.name, "PROJ-1 Review");
+        assert_eq!(first.total_minutes, 90);
+
+        let records = list_records(&mut manager, day).unwrap();
+        assert_eq!(records.len(), 2);
+        // Sorted by start time
+        assert_eq!(records[0].name, "PROJ-1 Review");
+        assert_eq!(records[0].description, "PR #42");
+        assert_eq!(records[1].name, "Meeting");
+    }
+
+    #[test]
+    fn test_add_record_validation() {
+        let (_temp_dir, mut manager) = create_test_manager();
+        let day = date!(2026 - 10 - 17);
+
+        assert!(add_record(&mut manager, day, "  ", "13:00", "14:00", None).is_err());
+        assert!(add_record(&mut manager, day, "Task", "25:00", "14:00", None).is_err());
+        assert!(add_record(&mut manager, day, "Task", "13:00", "1pm", None).is_err());
+        assert!(list_records(&mut manager, day).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_edit_record() {
+        let (_temp_dir, mut manager) = create_test_manager();
+        let day = date!(2026 - 10 - 17);
+        let record = add_record(&mut manager, day, "Task", "13:00", "14:30", None).unwrap();
+
+        let changes = RecordChanges {
+            end: Some("15:00".to_string()),
+            description: Some("Extended".to_string()),
+            ..Default::default()
+        };
+        let updated = edit_record(&mut manager, day, record.id, changes).unwrap();
+
+        assert_eq!(updated.name, "Task");
+        assert_eq!(updated.end, TimePoint::new(15, 0).unwrap());
+        assert_eq!(updated.total_minutes, 120);
+
+        let records = list_records(&mut manager, day).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].description, "Extended");
+        assert_eq!(records[0].total_minutes, 120);
+    }
+
+    #[test]
+    fn test_edit_record_errors() {
+        let (_temp_dir, mut manager) = create_test_manager();
+        let day = date!(2026 - 10 - 17);
+        let record = add_record(&mut manager, day, "Task", "13:00", "14:30", None).unwrap();
+
+        // No changes requested
+        assert!(edit_record(&mut manager, day, record.id, RecordChanges::default()).is_err());
+
+        // Unknown ID must not create a new record
+        let changes = RecordChanges {
+            name: Some("Ghost".to_string()),
+            ..Default::default()
+        };
+        assert!(edit_record(&mut manager, day, 99, changes).is_err());
+        assert_eq!(list_records(&mut manager, day).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_delete_record() {
+        let (_temp_dir, mut manager) = create_test_manager();
+        let day = date!(2026 - 10 - 17);
+        let record = add_record(&mut manager, day, "Task", "13:00", "14:30", None).unwrap();
+
+        let removed = manager.remove_record(day, record.id).unwrap();
+        assert_eq!(removed.name, "Task");
+        assert!(list_records(&mut manager, day).unwrap().is_empty());
+        assert!(manager.remove_record(day, record.id).is_err());
+    }
+
+    #[test]
+    fn test_log_entry() {
+        let (_temp_dir, mut manager) = create_test_manager();
+        let now = time::macros::datetime!(2026-10-17 16:45);
+
+        let (date, record) = log_entry(
+            &mut manager,
+            "yesterday 2pm-3:30pm PROJ-42 code review",
+            Some("PR #7".to_string()),
+            now,
+        )
+        .unwrap();
+
+        assert_eq!(date, date!(2026 - 10 - 16));
+        assert_eq!(record.name, "PROJ-42 code review");
+        assert_eq!(record.total_minutes, 90);
+
+        let records = list_records(&mut manager, date).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].description, "PR #7");
+
+        assert!(log_entry(&mut manager, "no time here", None, now).is_err());
+    }
+}
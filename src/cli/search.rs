@@ -0,0 +1,81 @@
+//! Search command
+
+use super::OutputFormat;
+use super::record::parse_date_arg;
+use crate::models::{SearchHit, SearchQuery};
+use crate::storage::Storage;
+use anyhow::{Result, anyhow};
+use time::Date;
+
+/// Search all stored days for records matching `query`
+pub fn handle_search(
+    query: &str,
+    from: Option<String>,
+    to: Option<String>,
+    limit: Option<usize>,
+    storage: Storage,
+    format: OutputFormat,
+) -> Result<()> {
+    let query_terms = SearchQuery::parse(query);
+    if query_terms.is_empty() {
+        return Err(anyhow!("Search query cannot be empty"));
+    }
+
+    let from = from
+        .map(|from| parse_date_arg(Some(&from)))
+        .transpose()?
+        .unwrap_or(Date::MIN);
+    let to = to
+        .map(|to| parse_date_arg(Some(&to)))
+        .transpose()?
+        .unwrap_or(Date::MAX);
+
+    let (mut days, skipped) = storage.load_all()?;
+    for skipped in skipped {
+        eprintln!("Warning: skipped {}", skipped);
+    }
+    days.retain(|day| day.date >= from && day.date <= to);
+    let mut hits = query_terms.search(&days);
+    if let Some(limit) = limit {
+        hits.truncate(limit);
+    }
+
+    print_hits(&hits, query, format)
+}
+
+fn print_hits(hits: &[SearchHit], query: &str, format: OutputFormat) -> Result<()> {
+    if format == OutputFormat::Json {
+        println!("{}", serde_json::to_string(hits)?);
+        return Ok(());
+    }
+
+    if hits.is_empty() {
+        println!("No records match '{}'", query);
+        return Ok(());
+    }
+
+    for hit in hits {
+        let record = &hit.record;
+        let mut line = format!(
+            "  {}  {}-{}  {:>7}  {}",
+            hit.date,
+            record.start,
+            record.end,
+            record.format_duration(),
+            record.name
+        );
+        if !record.description.is_empty() {
+            line.push_str(&format!(" — {}", record.description));
+        }
+        println!("{}", line);
+    }
+    let total: u32 = hits.iter().map(|hit| hit.record.total_minutes).sum();
+    println!(
+        "{} {}, {}h {:02}m total",
+        hits.len(),
+        if hits.len() == 1 { "match" } else { "matches" },
+        total / 60,
+        total % 60
+    );
+    Ok(())
+}
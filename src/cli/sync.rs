@@ -0,0 +1,106 @@
+//! Issue tracker sync command
+
+use super::record::parse_date_arg;
+use super::{OutputFormat, load_config};
+use crate::integrations::sync::{self, RetryPolicy, SyncAction, SyncOptions, SyncOutcome};
+use crate::models::LocalZone;
+use crate::storage::{Storage, StorageManager};
+use anyhow::{Result, anyhow};
+
+/// Push a day's ticketed records to the configured trackers
+pub fn handle_sync(
+    date: Option<String>,
+    dry_run: bool,
+    storage: Storage,
+    format: OutputFormat,
+) -> Result<()> {
+    let date = parse_date_arg(date.as_deref())?;
+    let config = load_config()?;
+
+    let mut manager = StorageManager::from_storage(storage);
+    let mut day_data = manager.load_with_tracking(date)?;
+
+    let options = SyncOptions {
+        zone: LocalZone::System,
+        dry_run,
+        retry: RetryPolicy::default(),
+    };
+    let outcomes = sync::sync_day(&mut day_data, &config, &options);
+
+    // Persist remote IDs even if some records failed, so they aren't duplicated next time
+    if !dry_run
+        && outcomes.iter().any(|o| {
+            matches!(
+                o.action,
+                SyncAction::Created | SyncAction::Updated | SyncAction::Deleted
+            )
+        })
+    {
+        manager.save(&day_data)?;
+    }
+
+    print_outcomes(&outcomes, date, dry_run, format)?;
+
+    let failed = outcomes
+        .iter()
+        .filter(|o| o.action == SyncAction::Failed)
+        .count();
+    if failed > 0 {
+        return Err(anyhow!("{} sync entries failed", failed));
+    }
+    Ok(())
+}
+
+fn print_outcomes(
+    outcomes: &[SyncOutcome],
+    date: time::Date,
+    dry_run: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    if format == OutputFormat::Json {
+        println!("{}", serde_json::to_string(outcomes)?);
+        return Ok(());
+    }
+
+    if dry_run {
+        println!("Sync preview for {} (dry run, nothing sent)", date);
+    } else {
+        println!("Sync for {}", date);
+    }
+
+    for outcome in outcomes {
+        let (icon, verb) = match (&outcome.action, dry_run) {
+            (SyncAction::Created, false) => ("✓", "created"),
+            (SyncAction::Created, true) => ("+", "would create"),
+            (SyncAction::Updated, false) => ("↻", "updated"),
+            (SyncAction::Updated, true) => ("↻", "would update"),
+            (SyncAction::Deleted, false) => ("×", "deleted from previous ticket"),
+            (SyncAction::Deleted, true) => ("×", "would delete from previous ticket"),
+            (SyncAction::Skipped, _) => ("-", "skipped"),
+            (SyncAction::Failed, _) => ("✗", "failed"),
+        };
+        let detail = match (&outcome.action, &outcome.tracker) {
+            (SyncAction::Created | SyncAction::Updated | SyncAction::Deleted, Some(tracker))
+                if !dry_run =>
+            {
+                format!("{} {}", tracker, outcome.detail)
+            }
+            _ => outcome.detail.clone(),
+        };
+        let ids: Vec<String> = outcome
+            .record_ids
+            .iter()
+            .map(|id| format!("#{}", id))
+            .collect();
+        println!(
+            "  {} {} {} — {} ({})",
+            icon,
+            ids.join(","),
+            outcome.name,
+            verb,
+            detail
+        );
+    }
+
+    Ok(())
+}
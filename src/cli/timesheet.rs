@@ -0,0 +1,133 @@
+//! Timesheet command
+
+use super::exchange::write_output;
+use super::load_config;
+use super::record::parse_date_arg;
+use crate::formats::timesheet::{self, DocumentFormat, Period, SheetDetails, Timesheet};
+use crate::storage::Storage;
+use anyhow::{Context, Result};
+use clap::{Args, ValueEnum};
+use std::fs;
+use std::path::{Path, PathBuf};
+use time::{OffsetDateTime, UtcOffset};
+
+/// Options of the `timesheet` command
+///
+/// Unset options fall back to the `[timesheet]` section of the config.
+#[derive(Args)]
+pub struct TimesheetArgs {
+    /// Cover a week (Monday to Sunday) or a calendar month
+    #[arg(long, value_enum, default_value_t = PeriodArg::Week)]
+    period: PeriodArg,
+
+    /// Any day in the period (YYYY-MM-DD, defaults to today)
+    #[arg(long)]
+    date: Option<String>,
+
+    /// Render Markdown instead of HTML
+    #[arg(long)]
+    markdown: bool,
+
+    /// Round each task's daily time to this many minutes
+    #[arg(long)]
+    round: Option<u32>,
+
+    /// List record descriptions below the grid
+    #[arg(long)]
+    descriptions: bool,
+
+    /// Client shown in the title and signature block
+    #[arg(long)]
+    client: Option<String>,
+
+    /// Template file to render into instead of the built-in one
+    #[arg(long)]
+    template: Option<PathBuf>,
+
+    /// Print the built-in template (a starting point for your own) and exit
+    #[arg(long)]
+    print_template: bool,
+
+    /// Write to a file instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum PeriodArg {
+    Week,
+    Month,
+}
+
+/// Render a week or month of records as a timesheet document
+pub fn handle_timesheet(args: TimesheetArgs, storage: Storage) -> Result<()> {
+    let format = match args.markdown {
+        true => DocumentFormat::Markdown,
+        false => DocumentFormat::Html,
+    };
+    if args.print_template {
+        print!("{}", format.default_template());
+        return Ok(());
+    }
+
+    let config = load_config()?.timesheet;
+    let template_path = args.template.or(match format {
+        DocumentFormat::Html => config.html_template,
+        DocumentFormat::Markdown => config.markdown_template,
+    });
+    let template = match template_path {
+        Some(path) => {
+            let path = expand_home(&path);
+            fs::read_to_string(&path).context(format!("Failed to read template: {:?}", path))?
+        }
+        None => format.default_template().to_string(),
+    };
+
+    let period = match args.period {
+        PeriodArg::Week => Period::Week,
+        PeriodArg::Month => Period::Month,
+    };
+    let (from, to) = period.range(parse_date_arg(args.date.as_deref())?);
+    let days = storage.load_range(from, to)?;
+    let sheet = Timesheet::build(&days, from, to, args.round.unwrap_or(config.round_minutes));
+
+    let details = SheetDetails {
+        name: config.name,
+        client: args.client.or(config.client),
+        descriptions: args.descriptions || config.descriptions,
+    };
+    let now = OffsetDateTime::now_utc()
+        .to_offset(UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC));
+    let document = timesheet::render(&sheet, &details, format, &template, now);
+    write_output(&document, args.output.as_deref())
+}
+
+/// Expand a leading `~/` to the home directory
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_home() {
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(
+            expand_home(Path::new("~/sheets/t.html")),
+            home.join("sheets/t.html")
+        );
+        assert_eq!(
+            expand_home(Path::new("/etc/t.html")),
+            PathBuf::from("/etc/t.html")
+        );
+        assert_eq!(
+            expand_home(Path::new("~user/t.html")),
+            PathBuf::from("~user/t.html")
+        );
+    }
+}
@@ -1,9 +1,12 @@
+use crate::integrations::patterns::TicketMatchers;
 use anyhow::{Context, Result};
+use indexmap::IndexMap;
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 /// Configuration for issue tracker integrations (JIRA, Linear, GitHub, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -13,6 +16,25 @@ pub struct Config {
 
     #[serde(default)]
     pub theme: ThemeConfig,
+
+    #[serde(default)]
+    pub hooks: HookConfig,
+
+    #[serde(default)]
+    pub timesheet: TimesheetConfig,
+
+    #[serde(default)]
+    pub stats: StatsConfig,
+
+    #[serde(default)]
+    pub table: TableConfig,
+
+    #[serde(default)]
+    pub timeline: TimelineConfig,
+
+    /// Problems `Config::load` worked around, e.g. invalid ticket patterns it skipped
+    #[serde(skip)]
+    pub warnings: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -21,9 +43,26 @@ pub struct IntegrationConfig {
     #[serde(default)]
     pub default_tracker: Option<String>,
 
-    /// Map of tracker name to tracker configuration
+    /// Map of tracker name to tracker configuration, in config file order
+    /// (the first tracker wins when patterns overlap)
     #[serde(default)]
-    pub trackers: HashMap<String, TrackerConfig>,
+    pub trackers: IndexMap<String, TrackerConfig>,
+
+    /// `ticket_patterns` compiled on first use (eagerly by `Config::load`, which reports
+    /// invalid ones)
+    #[serde(skip)]
+    matchers: OnceLock<TicketMatchers>,
+}
+
+impl IntegrationConfig {
+    /// Compiled ticket patterns of all enabled trackers
+    ///
+    /// Configs not created by `Config::load` compile lazily and skip invalid patterns.
+    /// Compiled once, so `trackers` must not change afterwards.
+    pub fn matchers(&self) -> &TicketMatchers {
+        self.matchers
+            .get_or_init(|| TicketMatchers::compile_lenient(self))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -41,6 +80,177 @@ pub struct TrackerConfig {
     /// URL template for worklog page: {base_url}, {ticket}
     #[serde(default)]
     pub worklog_url: String,
+    /// Time sync backend for `work-tuimer sync` ("jira", "linear" or "github"), disabled if unset
+    #[serde(default)]
+    pub sync: Option<String>,
+    /// API token used by the sync backend
+    #[serde(default)]
+    pub api_token: Option<String>,
+    /// Account for basic auth (Jira Cloud email); the token is sent as a bearer token if unset
+    #[serde(default)]
+    pub api_user: Option<String>,
+    /// API endpoint for the sync backend if it differs from the default
+    /// (Linear: https://api.linear.app/graphql, GitHub: https://api.github.com)
+    #[serde(default)]
+    pub api_url: Option<String>,
+}
+
+/// Shell commands run on timer session events
+///
+/// Each command receives the timer state as JSON on stdin and a summary in
+/// `WORK_TUIMER_*` environment variables.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct HookConfig {
+    /// Command run after a session starts
+    #[serde(default)]
+    pub on_start: Option<String>,
+    /// Command run after a session stops
+    #[serde(default)]
+    pub on_stop: Option<String>,
+    /// Command run after a session is paused
+    #[serde(default)]
+    pub on_pause: Option<String>,
+    /// Command run after a paused session resumes
+    #[serde(default)]
+    pub on_resume: Option<String>,
+}
+
+/// Defaults for the `timesheet` command
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TimesheetConfig {
+    /// Name printed in the signature block
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Client printed in the header and signature block
+    #[serde(default)]
+    pub client: Option<String>,
+    /// Round each task's daily time to this many minutes (0 = no rounding)
+    #[serde(default)]
+    pub round_minutes: u32,
+    /// List record descriptions below the grid
+    #[serde(default)]
+    pub descriptions: bool,
+    /// Template file replacing the built-in HTML template
+    #[serde(default)]
+    pub html_template: Option<PathBuf>,
+    /// Template file replacing the built-in Markdown template
+    #[serde(default)]
+    pub markdown_template: Option<PathBuf>,
+}
+
+/// Statistics dashboard settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsConfig {
+    /// Number of days (up to the viewed day) the dashboard covers when opened
+    #[serde(default = "default_stats_days")]
+    pub days: u32,
+}
+
+fn default_stats_days() -> u32 {
+    90
+}
+
+impl Default for StatsConfig {
+    fn default() -> Self {
+        Self {
+            days: default_stats_days(),
+        }
+    }
+}
+
+/// Record table settings
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TableConfig {
+    /// Order of the records, changed with `o` in the TUI
+    #[serde(default)]
+    pub sort: SortOrder,
+    /// Whether nudging a time with `+`/`-` also moves the touching neighbour's boundary
+    #[serde(default)]
+    pub nudge_adjacent: bool,
+}
+
+/// Order of the records in the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    /// By start time
+    #[default]
+    Start,
+    /// Longest first
+    Duration,
+    /// By task name, ignoring case
+    Name,
+    /// By the first ticket in the task name, records without one last
+    Ticket,
+}
+
+impl SortOrder {
+    /// The order `o` switches to
+    pub fn next(self) -> Self {
+        match self {
+            SortOrder::Start => SortOrder::Duration,
+            SortOrder::Duration => SortOrder::Name,
+            SortOrder::Name => SortOrder::Ticket,
+            SortOrder::Ticket => SortOrder::Start,
+        }
+    }
+
+    /// Value of `sort` in the config file
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SortOrder::Start => "start",
+            SortOrder::Duration => "duration",
+            SortOrder::Name => "name",
+            SortOrder::Ticket => "ticket",
+        }
+    }
+}
+
+/// Timeline pane settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineConfig {
+    /// Show the timeline below the record table on startup (toggled with `w` in the TUI)
+    #[serde(default)]
+    pub show: bool,
+    /// Hours the timeline spans
+    #[serde(default)]
+    pub axis: TimelineAxis,
+    /// First hour of the working day, for the `working` axis
+    #[serde(default = "default_day_start")]
+    pub day_start: u32,
+    /// Hour the working day ends, for the `working` axis
+    #[serde(default = "default_day_end")]
+    pub day_end: u32,
+}
+
+fn default_day_start() -> u32 {
+    8
+}
+
+fn default_day_end() -> u32 {
+    18
+}
+
+impl Default for TimelineConfig {
+    fn default() -> Self {
+        Self {
+            show: false,
+            axis: TimelineAxis::default(),
+            day_start: default_day_start(),
+            day_end: default_day_end(),
+        }
+    }
+}
+
+/// Hours the timeline spans
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TimelineAxis {
+    /// The working hours, widened to whole hours around records outside them
+    #[default]
+    Working,
+    /// All 24 hours
+    Full,
 }
 
 impl Config {
@@ -51,14 +261,27 @@ impl Config {
         if config_path.exists() {
             let contents = fs::read_to_string(&config_path)
                 .context(format!("Failed to read config file: {:?}", config_path))?;
-            let config: Config =
-                toml::from_str(&contents).context("Failed to parse config TOML")?;
-            Ok(config)
+            Self::parse(&contents, &config_path)
         } else {
             Ok(Config::default())
         }
     }
 
+    /// Parse a config file's contents
+    ///
+    /// An invalid ticket pattern doesn't fail the whole config: it is skipped and
+    /// reported in `warnings`, and the rest of the tracker's patterns still work.
+    fn parse(contents: &str, path: &Path) -> Result<Self> {
+        let mut config: Config = toml::from_str(contents).context("Failed to parse config TOML")?;
+        let (matchers, errors) = TicketMatchers::compile(&config.integrations);
+        let _ = config.integrations.matchers.set(matchers);
+        config.warnings = errors
+            .into_iter()
+            .map(|e| format!("{} in {:?} (skipped)", e, path))
+            .collect();
+        Ok(config)
+    }
+
     /// Get config file path (~/.config/work-tuimer/config.toml)
     /// Respects XDG_CONFIG_HOME environment variable on Unix systems
     fn get_config_path() -> PathBuf {
@@ -91,6 +314,11 @@ impl Config {
         PathBuf::from("./config.toml")
     }
 
+    /// Remember the record table's sort order in the config file
+    pub fn save_sort_order(order: SortOrder) -> Result<()> {
+        write_sort_order(&Self::get_config_path(), order)
+    }
+
     /// Check if any tracker integration is properly configured
     pub fn has_integrations(&self) -> bool {
         self.integrations
@@ -105,6 +333,29 @@ impl Config {
     }
 }
 
+/// Set `table.sort` in a config file, keeping the rest of it (comments included) as written
+fn write_sort_order(path: &Path, order: SortOrder) -> Result<()> {
+    let contents = if path.exists() {
+        fs::read_to_string(path).context(format!("Failed to read config file: {:?}", path))?
+    } else {
+        String::new()
+    };
+    let mut document: toml_edit::DocumentMut =
+        contents.parse().context("Failed to parse config TOML")?;
+    let table = document
+        .entry("table")
+        .or_insert(toml_edit::table())
+        .as_table_like_mut()
+        .context("`table` in the config file is not a table")?;
+    table.insert("sort", toml_edit::value(order.as_str()));
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).context(format!("Failed to create {:?}", dir))?;
+    }
+    fs::write(path, document.to_string())
+        .context(format!("Failed to write config file: {:?}", path))
+}
+
 /// Theme configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThemeConfig {
@@ -567,6 +818,159 @@ worklog_url = "{base_url}/browse/{ticket}?focusedWorklogId=-1"
         assert!(tracker.ticket_patterns.is_empty());
     }
 
+    #[test]
+    fn test_invalid_ticket_pattern_is_skipped_not_fatal() {
+        let toml_str = r#"
+[hooks]
+on_start = "notify-send started"
+
+[integrations.trackers.jira]
+enabled = true
+ticket_patterns = ["^PROJ-(\\d+$", "^ENG-\\d+$"]
+        "#;
+        let config = Config::parse(toml_str, Path::new("config.toml")).unwrap();
+
+        assert_eq!(config.warnings.len(), 1);
+        assert!(config.warnings[0].contains("tracker 'jira'"));
+        assert!(config.warnings[0].contains("^PROJ-(\\d+$"));
+        // The rest of the config still applies
+        assert_eq!(
+            config.hooks.on_start.as_deref(),
+            Some("notify-send started")
+        );
+        let jira = config.integrations.matchers().tracker("jira").unwrap();
+        assert!(jira.matches("ENG-1"));
+    }
+
+    #[test]
+    fn test_hook_config_defaults() {
+        let config = Config::default();
+        assert!(config.hooks.on_start.is_none());
+        assert!(config.hooks.on_stop.is_none());
+        assert!(config.hooks.on_pause.is_none());
+        assert!(config.hooks.on_resume.is_none());
+    }
+
+    #[test]
+    fn test_hook_config_deserialization() {
+        let toml_str = r#"
+[hooks]
+on_start = "notify-send 'Started'"
+on_stop = "~/bin/slack-status.sh clear"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).expect("Failed to deserialize");
+        assert_eq!(
+            config.hooks.on_start,
+            Some("notify-send 'Started'".to_string())
+        );
+        assert_eq!(
+            config.hooks.on_stop,
+            Some("~/bin/slack-status.sh clear".to_string())
+        );
+        assert!(config.hooks.on_pause.is_none());
+        assert!(config.hooks.on_resume.is_none());
+    }
+
+    #[test]
+    fn test_timesheet_config_deserialization() {
+        let toml_str = r#"
+[timesheet]
+name = "Ana Smith"
+round_minutes = 15
+html_template = "/home/ana/timesheet.html"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).expect("Failed to deserialize");
+        assert_eq!(config.timesheet.name.as_deref(), Some("Ana Smith"));
+        assert_eq!(config.timesheet.round_minutes, 15);
+        assert!(!config.timesheet.descriptions);
+        assert_eq!(
+            config.timesheet.html_template,
+            Some(PathBuf::from("/home/ana/timesheet.html"))
+        );
+        assert!(config.timesheet.markdown_template.is_none());
+    }
+
+    #[test]
+    fn test_stats_config_deserialization() {
+        let config: Config = toml::from_str("[stats]\ndays = 30").expect("Failed to deserialize");
+        assert_eq!(config.stats.days, 30);
+        assert_eq!(Config::default().stats.days, 90);
+    }
+
+    #[test]
+    fn test_table_config_deserialization() {
+        let config: Config =
+            toml::from_str("[table]\nsort = \"ticket\"").expect("Failed to deserialize");
+        assert_eq!(config.table.sort, SortOrder::Ticket);
+        assert_eq!(Config::default().table.sort, SortOrder::Start);
+        assert!(toml::from_str::<Config>("[table]\nsort = \"size\"").is_err());
+        assert!(!config.table.nudge_adjacent);
+        let config: Config =
+            toml::from_str("[table]\nnudge_adjacent = true").expect("Failed to deserialize");
+        assert!(config.table.nudge_adjacent);
+    }
+
+    #[test]
+    fn test_timeline_config_deserialization() {
+        let config: Config =
+            toml::from_str("[timeline]\nshow = true\naxis = \"full\"\nday_start = 7")
+                .expect("Failed to deserialize");
+        assert!(config.timeline.show);
+        assert_eq!(config.timeline.axis, TimelineAxis::Full);
+        assert_eq!(config.timeline.day_start, 7);
+        assert_eq!(config.timeline.day_end, 18);
+
+        let default = Config::default().timeline;
+        assert!(!default.show);
+        assert_eq!(default.axis, TimelineAxis::Working);
+        assert_eq!((default.day_start, default.day_end), (8, 18));
+    }
+
+    #[test]
+    fn test_sort_order_cycles_through_all() {
+        let mut order = SortOrder::Start;
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            seen.push(order.as_str());
+            order = order.next();
+        }
+        assert_eq!(order, SortOrder::Start);
+        assert_eq!(seen, ["start", "duration", "name", "ticket"]);
+    }
+
+    #[test]
+    fn test_write_sort_order_keeps_the_rest_of_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(
+            &path,
+            "# My settings\n[theme]\nactive = \"kanagawa\" # dark\n\n[table]\nsort = \"name\"\n",
+        )
+        .unwrap();
+
+        write_sort_order(&path, SortOrder::Duration).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with("# My settings\n"));
+        assert!(contents.contains("active = \"kanagawa\" # dark"));
+        let config: Config = toml::from_str(&contents).unwrap();
+        assert_eq!(config.table.sort, SortOrder::Duration);
+        assert_eq!(config.theme.active, "kanagawa");
+    }
+
+    #[test]
+    fn test_write_sort_order_creates_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("work-tuimer").join("config.toml");
+
+        write_sort_order(&path, SortOrder::Ticket).unwrap();
+
+        let config: Config = toml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(config.table.sort, SortOrder::Ticket);
+    }
+
     // Theme-related tests
 
     #[test]
@@ -847,6 +1251,12 @@ badge = "lightmagenta"
                 active: "custom1".to_string(),
                 custom,
             },
+            hooks: HookConfig::default(),
+            timesheet: TimesheetConfig::default(),
+            stats: StatsConfig::default(),
+            table: TableConfig::default(),
+            timeline: TimelineConfig::default(),
+            warnings: Vec::new(),
         };
 
         let theme = config.get_theme();
@@ -0,0 +1,1463 @@
+//! iCalendar (ICS) export and import
+//!
+//! Records are exported as VEVENTs in floating local time (no time zone), so calendar
+//! apps show them at the wall-clock times they were recorded. Imports read a local
+//! `.ics` file (e.g. exported meetings) and propose a record for each event occurrence
+//! the user attends.
+
+use super::{ProposedRecord, record_span};
+use crate::models::{DayData, LocalZone};
+use std::collections::{HashMap, HashSet};
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+/// Longest line allowed by RFC 5545, in octets (excluding the CRLF)
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Upper bound on generated occurrences per recurring event
+const MAX_OCCURRENCES: usize = 10_000;
+
+/// Largest RRULE INTERVAL accepted (a thousand years for a yearly rule)
+const MAX_INTERVAL: u32 = 1_000;
+
+/// Render the records of `days` as an iCalendar document
+///
+/// `stamp` is used as the DTSTAMP of every event. Records ending after midnight end on
+/// the next day.
+pub fn export(days: &[DayData], stamp: OffsetDateTime) -> String {
+    let stamp = stamp.to_offset(UtcOffset::UTC);
+    let stamp = format!(
+        "{}Z",
+        format_date_time(PrimitiveDateTime::new(stamp.date(), stamp.time()))
+    );
+
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, "PRODID:-//work-tuimer//EN");
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+
+    for day in days {
+        for record in day.get_sorted_records() {
+            let (start, end) = record_span(day, record);
+
+            push_line(&mut out, "BEGIN:VEVENT");
+            push_line(
+                &mut out,
+                &format!("UID:{}-{}@work-tuimer", day.date, record.id),
+            );
+            push_line(&mut out, &format!("DTSTAMP:{}", stamp));
+            push_line(&mut out, &format!("DTSTART:{}", format_date_time(start)));
+            push_line(&mut out, &format!("DTEND:{}", format_date_time(end)));
+            push_line(&mut out, &format!("SUMMARY:{}", escape_text(&record.name)));
+            if !record.description.is_empty() {
+                push_line(
+                    &mut out,
+                    &format!("DESCRIPTION:{}", escape_text(&record.description)),
+                );
+            }
+            push_line(&mut out, "END:VEVENT");
+        }
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+/// An event read from an ICS file, with times in local time
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalendarEvent {
+    pub uid: String,
+    pub summary: String,
+    pub description: String,
+    pub start: PrimitiveDateTime,
+    pub end: PrimitiveDateTime,
+    /// Date-only event (no times to record)
+    pub all_day: bool,
+    pub cancelled: bool,
+    /// Organizer email, lowercase
+    pub organizer: Option<String>,
+    pub attendees: Vec<Attendee>,
+    pub recurrence: Option<Recurrence>,
+    pub exdates: Vec<PrimitiveDateTime>,
+    /// Set on an edited occurrence of a recurring event: the start it replaces
+    pub recurrence_id: Option<PrimitiveDateTime>,
+}
+
+/// An event attendee and their participation status
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attendee {
+    /// Email, lowercase
+    pub email: String,
+    /// PARTSTAT value, e.g. "ACCEPTED" or "DECLINED" (uppercase)
+    pub status: String,
+}
+
+/// A repeat rule (RRULE)
+///
+/// Only the common shapes are expanded: every N days, every N weeks on the given
+/// weekdays, and every N months or years on the start's day. Rules using other parts
+/// (BYMONTHDAY, BYSETPOS, ...) keep only their first occurrence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<PrimitiveDateTime>,
+    /// Weekdays of a weekly rule (empty: the start's weekday)
+    pub weekdays: Vec<time::Weekday>,
+    /// The rule uses parts that aren't expanded
+    pub unsupported: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// Events read from an iCalendar document
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Calendar {
+    pub events: Vec<CalendarEvent>,
+    /// Events that were left out, and why
+    pub skipped: Vec<String>,
+}
+
+/// Parse the events of an iCalendar document
+///
+/// UTC times (`...Z`) are converted to local times in `zone`, and times with a TZID are converted
+/// from the time zone the document defines for it (VTIMEZONE). Events using a TZID the
+/// document doesn't define are skipped and listed in [`Calendar::skipped`].
+pub fn parse(text: &str, local: LocalZone) -> Result<Calendar, String> {
+    let lines = unfold(text);
+    if !lines
+        .iter()
+        .any(|line| line.eq_ignore_ascii_case("BEGIN:VCALENDAR"))
+    {
+        return Err("Not an iCalendar file (no BEGIN:VCALENDAR)".to_string());
+    }
+
+    // Time zones may be defined after the events using them, so events are built last
+    let mut event_properties: Vec<Vec<Property>> = Vec::new();
+    let mut zones: HashMap<String, TimeZone> = HashMap::new();
+    let mut components: Vec<String> = Vec::new();
+    let mut properties: Vec<Property> = Vec::new();
+    let mut zone_id = String::new();
+    let mut observances: Vec<Observance> = Vec::new();
+
+    for line in &lines {
+        let Some(property) = Property::parse(line) else {
+            continue;
+        };
+        let current = components.last().map(String::as_str);
+        match property.name.as_str() {
+            "BEGIN" => {
+                let component = property.value.to_ascii_uppercase();
+                match component.as_str() {
+                    "VEVENT" | "STANDARD" | "DAYLIGHT" => properties.clear(),
+                    "VTIMEZONE" => {
+                        zone_id.clear();
+                        observances.clear();
+                    }
+                    _ => {}
+                }
+                components.push(component);
+            }
+            "END" => match components.pop().as_deref() {
+                Some("VEVENT") => event_properties.push(std::mem::take(&mut properties)),
+                Some("STANDARD" | "DAYLIGHT") => observances.push(Observance::parse(&properties)?),
+                Some("VTIMEZONE") => {
+                    zones.insert(
+                        std::mem::take(&mut zone_id),
+                        TimeZone {
+                            observances: std::mem::take(&mut observances),
+                        },
+                    );
+                }
+                _ => {}
+            },
+            "TZID" if current == Some("VTIMEZONE") => zone_id = property.value.trim().to_string(),
+            // Skip properties of nested components such as VALARM
+            _ if matches!(current, Some("VEVENT" | "STANDARD" | "DAYLIGHT")) => {
+                properties.push(property)
+            }
+            _ => {}
+        }
+    }
+
+    let mut calendar = Calendar::default();
+    for properties in &event_properties {
+        let unknown_zone = properties
+            .iter()
+            .filter_map(|p| p.param("TZID"))
+            .find(|id| zone_for(id, &zones).is_none());
+        if let Some(id) = unknown_zone {
+            let summary = properties
+                .iter()
+                .find(|p| p.name == "SUMMARY")
+                .map(|p| unescape_text(&p.value))
+                .unwrap_or_default();
+            calendar.skipped.push(format!(
+                "'{}': time zone '{}' is not defined in the file",
+                summary, id
+            ));
+            continue;
+        }
+        calendar
+            .events
+            .push(build_event(properties, &zones, local)?);
+    }
+
+    Ok(calendar)
+}
+
+/// Propose records for the events between `from` and `to` (inclusive)
+///
+/// Skips cancelled and all-day events, and events of a day or longer. With `me` (an
+/// email), events that list `me` as an attendee are only kept if they were accepted.
+pub fn propose_records(
+    events: &[CalendarEvent],
+    from: Date,
+    to: Date,
+    me: Option<&str>,
+) -> Vec<ProposedRecord> {
+    let me = me.map(str::to_lowercase);
+    let overridden: HashSet<(&str, PrimitiveDateTime)> = events
+        .iter()
+        .filter_map(|e| Some((e.uid.as_str(), e.recurrence_id?)))
+        .collect();
+
+    let mut proposals = Vec::new();
+    for event in events {
+        if event.cancelled || event.all_day || !is_attending(event, me.as_deref()) {
+            continue;
+        }
+        let length = event.end - event.start;
+        if length <= Duration::ZERO || length >= Duration::DAY {
+            continue;
+        }
+
+        for start in occurrences(event, from, to) {
+            if start.date() < from
+                || event.exdates.contains(&start)
+                || (event.recurrence_id.is_none()
+                    && overridden.contains(&(event.uid.as_str(), start)))
+            {
+                continue;
+            }
+            proposals.extend(ProposedRecord::from_span(
+                start,
+                start + length,
+                &event.summary,
+                &event.description,
+            ));
+        }
+    }
+
+    super::in_range(proposals, from, to)
+}
+
+fn is_attending(event: &CalendarEvent, me: Option<&str>) -> bool {
+    let Some(me) = me else {
+        return true;
+    };
+    if event.organizer.as_deref() == Some(me) {
+        return true;
+    }
+    // Events without `me` on the guest list are the user's own
+    event
+        .attendees
+        .iter()
+        .find(|a| a.email == me)
+        .is_none_or(|a| a.status == "ACCEPTED")
+}
+
+/// Starts of an event's occurrences up to the end of `last_day`
+///
+/// Periods of the rule that end before `first_day` are skipped without listing their
+/// occurrences, so old series still reach the range; some earlier starts may be included.
+fn occurrences(event: &CalendarEvent, first_day: Date, last_day: Date) -> Vec<PrimitiveDateTime> {
+    let first = event.start;
+    let rule = match &event.recurrence {
+        Some(rule) if !rule.unsupported && event.recurrence_id.is_none() => rule,
+        _ => {
+            return if first.date() <= last_day {
+                vec![first]
+            } else {
+                Vec::new()
+            };
+        }
+    };
+
+    let interval = rule.interval.max(1) as i64;
+    let (skipped, mut emitted) = skip_periods(rule, first, interval, first_day);
+    let mut starts = Vec::new();
+
+    // Candidates come in chronological order, one period at a time
+    for period in skipped..skipped + MAX_OCCURRENCES as i64 {
+        // None once the period is past the last representable date
+        let Some(candidates) = period_starts(rule, first, period * interval) else {
+            break;
+        };
+        for start in candidates {
+            let past_until = rule.until.is_some_and(|until| start > until);
+            let past_count = rule.count.is_some_and(|count| emitted >= count);
+            if start.date() > last_day || past_until || past_count {
+                return starts;
+            }
+            starts.push(start);
+            emitted += 1;
+        }
+    }
+
+    starts
+}
+
+/// Starts in the period `steps` days, weeks, months or years after the first one
+fn period_starts(
+    rule: &Recurrence,
+    first: PrimitiveDateTime,
+    steps: i64,
+) -> Option<Vec<PrimitiveDateTime>> {
+    match rule.frequency {
+        Frequency::Daily => Some(vec![first.checked_add(Duration::days(steps))?]),
+        Frequency::Weekly => {
+            let week_start = week_start(first.date())?.checked_add(Duration::weeks(steps))?;
+            Some(
+                weekly_days(rule, first)
+                    .into_iter()
+                    .filter_map(|d| week_start.checked_add(Duration::days(d)))
+                    .map(|date| PrimitiveDateTime::new(date, first.time()))
+                    .filter(|start| *start >= first)
+                    .collect(),
+            )
+        }
+        Frequency::Monthly => month_starts(first, steps),
+        Frequency::Yearly => month_starts(first, steps * 12),
+    }
+}
+
+/// The start `months` months later, none if that day doesn't exist, or None past the last
+/// representable year
+fn month_starts(first: PrimitiveDateTime, months: i64) -> Option<Vec<PrimitiveDateTime>> {
+    let year = (first.year() as i64 * 12 + first.month() as i64 - 1 + months).div_euclid(12);
+    if year > Date::MAX.year() as i64 {
+        return None;
+    }
+    Some(add_months(first, months).into_iter().collect())
+}
+
+/// Number of periods that end before `first_day`, and how many occurrences they hold
+fn skip_periods(
+    rule: &Recurrence,
+    first: PrimitiveDateTime,
+    interval: i64,
+    first_day: Date,
+) -> (i64, u32) {
+    let months = |date: Date| date.year() as i64 * 12 + date.month() as i64;
+    // Whole units (days, weeks or months) between the first period and `first_day`
+    let units = match rule.frequency {
+        Frequency::Daily => (first_day - first.date()).whole_days(),
+        Frequency::Weekly => match week_start(first.date()) {
+            Some(week_start) => (first_day - week_start).whole_weeks(),
+            None => 0,
+        },
+        Frequency::Monthly => months(first_day) - months(first.date()),
+        Frequency::Yearly => (months(first_day) - months(first.date())) / 12,
+    };
+    if units <= 0 {
+        return (0, 0);
+    }
+    let skipped = (units + interval - 1) / interval;
+
+    let emitted = match rule.frequency {
+        _ if rule.count.is_none() => 0,
+        Frequency::Daily => skipped,
+        Frequency::Weekly => {
+            let days = weekly_days(rule, first);
+            let in_first = days
+                .iter()
+                .filter(|&&d| d >= first.weekday().number_days_from_monday() as i64)
+                .count() as i64;
+            in_first + (skipped - 1) * days.len() as i64
+        }
+        // Months without the start's day have no occurrence
+        Frequency::Monthly | Frequency::Yearly => {
+            let step = match rule.frequency {
+                Frequency::Monthly => interval,
+                _ => interval * 12,
+            };
+            (0..skipped)
+                .filter(|period| add_months(first, period * step).is_some())
+                .count() as i64
+        }
+    };
+    (skipped, u32::try_from(emitted).unwrap_or(u32::MAX))
+}
+
+/// Monday of the week `date` is in
+fn week_start(date: Date) -> Option<Date> {
+    date.checked_sub(Duration::days(
+        date.weekday().number_days_from_monday() as i64
+    ))
+}
+
+/// Days of a weekly rule, counted from Monday
+fn weekly_days(rule: &Recurrence, first: PrimitiveDateTime) -> Vec<i64> {
+    let mut days: Vec<i64> = if rule.weekdays.is_empty() {
+        vec![first.weekday().number_days_from_monday() as i64]
+    } else {
+        rule.weekdays
+            .iter()
+            .map(|d| d.number_days_from_monday() as i64)
+            .collect()
+    };
+    days.sort_unstable();
+    days.dedup();
+    days
+}
+
+/// The same day and time `months` months later, if that day exists
+fn add_months(start: PrimitiveDateTime, months: i64) -> Option<PrimitiveDateTime> {
+    let index = start.year() as i64 * 12 + (start.month() as i64 - 1) + months;
+    let year = i32::try_from(index.div_euclid(12)).ok()?;
+    let month = Month::try_from((index.rem_euclid(12) + 1) as u8).ok()?;
+    let date = Date::from_calendar_date(year, month, start.day()).ok()?;
+    Some(PrimitiveDateTime::new(date, start.time()))
+}
+
+/// A content line: `NAME;PARAM=VALUE:value`
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    fn parse(line: &str) -> Option<Self> {
+        // The value starts at the first colon outside a quoted parameter value
+        let mut in_quotes = false;
+        let colon = line.char_indices().find_map(|(i, c)| match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                None
+            }
+            ':' if !in_quotes => Some(i),
+            _ => None,
+        })?;
+
+        let mut parts = split_unquoted(&line[..colon], ';').into_iter();
+        let name = parts.next()?.trim().to_ascii_uppercase();
+        let params = parts
+            .filter_map(|param| {
+                let (key, value) = param.split_once('=')?;
+                Some((
+                    key.trim().to_ascii_uppercase(),
+                    value.trim().trim_matches('"').to_string(),
+                ))
+            })
+            .collect();
+
+        Some(Property {
+            name,
+            params,
+            value: line[colon + 1..].to_string(),
+        })
+    }
+
+    fn param(&self, key: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+fn split_unquoted(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        } else if c == separator && !in_quotes {
+            parts.push(&text[start..i]);
+            start = i + 1;
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+fn build_event(
+    properties: &[Property],
+    zones: &HashMap<String, TimeZone>,
+    local: LocalZone,
+) -> Result<CalendarEvent, String> {
+    let find = |name: &str| properties.iter().find(|p| p.name == name);
+    let parse_date_time = |property: &Property| parse_date_time(property, zones, local);
+
+    let dtstart = find("DTSTART").ok_or("Event without DTSTART")?;
+    let (start, all_day) = parse_date_time(dtstart)?;
+    let end = match (find("DTEND"), find("DURATION")) {
+        (Some(dtend), _) => parse_date_time(dtend)?.0,
+        (None, Some(duration)) => start + parse_duration(&duration.value)?,
+        (None, None) if all_day => start + Duration::DAY,
+        (None, None) => start,
+    };
+
+    let mut exdates = Vec::new();
+    for exdate in properties.iter().filter(|p| p.name == "EXDATE") {
+        for value in exdate.value.split(',') {
+            let single = Property {
+                name: exdate.name.clone(),
+                params: exdate.params.clone(),
+                value: value.to_string(),
+            };
+            exdates.push(parse_date_time(&single)?.0);
+        }
+    }
+
+    Ok(CalendarEvent {
+        uid: find("UID").map(|p| p.value.clone()).unwrap_or_default(),
+        summary: find("SUMMARY")
+            .map(|p| unescape_text(&p.value))
+            .unwrap_or_default(),
+        description: find("DESCRIPTION")
+            .map(|p| unescape_text(&p.value))
+            .unwrap_or_default(),
+        start,
+        end,
+        all_day,
+        cancelled: find("STATUS").is_some_and(|p| p.value.eq_ignore_ascii_case("CANCELLED")),
+        organizer: find("ORGANIZER").map(|p| email(&p.value)),
+        attendees: properties
+            .iter()
+            .filter(|p| p.name == "ATTENDEE")
+            .map(|p| Attendee {
+                email: email(&p.value),
+                status: p
+                    .param("PARTSTAT")
+                    .unwrap_or("NEEDS-ACTION")
+                    .to_ascii_uppercase(),
+            })
+            .collect(),
+        recurrence: find("RRULE")
+            .map(|p| parse_recurrence(&p.value, local))
+            .transpose()?,
+        exdates,
+        recurrence_id: find("RECURRENCE-ID")
+            .map(|p| parse_date_time(p).map(|(start, _)| start))
+            .transpose()?,
+    })
+}
+
+fn email(value: &str) -> String {
+    let value = value.trim();
+    let value = value
+        .get(..7)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+        .map_or(value, |_| &value[7..]);
+    value.to_lowercase()
+}
+
+/// Parse a DATE or DATE-TIME property value in local time, returning whether it was
+/// date-only
+///
+/// The caller must have checked that a TZID parameter names a zone in `zones`.
+fn parse_date_time(
+    property: &Property,
+    zones: &HashMap<String, TimeZone>,
+    local: LocalZone,
+) -> Result<(PrimitiveDateTime, bool), String> {
+    let instant = match (parse_raw_date_time(property)?, property.param("TZID")) {
+        (RawDateTime::Date(date), _) => {
+            return Ok((PrimitiveDateTime::new(date, Time::MIDNIGHT), true));
+        }
+        (RawDateTime::Utc(date_time), _) => date_time.assume_utc(),
+        (RawDateTime::Local(date_time), Some(id)) => {
+            let zone = zone_for(id, zones)
+                .ok_or_else(|| format!("Unknown time zone '{}' in {}", id, property.name))?;
+            date_time.assume_offset(zone.offset_at(date_time))
+        }
+        // Floating time: the same wall-clock time everywhere
+        (RawDateTime::Local(date_time), None) => return Ok((date_time, false)),
+    };
+    Ok((local.to_local(instant), false))
+}
+
+/// A DATE or DATE-TIME value as written
+enum RawDateTime {
+    Date(Date),
+    /// Floating, or in the zone of a TZID parameter
+    Local(PrimitiveDateTime),
+    /// Ending in `Z`
+    Utc(PrimitiveDateTime),
+}
+
+fn parse_raw_date_time(property: &Property) -> Result<RawDateTime, String> {
+    let value = property.value.trim();
+    let invalid = || format!("Invalid {} '{}'", property.name, value);
+
+    let date = parse_date(value.get(..8).ok_or_else(invalid)?).ok_or_else(invalid)?;
+    if value.len() == 8 || property.param("VALUE") == Some("DATE") {
+        return Ok(RawDateTime::Date(date));
+    }
+
+    let time_part = value
+        .get(8..)
+        .and_then(|rest| rest.strip_prefix('T'))
+        .ok_or_else(invalid)?;
+    let (digits, utc) = match time_part.strip_suffix('Z') {
+        Some(digits) => (digits, true),
+        None => (time_part, false),
+    };
+    let number = |range: std::ops::Range<usize>| {
+        digits
+            .get(range)
+            .and_then(|s| s.parse::<u8>().ok())
+            .ok_or_else(invalid)
+    };
+    if digits.len() != 6 {
+        return Err(invalid());
+    }
+    // Leap seconds (:60) are folded into the minute
+    let time = Time::from_hms(number(0..2)?, number(2..4)?, number(4..6)?.min(59))
+        .map_err(|_| invalid())?;
+
+    let date_time = PrimitiveDateTime::new(date, time);
+    Ok(if utc {
+        RawDateTime::Utc(date_time)
+    } else {
+        RawDateTime::Local(date_time)
+    })
+}
+
+/// A time zone defined by a VTIMEZONE component
+#[derive(Debug, Clone, PartialEq)]
+struct TimeZone {
+    observances: Vec<Observance>,
+}
+
+/// The zone used for a TZID: a VTIMEZONE of the document, or UTC by name
+fn zone_for<'a>(id: &str, zones: &'a HashMap<String, TimeZone>) -> Option<&'a TimeZone> {
+    static UTC_ZONE: TimeZone = TimeZone {
+        observances: Vec::new(),
+    };
+    match zones.get(id) {
+        Some(zone) => Some(zone),
+        None if ["UTC", "GMT", "Etc/UTC", "Etc/GMT", "Z"]
+            .iter()
+            .any(|name| id.eq_ignore_ascii_case(name)) =>
+        {
+            Some(&UTC_ZONE)
+        }
+        None => None,
+    }
+}
+
+impl TimeZone {
+    /// UTC offset in effect at a wall-clock time of this zone
+    fn offset_at(&self, wall: PrimitiveDateTime) -> UtcOffset {
+        let latest = self
+            .observances
+            .iter()
+            .filter_map(|o| Some((o.latest_onset(wall)?, o)))
+            .max_by_key(|(onset, _)| *onset);
+        match latest {
+            Some((_, observance)) => observance.offset_to,
+            // Before the first transition: the offset the earliest one changes from
+            None => self
+                .observances
+                .iter()
+                .min_by_key(|o| o.start)
+                .map_or(UtcOffset::UTC, |o| o.offset_from),
+        }
+    }
+}
+
+/// A STANDARD or DAYLIGHT part of a VTIMEZONE: when its offset takes effect
+#[derive(Debug, Clone, PartialEq)]
+struct Observance {
+    /// First onset, in wall-clock time before the change
+    start: PrimitiveDateTime,
+    offset_from: UtcOffset,
+    offset_to: UtcOffset,
+    /// Yearly repeat: the month and its nth weekday (negative: counting from the end)
+    rule: Option<(Month, i8, Weekday)>,
+    /// Last onset of the rule, in UTC
+    until: Option<OffsetDateTime>,
+    /// Further onsets listed with RDATE
+    rdates: Vec<PrimitiveDateTime>,
+}
+
+impl Observance {
+    fn parse(properties: &[Property]) -> Result<Self, String> {
+        let find = |name: &str| properties.iter().find(|p| p.name == name);
+        let offset = |name: &str| {
+            let property = find(name).ok_or_else(|| format!("VTIMEZONE without {}", name))?;
+            parse_utc_offset(&property.value)
+                .ok_or_else(|| format!("Invalid {} '{}'", name, property.value))
+        };
+        let offset_from = offset("TZOFFSETFROM")?;
+        let offset_to = offset("TZOFFSETTO")?;
+        let dtstart = find("DTSTART").ok_or("VTIMEZONE without DTSTART")?;
+        let start = match parse_raw_date_time(dtstart)? {
+            RawDateTime::Date(date) => PrimitiveDateTime::new(date, Time::MIDNIGHT),
+            RawDateTime::Local(start) | RawDateTime::Utc(start) => start,
+        };
+
+        let mut rule = None;
+        let mut until = None;
+        if let Some(rrule) = find("RRULE") {
+            let mut month = None;
+            let mut day = None;
+            for part in rrule.value.split(';') {
+                match part.split_once('=') {
+                    Some(("BYMONTH", value)) => {
+                        month = value
+                            .parse::<u8>()
+                            .ok()
+                            .and_then(|m| Month::try_from(m).ok())
+                    }
+                    Some(("BYDAY", value)) => day = nth_weekday(value),
+                    Some(("UNTIL", value)) => {
+                        let property = Property {
+                            name: "UNTIL".to_string(),
+                            params: Vec::new(),
+                            value: value.to_string(),
+                        };
+                        until = Some(match parse_raw_date_time(&property)? {
+                            RawDateTime::Date(date) => {
+                                PrimitiveDateTime::new(date, Time::MAX).assume_offset(offset_from)
+                            }
+                            RawDateTime::Local(until) => until.assume_offset(offset_from),
+                            RawDateTime::Utc(until) => until.assume_utc(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            // Other rule shapes only keep their first onset
+            if let (Some(month), Some((n, weekday))) = (month, day) {
+                rule = Some((month, n, weekday));
+            }
+        }
+
+        let mut rdates = Vec::new();
+        for rdate in properties.iter().filter(|p| p.name == "RDATE") {
+            for value in rdate.value.split(',') {
+                let single = Property {
+                    name: "RDATE".to_string(),
+                    params: Vec::new(),
+                    value: value.to_string(),
+                };
+                rdates.push(match parse_raw_date_time(&single)? {
+                    RawDateTime::Date(date) => PrimitiveDateTime::new(date, Time::MIDNIGHT),
+                    RawDateTime::Local(onset) | RawDateTime::Utc(onset) => onset,
+                });
+            }
+        }
+
+        Ok(Observance {
+            start,
+            offset_from,
+            offset_to,
+            rule,
+            until,
+            rdates,
+        })
+    }
+
+    /// The last onset at or before a wall-clock time
+    fn latest_onset(&self, wall: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        let mut onsets = vec![self.start];
+        onsets.extend(&self.rdates);
+        if let Some((month, n, weekday)) = self.rule {
+            let mut years = vec![wall.year(), wall.year() - 1];
+            if let Some(until) = self.until {
+                years.extend([until.year(), until.year() - 1]);
+            }
+            for year in years {
+                let Some(date) = nth_weekday_of_month(year, month, n, weekday) else {
+                    continue;
+                };
+                let onset = PrimitiveDateTime::new(date, self.start.time());
+                let within_until = self
+                    .until
+                    .is_none_or(|until| onset.assume_offset(self.offset_from) <= until);
+                if onset >= self.start && within_until {
+                    onsets.push(onset);
+                }
+            }
+        }
+        onsets.into_iter().filter(|onset| *onset <= wall).max()
+    }
+}
+
+/// Parse a TZOFFSETFROM/TO value such as `+0100`, `-0500` or `+053000`
+fn parse_utc_offset(value: &str) -> Option<UtcOffset> {
+    let value = value.trim();
+    let (sign, digits) = match value.split_at_checked(1)? {
+        ("+", digits) => (1, digits),
+        ("-", digits) => (-1, digits),
+        _ => return None,
+    };
+    if !matches!(digits.len(), 4 | 6) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let part = |range: std::ops::Range<usize>| digits.get(range)?.parse::<i8>().ok();
+    let seconds = if digits.len() == 6 { part(4..6)? } else { 0 };
+    UtcOffset::from_hms(sign * part(0..2)?, sign * part(2..4)?, sign * seconds).ok()
+}
+
+/// Parse a BYDAY value such as `-1SU` or `2SU`
+fn nth_weekday(value: &str) -> Option<(i8, Weekday)> {
+    let value = value.trim();
+    let split = value.len().checked_sub(2)?;
+    let n: i8 = value.get(..split)?.trim_start_matches('+').parse().ok()?;
+    Some((n, weekday(value.get(split..)?)?))
+}
+
+/// The nth weekday of a month, counting from the end if `n` is negative
+fn nth_weekday_of_month(year: i32, month: Month, n: i8, weekday: Weekday) -> Option<Date> {
+    if n > 0 {
+        let first = Date::from_calendar_date(year, month, 1).ok()?;
+        let ahead =
+            (weekday.number_days_from_monday() + 7 - first.weekday().number_days_from_monday()) % 7;
+        let date = first + Duration::days(ahead as i64) + Duration::weeks(n as i64 - 1);
+        (date.month() == month).then_some(date)
+    } else if n < 0 {
+        let last = Date::from_calendar_date(year, month, month.length(year)).ok()?;
+        let back =
+            (last.weekday().number_days_from_monday() + 7 - weekday.number_days_from_monday()) % 7;
+        let date = last - Duration::days(back as i64) - Duration::weeks(-n as i64 - 1);
+        (date.month() == month).then_some(date)
+    } else {
+        None
+    }
+}
+
+fn parse_date(value: &str) -> Option<Date> {
+    let year = value.get(0..4)?.parse().ok()?;
+    let month = Month::try_from(value.get(4..6)?.parse::<u8>().ok()?).ok()?;
+    let day = value.get(6..8)?.parse().ok()?;
+    Date::from_calendar_date(year, month, day).ok()
+}
+
+/// Parse a DURATION value such as `PT1H30M` or `P1W`
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let invalid = || format!("Invalid DURATION '{}'", value);
+    let body = value.trim();
+    let body = body.strip_prefix('+').unwrap_or(body);
+    let body = body.strip_prefix('P').ok_or_else(invalid)?;
+
+    let mut total = Duration::ZERO;
+    let mut number = String::new();
+    let mut in_time = false;
+    for c in body.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' if number.is_empty() => in_time = true,
+            _ => {
+                let n: i64 = number.parse().map_err(|_| invalid())?;
+                number.clear();
+                total += match (c, in_time) {
+                    ('W', false) => Duration::weeks(n),
+                    ('D', false) => Duration::days(n),
+                    ('H', true) => Duration::hours(n),
+                    ('M', true) => Duration::minutes(n),
+                    ('S', true) => Duration::seconds(n),
+                    _ => return Err(invalid()),
+                };
+            }
+        }
+    }
+    if !number.is_empty() {
+        return Err(invalid());
+    }
+    Ok(total)
+}
+
+fn parse_recurrence(value: &str, local: LocalZone) -> Result<Recurrence, String> {
+    let mut rule = Recurrence {
+        frequency: Frequency::Daily,
+        interval: 1,
+        count: None,
+        until: None,
+        weekdays: Vec::new(),
+        unsupported: false,
+    };
+    let mut frequency = None;
+
+    for part in value.split(';').filter(|p| !p.is_empty()) {
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid RRULE '{}'", part))?;
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => {
+                frequency = match value.to_ascii_uppercase().as_str() {
+                    "DAILY" => Some(Frequency::Daily),
+                    "WEEKLY" => Some(Frequency::Weekly),
+                    "MONTHLY" => Some(Frequency::Monthly),
+                    "YEARLY" => Some(Frequency::Yearly),
+                    _ => None,
+                }
+            }
+            "INTERVAL" => {
+                rule.interval = match value.parse() {
+                    Ok(interval) if interval > MAX_INTERVAL => {
+                        return Err(format!("RRULE INTERVAL {} is too large", interval));
+                    }
+                    Ok(interval) => interval,
+                    Err(_) => 1,
+                }
+            }
+            "COUNT" => rule.count = value.parse().ok(),
+            "UNTIL" => {
+                let until = Property {
+                    name: "UNTIL".to_string(),
+                    params: Vec::new(),
+                    value: value.to_string(),
+                };
+                let (until, date_only) = parse_date_time(&until, &HashMap::new(), local)?;
+                // A date-only UNTIL includes that whole day
+                rule.until = Some(if date_only {
+                    until + Duration::DAY - Duration::SECOND
+                } else {
+                    until
+                });
+            }
+            "BYDAY" => {
+                for day in value.split(',') {
+                    match weekday(day) {
+                        Some(day) => rule.weekdays.push(day),
+                        None => rule.unsupported = true,
+                    }
+                }
+            }
+            "WKST" => {}
+            _ => rule.unsupported = true,
+        }
+    }
+
+    match frequency {
+        Some(frequency) => rule.frequency = frequency,
+        None => rule.unsupported = true,
+    }
+    // BYDAY on monthly/yearly rules means "the 2nd Tuesday" etc.
+    if rule.frequency != Frequency::Weekly && !rule.weekdays.is_empty() {
+        rule.unsupported = true;
+    }
+    Ok(rule)
+}
+
+fn weekday(value: &str) -> Option<Weekday> {
+    use Weekday::*;
+    Some(match value.trim().to_ascii_uppercase().as_str() {
+        "MO" => Monday,
+        "TU" => Tuesday,
+        "WE" => Wednesday,
+        "TH" => Thursday,
+        "FR" => Friday,
+        "SA" => Saturday,
+        "SU" => Sunday,
+        _ => return None,
+    })
+}
+
+/// Join folded lines (continuations start with a space or tab)
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ if line.trim().is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Append a content line, folded to at most 75 octets per line, with CRLF
+fn push_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn unescape_text(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => unescaped.push('\n'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+fn format_date_time(date_time: PrimitiveDateTime) -> String {
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}",
+        date_time.year(),
+        date_time.month() as u8,
+        date_time.day(),
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{TimePoint, WorkRecord};
+    use time::macros::{date, datetime, offset};
+
+    fn record(id: u32, name: &str, start: &str, end: &str, description: &str) -> WorkRecord {
+        let mut record = WorkRecord::new(
+            id,
+            name.to_string(),
+            TimePoint::parse(start).unwrap(),
+            TimePoint::parse(end).unwrap(),
+        );
+        record.description = description.to_string();
+        record
+    }
+
+    fn calendar(events: &str) -> String {
+        format!(
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\n{}END:VCALENDAR\r\n",
+            events
+        )
+    }
+
+    #[test]
+    fn test_export_events() {
+        let mut day = DayData::new(date!(2026 - 10 - 17));
+        day.add_record(record(2, "Standup", "09:00", "09:15", ""));
+        day.add_record(record(
+            1,
+            "PROJ-1, review; fix",
+            "23:30",
+            "00:30",
+            "PR\nnotes",
+        ));
+
+        let ics = export(&[day], datetime!(2026-10-18 08:00 UTC));
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains(
+            "UID:2026-10-17-2@work-tuimer\r\nDTSTAMP:20261018T080000Z\r\nDTSTART:20261017T090000\r\nDTEND:20261017T091500\r\nSUMMARY:Standup\r\nEND:VEVENT"
+        ));
+        // Past midnight ends on the next day; text is escaped
+        assert!(ics.contains("DTSTART:20261017T233000\r\nDTEND:20261018T003000\r\n"));
+        assert!(ics.contains("SUMMARY:PROJ-1\\, review\\; fix\r\nDESCRIPTION:PR\\nnotes\r\n"));
+        // Sorted by start time
+        assert!(ics.find("Standup").unwrap() < ics.find("PROJ-1").unwrap());
+    }
+
+    #[test]
+    fn test_long_lines_are_folded() {
+        let mut out = String::new();
+        push_line(&mut out, &format!("SUMMARY:{}", "é".repeat(60)));
+
+        for line in out.split("\r\n") {
+            assert!(line.len() <= MAX_LINE_OCTETS);
+        }
+        assert_eq!(unfold(&out), vec![format!("SUMMARY:{}", "é".repeat(60))]);
+    }
+
+    #[test]
+    fn test_export_round_trip() {
+        let mut day = DayData::new(date!(2026 - 10 - 17));
+        day.add_record(record(
+            1,
+            "Design, review",
+            "13:00",
+            "14:30",
+            "Line 1\nLine 2",
+        ));
+
+        let ics = export(&[day], datetime!(2026-10-18 08:00 UTC));
+        let events = parse(&ics, LocalZone::Fixed(UtcOffset::UTC))
+            .unwrap()
+            .events;
+        let proposals =
+            propose_records(&events, date!(2026 - 10 - 17), date!(2026 - 10 - 17), None);
+
+        assert_eq!(
+            proposals,
+            vec![ProposedRecord {
+                date: date!(2026 - 10 - 17),
+                name: "Design, review".to_string(),
+                start: TimePoint::new(13, 0).unwrap(),
+                end: TimePoint::new(14, 30).unwrap(),
+                description: "Line 1\nLine 2".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_event_properties() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\n\
+             UID:abc\r\n\
+             SUMMARY:Planning\r\n\
+             DTSTART:20261017T120000Z\r\n\
+             DURATION:PT1H30M\r\n\
+             ORGANIZER;CN=\"Boss: The\":mailto:Boss@Example.com\r\n\
+             ATTENDEE;PARTSTAT=ACCEPTED;CN=Me:mailto:me@example.com\r\n\
+             DESCRIPTION:Long agen\r\n da\r\n\
+             BEGIN:VALARM\r\n\
+             DESCRIPTION:Reminder\r\n\
+             END:VALARM\r\n\
+             END:VEVENT\r\n",
+        );
+
+        let events = parse(&ics, LocalZone::Fixed(offset!(+2))).unwrap().events;
+
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.summary, "Planning");
+        assert_eq!(event.description, "Long agenda");
+        assert_eq!(event.start, datetime!(2026-10-17 14:00));
+        assert_eq!(event.end, datetime!(2026-10-17 15:30));
+        assert_eq!(event.organizer.as_deref(), Some("boss@example.com"));
+        assert_eq!(event.attendees[0].email, "me@example.com");
+        assert_eq!(event.attendees[0].status, "ACCEPTED");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("hello", LocalZone::Fixed(UtcOffset::UTC)).is_err());
+
+        let ics = calendar("BEGIN:VEVENT\r\nDTSTART:2026-10-17\r\nEND:VEVENT\r\n");
+        let error = parse(&ics, LocalZone::Fixed(UtcOffset::UTC)).unwrap_err();
+        assert!(error.contains("DTSTART"));
+    }
+
+    #[test]
+    fn test_propose_only_attended_events() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Accepted\r\nDTSTART:20261017T090000\r\nDTEND:20261017T093000\r\n\
+             ATTENDEE;PARTSTAT=ACCEPTED:mailto:me@example.com\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:2\r\nSUMMARY:Declined\r\nDTSTART:20261017T100000\r\nDTEND:20261017T110000\r\n\
+             ATTENDEE;PARTSTAT=DECLINED:mailto:me@example.com\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:3\r\nSUMMARY:Cancelled\r\nSTATUS:CANCELLED\r\nDTSTART:20261017T110000\r\nDTEND:20261017T120000\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:4\r\nSUMMARY:Holiday\r\nDTSTART;VALUE=DATE:20261017\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:5\r\nSUMMARY:Focus time\r\nDTSTART:20261017T140000\r\nDTEND:20261017T150000\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:6\r\nSUMMARY:Tomorrow\r\nDTSTART:20261018T090000\r\nDTEND:20261018T100000\r\nEND:VEVENT\r\n",
+        );
+        let events = parse(&ics, LocalZone::Fixed(UtcOffset::UTC))
+            .unwrap()
+            .events;
+        let day = date!(2026 - 10 - 17);
+
+        let names = |me| -> Vec<String> {
+            propose_records(&events, day, day, me)
+                .into_iter()
+                .map(|p| p.name)
+                .collect()
+        };
+
+        assert_eq!(
+            names(Some("ME@example.com")),
+            vec!["Accepted", "Focus time"]
+        );
+        assert_eq!(names(None), vec!["Accepted", "Declined", "Focus time"]);
+    }
+
+    #[test]
+    fn test_propose_recurring_events() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:standup\r\nSUMMARY:Standup\r\n\
+             DTSTART:20261005T091500\r\nDTEND:20261005T093000\r\n\
+             RRULE:FREQ=WEEKLY;BYDAY=MO,WE,FR;UNTIL=20261016\r\n\
+             EXDATE:20261007T091500\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:standup\r\nSUMMARY:Standup (moved)\r\n\
+             RECURRENCE-ID:20261009T091500\r\n\
+             DTSTART:20261009T100000\r\nDTEND:20261009T101500\r\nEND:VEVENT\r\n",
+        );
+        let events = parse(&ics, LocalZone::Fixed(UtcOffset::UTC))
+            .unwrap()
+            .events;
+
+        let proposals =
+            propose_records(&events, date!(2026 - 10 - 06), date!(2026 - 10 - 31), None);
+        let summary: Vec<(Date, String, String)> = proposals
+            .into_iter()
+            .map(|p| (p.date, p.start.to_string(), p.name))
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    date!(2026 - 10 - 09),
+                    "10:00".to_string(),
+                    "Standup (moved)".to_string()
+                ),
+                (
+                    date!(2026 - 10 - 12),
+                    "09:15".to_string(),
+                    "Standup".to_string()
+                ),
+                (
+                    date!(2026 - 10 - 14),
+                    "09:15".to_string(),
+                    "Standup".to_string()
+                ),
+                (
+                    date!(2026 - 10 - 16),
+                    "09:15".to_string(),
+                    "Standup".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_recurrence_count_and_months() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:retro\r\nSUMMARY:Retro\r\n\
+             DTSTART:20260131T150000\r\nDTEND:20260131T160000\r\n\
+             RRULE:FREQ=MONTHLY;COUNT=3\r\nEND:VEVENT\r\n",
+        );
+        let events = parse(&ics, LocalZone::Fixed(UtcOffset::UTC))
+            .unwrap()
+            .events;
+
+        let dates: Vec<Date> =
+            propose_records(&events, date!(2026 - 01 - 01), date!(2026 - 12 - 31), None)
+                .into_iter()
+                .map(|p| p.date)
+                .collect();
+        // February has no 31st; COUNT counts only real occurrences
+        assert_eq!(
+            dates,
+            vec![
+                date!(2026 - 01 - 31),
+                date!(2026 - 03 - 31),
+                date!(2026 - 05 - 31)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_old_series_reach_the_range() {
+        // More than MAX_OCCURRENCES occurrences before the range
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:daily\r\nSUMMARY:Daily\r\n\
+             DTSTART:19800102T090000\r\nDTEND:19800102T091500\r\n\
+             RRULE:FREQ=DAILY\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:weekly\r\nSUMMARY:Workdays\r\n\
+             DTSTART:19800102T100000\r\nDTEND:19800102T110000\r\n\
+             RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TU,WE,TH,FR\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:monthly\r\nSUMMARY:Monthly\r\n\
+             DTSTART:19800131T150000\r\nDTEND:19800131T160000\r\n\
+             RRULE:FREQ=MONTHLY\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:counted\r\nSUMMARY:Counted\r\n\
+             DTSTART:19800102T120000\r\nDTEND:19800102T130000\r\n\
+             RRULE:FREQ=DAILY;COUNT=5\r\nEND:VEVENT\r\n",
+        );
+        let events = parse(&ics, LocalZone::Fixed(UtcOffset::UTC))
+            .unwrap()
+            .events;
+
+        let proposals =
+            propose_records(&events, date!(2026 - 10 - 16), date!(2026 - 10 - 31), None);
+        let count = |name: &str| proposals.iter().filter(|p| p.name == name).count();
+        assert_eq!(count("Daily"), 16);
+        // Every other week from the week of 1980-01-02: 2442 weeks later is Oct 19
+        let workdays: Vec<Date> = proposals
+            .iter()
+            .filter(|p| p.name == "Workdays")
+            .map(|p| p.date)
+            .collect();
+        assert_eq!(
+            workdays,
+            vec![
+                date!(2026 - 10 - 19),
+                date!(2026 - 10 - 20),
+                date!(2026 - 10 - 21),
+                date!(2026 - 10 - 22),
+                date!(2026 - 10 - 23)
+            ]
+        );
+        assert_eq!(count("Monthly"), 1);
+        assert_eq!(count("Counted"), 0);
+    }
+
+    #[test]
+    fn test_large_intervals_do_not_overflow() {
+        let rule = |rule: &str| {
+            calendar(&format!(
+                "BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Late\r\n\
+                 DTSTART:99991230T090000\r\nDTEND:99991230T100000\r\n\
+                 RRULE:{}\r\nEND:VEVENT\r\n",
+                rule
+            ))
+        };
+
+        let error = parse(
+            &rule("FREQ=DAILY;INTERVAL=4000000000"),
+            LocalZone::Fixed(UtcOffset::UTC),
+        )
+        .unwrap_err();
+        assert!(error.contains("INTERVAL"));
+
+        for rule in [
+            rule("FREQ=DAILY"),
+            rule("FREQ=WEEKLY;INTERVAL=1000"),
+            rule("FREQ=YEARLY;INTERVAL=1000"),
+        ] {
+            let events = parse(&rule, LocalZone::Fixed(UtcOffset::UTC))
+                .unwrap()
+                .events;
+            let proposals = propose_records(&events, Date::MIN, Date::MAX, None);
+            assert!(!proposals.is_empty());
+            assert!(proposals.len() <= 2);
+        }
+    }
+
+    /// Central European time as exported by calendar apps, with a VTIMEZONE per TZID
+    const BERLIN: &str = "BEGIN:VTIMEZONE\r\nTZID:Europe/Berlin\r\n\
+         BEGIN:DAYLIGHT\r\nTZOFFSETFROM:+0100\r\nTZOFFSETTO:+0200\r\n\
+         DTSTART:19700329T020000\r\nRRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r\nEND:DAYLIGHT\r\n\
+         BEGIN:STANDARD\r\nTZOFFSETFROM:+0200\r\nTZOFFSETTO:+0100\r\n\
+         DTSTART:19701025T030000\r\nRRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r\nEND:STANDARD\r\n\
+         END:VTIMEZONE\r\n";
+
+    #[test]
+    fn test_tzid_times_use_their_time_zone() {
+        let ics = calendar(&format!(
+            "BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Summer\r\n\
+             DTSTART;TZID=Europe/Berlin:20261017T090000\r\n\
+             DTEND;TZID=Europe/Berlin:20261017T100000\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:2\r\nSUMMARY:Winter\r\n\
+             DTSTART;TZID=Europe/Berlin:20261110T090000\r\nDURATION:PT1H\r\nEND:VEVENT\r\n\
+             {}",
+            // Defined after the events using it
+            BERLIN
+        ));
+
+        let calendar = parse(&ics, LocalZone::Fixed(UtcOffset::UTC)).unwrap();
+        assert!(calendar.skipped.is_empty());
+        let starts: Vec<PrimitiveDateTime> = calendar.events.iter().map(|e| e.start).collect();
+        assert_eq!(
+            starts,
+            vec![datetime!(2026-10-17 07:00), datetime!(2026-11-10 08:00)]
+        );
+        assert_eq!(calendar.events[0].end, datetime!(2026-10-17 08:00));
+
+        // Already in the event's zone: wall-clock times are kept
+        let events = parse(&ics, LocalZone::Fixed(offset!(+2))).unwrap().events;
+        assert_eq!(events[0].start, datetime!(2026-10-17 09:00));
+    }
+
+    #[test]
+    fn test_vtimezone_rules_end_at_until() {
+        // US Eastern: DST moved from April to March in 2007
+        let ics = calendar(
+            "BEGIN:VTIMEZONE\r\nTZID:America/New_York\r\n\
+             BEGIN:DAYLIGHT\r\nTZOFFSETFROM:-0500\r\nTZOFFSETTO:-0400\r\nDTSTART:19870405T020000\r\n\
+             RRULE:FREQ=YEARLY;BYMONTH=4;BYDAY=1SU;UNTIL=20060402T070000Z\r\nEND:DAYLIGHT\r\n\
+             BEGIN:STANDARD\r\nTZOFFSETFROM:-0400\r\nTZOFFSETTO:-0500\r\nDTSTART:19671029T020000\r\n\
+             RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU;UNTIL=20061029T060000Z\r\nEND:STANDARD\r\n\
+             BEGIN:DAYLIGHT\r\nTZOFFSETFROM:-0500\r\nTZOFFSETTO:-0400\r\nDTSTART:20070311T020000\r\n\
+             RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=2SU\r\nEND:DAYLIGHT\r\n\
+             BEGIN:STANDARD\r\nTZOFFSETFROM:-0400\r\nTZOFFSETTO:-0500\r\nDTSTART:20071104T020000\r\n\
+             RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=1SU\r\nEND:STANDARD\r\n\
+             END:VTIMEZONE\r\n\
+             BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Spring\r\n\
+             DTSTART;TZID=America/New_York:20260320T090000\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:2\r\nSUMMARY:Fall\r\n\
+             DTSTART;TZID=America/New_York:20261030T090000\r\nEND:VEVENT\r\n",
+        );
+
+        let events = parse(&ics, LocalZone::Fixed(UtcOffset::UTC))
+            .unwrap()
+            .events;
+        assert_eq!(events[0].start, datetime!(2026-03-20 13:00));
+        assert_eq!(events[1].start, datetime!(2026-10-30 13:00));
+    }
+
+    #[test]
+    fn test_events_in_undefined_time_zones_are_skipped() {
+        let ics = calendar(
+            "BEGIN:VEVENT\r\nUID:1\r\nSUMMARY:Offsite\r\n\
+             DTSTART;TZID=Mars/Olympus:20261017T090000\r\nEND:VEVENT\r\n\
+             BEGIN:VEVENT\r\nUID:2\r\nSUMMARY:Sync\r\n\
+             DTSTART;TZID=UTC:20261017T090000\r\nEND:VEVENT\r\n",
+        );
+
+        let calendar = parse(&ics, LocalZone::Fixed(offset!(+2))).unwrap();
+        assert_eq!(calendar.events.len(), 1);
+        assert_eq!(calendar.events[0].start, datetime!(2026-10-17 11:00));
+        assert_eq!(
+            calendar.skipped,
+            vec!["'Offsite': time zone 'Mars/Olympus' is not defined in the file"]
+        );
+    }
+
+    #[test]
+    fn test_nth_weekday_of_month() {
+        use time::Weekday::Sunday;
+        assert_eq!(
+            nth_weekday_of_month(2026, Month::March, -1, Sunday),
+            Some(date!(2026 - 03 - 29))
+        );
+        assert_eq!(
+            nth_weekday_of_month(2026, Month::March, 2, Sunday),
+            Some(date!(2026 - 03 - 08))
+        );
+        assert_eq!(nth_weekday_of_month(2026, Month::February, 5, Sunday), None);
+        assert_eq!(
+            parse_utc_offset("-0430"),
+            UtcOffset::from_hms(-4, -30, 0).ok()
+        );
+        assert_eq!(parse_utc_offset("0100"), None);
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Ok(Duration::minutes(90)));
+        assert_eq!(parse_duration("P1W"), Ok(Duration::weeks(1)));
+        assert_eq!(parse_duration("P1DT2H"), Ok(Duration::hours(26)));
+        assert!(parse_duration("1H").is_err());
+        assert!(parse_duration("PT5").is_err());
+    }
+}
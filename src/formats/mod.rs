@@ -0,0 +1,171 @@
+//! File formats for exchanging records with other tools
+//!
+//! Importers turn another tool's entries into `ProposedRecord`s, which the caller merges
+//! into the matching days; exporters write days of records. `timesheet` renders them for
+//! people instead.
+
+pub mod ics;
+pub mod timesheet;
+pub mod timewarrior;
+pub mod toggl;
+
+use crate::models::{DayData, TimePoint, WorkRecord};
+use serde::Serialize;
+use time::{Date, Duration, PrimitiveDateTime, Time};
+
+/// A record suggested by an imported entry
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProposedRecord {
+    pub date: Date,
+    pub name: String,
+    pub start: TimePoint,
+    pub end: TimePoint,
+    pub description: String,
+}
+
+impl ProposedRecord {
+    /// Propose a record for a local time span, on the day it starts
+    ///
+    /// Returns None for unnamed entries and spans that are empty (under a minute) or a day
+    /// or longer, which a record can't hold.
+    pub fn from_span(
+        start: PrimitiveDateTime,
+        end: PrimitiveDateTime,
+        name: &str,
+        description: &str,
+    ) -> Option<Self> {
+        let name = name.trim();
+        let length = end - start;
+        if name.is_empty() || length <= Duration::ZERO || length >= Duration::DAY {
+            return None;
+        }
+
+        let proposal = ProposedRecord {
+            date: start.date(),
+            name: name.to_string(),
+            start: to_time_point(start.time()),
+            end: to_time_point(end.time()),
+            description: description.trim().to_string(),
+        };
+        (proposal.start != proposal.end).then_some(proposal)
+    }
+
+    pub fn into_record(self, id: u32) -> WorkRecord {
+        let mut record = WorkRecord::new(id, self.name, self.start, self.end);
+        record.description = self.description;
+        record
+    }
+
+    /// Whether `record` already covers this proposal (same name and times)
+    pub fn matches(&self, record: &WorkRecord) -> bool {
+        record.name == self.name && record.start == self.start && record.end == self.end
+    }
+}
+
+/// Keep the proposals between `from` and `to` (inclusive), sorted by start
+pub fn in_range(mut proposals: Vec<ProposedRecord>, from: Date, to: Date) -> Vec<ProposedRecord> {
+    proposals.retain(|p| p.date >= from && p.date <= to);
+    proposals.sort_by_key(|p| (p.date, p.start));
+    proposals
+}
+
+/// Local start and end of a record; records past midnight end on the next day
+pub fn record_span(day: &DayData, record: &WorkRecord) -> (PrimitiveDateTime, PrimitiveDateTime) {
+    let start = PrimitiveDateTime::new(day.date, to_time(record.start));
+    (
+        start,
+        start + Duration::minutes(record.total_minutes as i64),
+    )
+}
+
+/// Split `#tag` words off the end of a description
+///
+/// Other tools keep tags apart from notes; records keep them as trailing `#tag` words.
+/// `#123` is an issue reference, not a tag.
+pub fn split_tags(description: &str) -> (String, Vec<String>) {
+    let mut words: Vec<&str> = description.split_whitespace().collect();
+    let mut tags = Vec::new();
+    while let Some(tag) = words.last().and_then(|word| word.strip_prefix('#')) {
+        if tag.is_empty() || tag.chars().all(|c| c.is_ascii_digit()) {
+            break;
+        }
+        tags.insert(0, tag.to_string());
+        words.pop();
+    }
+
+    if tags.is_empty() {
+        return (description.trim().to_string(), tags);
+    }
+    (words.join(" "), tags)
+}
+
+/// Append tags to a description as `#tag` words (inverse of `split_tags`)
+pub fn join_tags(text: &str, tags: &[String]) -> String {
+    let tags = tags
+        .iter()
+        .map(|tag| tag.split_whitespace().collect::<Vec<_>>().join("-"))
+        .filter(|tag| !tag.is_empty())
+        .map(|tag| format!("#{}", tag));
+    std::iter::once(text.trim().to_string())
+        .filter(|text| !text.is_empty())
+        .chain(tags)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn to_time(point: TimePoint) -> Time {
+    Time::from_hms(point.hour, point.minute, 0).expect("TimePoint is a valid time")
+}
+
+fn to_time_point(time: Time) -> TimePoint {
+    TimePoint {
+        hour: time.hour(),
+        minute: time.minute(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn test_from_span() {
+        let proposal = ProposedRecord::from_span(
+            datetime!(2026-10-17 23:30),
+            datetime!(2026-10-18 00:45:30),
+            " Deploy ",
+            "",
+        )
+        .unwrap();
+        assert_eq!(proposal.date, time::macros::date!(2026 - 10 - 17));
+        assert_eq!(proposal.name, "Deploy");
+        assert_eq!(proposal.end, TimePoint::new(0, 45).unwrap());
+
+        let start = datetime!(2026-10-17 09:00);
+        assert!(ProposedRecord::from_span(start, start + Duration::DAY, "Long", "").is_none());
+        assert!(ProposedRecord::from_span(start, start + Duration::SECOND, "Short", "").is_none());
+        assert!(ProposedRecord::from_span(start, start + Duration::HOUR, " ", "").is_none());
+    }
+
+    #[test]
+    fn test_split_and_join_tags() {
+        assert_eq!(
+            split_tags("Website redesign #billable #meeting"),
+            (
+                "Website redesign".to_string(),
+                vec!["billable".to_string(), "meeting".to_string()]
+            )
+        );
+        // Issue references and inner hashes stay in the text
+        assert_eq!(split_tags("Fix #42"), ("Fix #42".to_string(), vec![]));
+        assert_eq!(
+            split_tags("#a in the middle"),
+            ("#a in the middle".to_string(), vec![])
+        );
+
+        let tags = vec!["billable".to_string(), "deep work".to_string()];
+        assert_eq!(join_tags("Website", &tags), "Website #billable #deep-work");
+        assert_eq!(join_tags("", &tags[..1]), "#billable");
+    }
+}
@@ -0,0 +1,646 @@
+//! Printable timesheets
+//!
+//! A week or month of records becomes a grid of tasks × days with totals, rendered into
+//! an HTML or Markdown template. Templates use `{placeholder}` substitution like
+//! `session prompt` (other braces, e.g. in CSS, are left alone); the built-in ones can be
+//! printed and copied as a starting point.
+
+use crate::models::DayData;
+use time::{Date, Duration, OffsetDateTime, Weekday};
+
+/// Built-in HTML template (self-contained, prints on landscape A4)
+const HTML_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+  @page { size: A4 landscape; margin: 12mm; }
+  body { font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; font-size: 10pt; color: #222; margin: 0; }
+  h1 { font-size: 16pt; margin: 0 0 2pt; }
+  h2 { font-size: 12pt; margin: 16pt 0 4pt; }
+  .meta { color: #555; margin-bottom: 12pt; }
+  table { border-collapse: collapse; width: 100%; }
+  th, td { border: 1px solid #bbb; padding: 3pt 4pt; }
+  th { background: #f0f0f0; font-weight: 600; }
+  .time { text-align: right; white-space: nowrap; }
+  .weekend { background: #f7f7f7; }
+  tr.totals td { font-weight: 600; background: #f0f0f0; }
+  ul.descriptions { padding-left: 14pt; }
+  .signature { display: flex; gap: 36pt; margin-top: 40pt; }
+  .signature div { flex: 1; border-top: 1px solid #222; padding-top: 3pt; }
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<div class="meta">{period} · Total {total} ({total_hours} h)</div>
+{table}
+{descriptions}
+{signature}
+<p class="meta">Generated {generated}</p>
+</body>
+</html>
+"#;
+
+/// Built-in Markdown template
+const MARKDOWN_TEMPLATE: &str = "# {title}
+
+{period} · Total **{total}** ({total_hours} h)
+
+{table}
+{descriptions}
+{signature}
+
+_Generated {generated}_
+";
+
+/// Span of days a timesheet covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    /// Monday to Sunday
+    Week,
+    /// First to last day of the month
+    Month,
+}
+
+impl Period {
+    /// First and last day of the period containing `date`
+    pub fn range(self, date: Date) -> (Date, Date) {
+        match self {
+            Period::Week => {
+                let monday = date - Duration::days(date.weekday().number_days_from_monday() as i64);
+                (monday, monday + Duration::days(6))
+            }
+            Period::Month => {
+                let first = date.replace_day(1).expect("every month has a first day");
+                let last = first
+                    .replace_day(date.month().length(date.year()))
+                    .expect("last day of month is valid");
+                (first, last)
+            }
+        }
+    }
+}
+
+/// Output document format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    Html,
+    Markdown,
+}
+
+impl DocumentFormat {
+    /// The built-in template for this format
+    pub fn default_template(self) -> &'static str {
+        match self {
+            DocumentFormat::Html => HTML_TEMPLATE,
+            DocumentFormat::Markdown => MARKDOWN_TEMPLATE,
+        }
+    }
+}
+
+/// Header and signature details
+#[derive(Debug, Clone, Default)]
+pub struct SheetDetails {
+    /// Person the timesheet belongs to
+    pub name: Option<String>,
+    pub client: Option<String>,
+    /// Include a list of record descriptions
+    pub descriptions: bool,
+}
+
+/// One task's time per day of the period
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskRow {
+    pub name: String,
+    /// Minutes for each day of `Timesheet::days`
+    pub minutes: Vec<u32>,
+    pub total: u32,
+}
+
+/// A record description shown below the grid
+#[derive(Debug, Clone, PartialEq)]
+pub struct Note {
+    pub date: Date,
+    pub task: String,
+    pub text: String,
+}
+
+/// Time per task and day for a range of days
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timesheet {
+    pub days: Vec<Date>,
+    /// Sorted by total (descending), then name
+    pub rows: Vec<TaskRow>,
+    pub notes: Vec<Note>,
+}
+
+impl Timesheet {
+    /// Group the records of `days` between `from` and `to` (inclusive) by task and day
+    ///
+    /// With `round_minutes`, each task's time per day is rounded to the nearest multiple
+    /// (halves round up), so the rounded cells add up to the totals. Tasks that round
+    /// down to nothing are left out.
+    pub fn build(days: &[DayData], from: Date, to: Date, round_minutes: u32) -> Self {
+        let mut dates = Vec::new();
+        let mut date = from;
+        while date <= to {
+            dates.push(date);
+            date += Duration::DAY;
+        }
+
+        let mut rows: Vec<TaskRow> = Vec::new();
+        let mut notes = Vec::new();
+        for day in days {
+            let Some(column) = dates.iter().position(|d| *d == day.date) else {
+                continue;
+            };
+            for record in day.get_sorted_records() {
+                let row = match rows.iter().position(|r| r.name == record.name) {
+                    Some(index) => &mut rows[index],
+                    None => {
+                        rows.push(TaskRow {
+                            name: record.name.clone(),
+                            minutes: vec![0; dates.len()],
+                            total: 0,
+                        });
+                        rows.last_mut().expect("row was just pushed")
+                    }
+                };
+                row.minutes[column] += record.total_minutes;
+
+                let note = Note {
+                    date: day.date,
+                    task: record.name.clone(),
+                    text: record.description.trim().to_string(),
+                };
+                if !note.text.is_empty() && !notes.contains(&note) {
+                    notes.push(note);
+                }
+            }
+        }
+
+        for row in &mut rows {
+            for minutes in &mut row.minutes {
+                *minutes = round(*minutes, round_minutes);
+            }
+            row.total = row.minutes.iter().sum();
+        }
+        rows.retain(|row| row.total > 0);
+        rows.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.name.cmp(&b.name)));
+        notes.sort_by_key(|note| note.date);
+
+        Timesheet {
+            days: dates,
+            rows,
+            notes,
+        }
+    }
+
+    /// Minutes per day across all tasks
+    pub fn day_totals(&self) -> Vec<u32> {
+        (0..self.days.len())
+            .map(|i| self.rows.iter().map(|row| row.minutes[i]).sum())
+            .collect()
+    }
+
+    pub fn total(&self) -> u32 {
+        self.rows.iter().map(|row| row.total).sum()
+    }
+
+    /// "Week of ..." / "October 2026" / "2026-10-01 – 2026-10-10"
+    pub fn period_label(&self) -> String {
+        let (Some(first), Some(last)) = (self.days.first(), self.days.last()) else {
+            return String::new();
+        };
+        if Period::Week.range(*first) == (*first, *last) {
+            format!("Week of {} – {}", first, last)
+        } else if Period::Month.range(*first) == (*first, *last) {
+            format!("{} {}", first.month(), first.year())
+        } else {
+            format!("{} – {}", first, last)
+        }
+    }
+}
+
+fn round(minutes: u32, step: u32) -> u32 {
+    if step == 0 {
+        return minutes;
+    }
+    (minutes + step / 2) / step * step
+}
+
+/// Render a timesheet into `template` (see `DocumentFormat::default_template`)
+///
+/// Placeholders: {title}, {period}, {name}, {client}, {table}, {descriptions},
+/// {signature}, {total}, {total_hours}, {generated}
+pub fn render(
+    sheet: &Timesheet,
+    details: &SheetDetails,
+    format: DocumentFormat,
+    template: &str,
+    generated: OffsetDateTime,
+) -> String {
+    let title = match &details.client {
+        Some(client) => format!("Timesheet — {}", client),
+        None => "Timesheet".to_string(),
+    };
+    let text = |value: &str| match format {
+        DocumentFormat::Html => escape_html(value),
+        DocumentFormat::Markdown => escape_markdown(value),
+    };
+    let (table, descriptions, signature) = match format {
+        DocumentFormat::Html => (
+            html_table(sheet),
+            html_descriptions(sheet, details),
+            html_signature(details),
+        ),
+        DocumentFormat::Markdown => (
+            markdown_table(sheet),
+            markdown_descriptions(sheet, details),
+            markdown_signature(details),
+        ),
+    };
+    let generated = format!(
+        "{} {:02}:{:02}",
+        generated.date(),
+        generated.hour(),
+        generated.minute()
+    );
+
+    fill(
+        template,
+        &[
+            ("title", text(&title)),
+            ("period", text(&sheet.period_label())),
+            ("name", text(details.name.as_deref().unwrap_or(""))),
+            ("client", text(details.client.as_deref().unwrap_or(""))),
+            ("table", table),
+            ("descriptions", descriptions),
+            ("signature", signature),
+            ("total", format_minutes(sheet.total())),
+            ("total_hours", format_decimal_hours(sheet.total())),
+            ("generated", generated),
+        ],
+    )
+}
+
+/// Substitute `{key}` placeholders in one pass, so values are never expanded again
+fn fill(template: &str, values: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        rest = &rest[open..];
+        let value = rest.find('}').and_then(|close| {
+            let key = &rest[1..close];
+            values
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, value)| (value, close))
+        });
+        match value {
+            Some((value, close)) => {
+                out.push_str(value);
+                rest = &rest[close + 1..];
+            }
+            // Not a placeholder (e.g. a CSS rule)
+            None => {
+                out.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn day_label(date: Date) -> String {
+    let weekday = match date.weekday() {
+        Weekday::Monday => "Mon",
+        Weekday::Tuesday => "Tue",
+        Weekday::Wednesday => "Wed",
+        Weekday::Thursday => "Thu",
+        Weekday::Friday => "Fri",
+        Weekday::Saturday => "Sat",
+        Weekday::Sunday => "Sun",
+    };
+    format!("{} {}", weekday, date.day())
+}
+
+fn is_weekend(date: Date) -> bool {
+    matches!(date.weekday(), Weekday::Saturday | Weekday::Sunday)
+}
+
+/// `H:MM`, or empty for no time
+fn format_cell(minutes: u32) -> String {
+    if minutes == 0 {
+        String::new()
+    } else {
+        format_minutes(minutes)
+    }
+}
+
+fn format_minutes(minutes: u32) -> String {
+    format!("{}:{:02}", minutes / 60, minutes % 60)
+}
+
+fn format_decimal_hours(minutes: u32) -> String {
+    format!("{:.2}", minutes as f64 / 60.0)
+}
+
+fn html_table(sheet: &Timesheet) -> String {
+    let day_class = |date: Date| match is_weekend(date) {
+        true => "time weekend",
+        false => "time",
+    };
+
+    let mut out = String::from("<table>\n<thead>\n<tr><th>Task</th>");
+    for date in &sheet.days {
+        out.push_str(&format!(
+            "<th class=\"{}\">{}</th>",
+            day_class(*date),
+            day_label(*date)
+        ));
+    }
+    out.push_str("<th class=\"time\">Total</th></tr>\n</thead>\n<tbody>\n");
+
+    for row in &sheet.rows {
+        out.push_str(&format!("<tr><td>{}</td>", escape_html(&row.name)));
+        for (date, minutes) in sheet.days.iter().zip(&row.minutes) {
+            out.push_str(&format!(
+                "<td class=\"{}\">{}</td>",
+                day_class(*date),
+                format_cell(*minutes)
+            ));
+        }
+        out.push_str(&format!(
+            "<td class=\"time\">{}</td></tr>\n",
+            format_minutes(row.total)
+        ));
+    }
+
+    out.push_str("<tr class=\"totals\"><td>Total</td>");
+    for (date, minutes) in sheet.days.iter().zip(sheet.day_totals()) {
+        out.push_str(&format!(
+            "<td class=\"{}\">{}</td>",
+            day_class(*date),
+            format_cell(minutes)
+        ));
+    }
+    out.push_str(&format!(
+        "<td class=\"time\">{}</td></tr>\n</tbody>\n</table>",
+        format_minutes(sheet.total())
+    ));
+    out
+}
+
+fn html_descriptions(sheet: &Timesheet, details: &SheetDetails) -> String {
+    if !details.descriptions || sheet.notes.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("<h2>Descriptions</h2>\n<ul class=\"descriptions\">\n");
+    for note in &sheet.notes {
+        out.push_str(&format!(
+            "<li><strong>{}</strong> {}: {}</li>\n",
+            day_label(note.date),
+            escape_html(&note.task),
+            escape_html(&note.text).replace('\n', "<br>")
+        ));
+    }
+    out.push_str("</ul>");
+    out
+}
+
+fn html_signature(details: &SheetDetails) -> String {
+    let name = details.name.as_deref().map(escape_html).unwrap_or_default();
+    let approver = match &details.client {
+        Some(client) => format!("Approved by ({})", escape_html(client)),
+        None => "Approved by".to_string(),
+    };
+    format!(
+        "<div class=\"signature\">\n<div>Prepared by: {}<br>Signature / date</div>\n<div>{}<br>Signature / date</div>\n</div>",
+        name, approver
+    )
+}
+
+fn markdown_table(sheet: &Timesheet) -> String {
+    let mut header = vec!["Task".to_string()];
+    header.extend(sheet.days.iter().map(|date| day_label(*date)));
+    header.push("Total".to_string());
+
+    let mut lines = vec![
+        format!("| {} |", header.join(" | ")),
+        format!("|---|{}---:|", "---:|".repeat(sheet.days.len())),
+    ];
+    for row in &sheet.rows {
+        let mut cells = vec![escape_markdown(&row.name)];
+        cells.extend(row.minutes.iter().map(|m| format_cell(*m)));
+        cells.push(format_minutes(row.total));
+        lines.push(format!("| {} |", cells.join(" | ")));
+    }
+
+    let mut totals = vec!["**Total**".to_string()];
+    totals.extend(sheet.day_totals().into_iter().map(format_cell));
+    totals.push(format!("**{}**", format_minutes(sheet.total())));
+    lines.push(format!("| {} |", totals.join(" | ")));
+    lines.join("\n")
+}
+
+fn markdown_descriptions(sheet: &Timesheet, details: &SheetDetails) -> String {
+    if !details.descriptions || sheet.notes.is_empty() {
+        return String::new();
+    }
+    let mut out = String::from("\n## Descriptions\n\n");
+    for note in &sheet.notes {
+        out.push_str(&format!(
+            "- **{}** {}: {}\n",
+            day_label(note.date),
+            escape_markdown(&note.task),
+            escape_markdown(&note.text).replace('\n', " ")
+        ));
+    }
+    out
+}
+
+fn markdown_signature(details: &SheetDetails) -> String {
+    let preparer = match &details.name {
+        Some(name) => format!("Prepared by: {}", escape_markdown(name)),
+        None => "Prepared by:".to_string(),
+    };
+    let approver = match &details.client {
+        Some(client) => format!("Approved by ({})", escape_markdown(client)),
+        None => "Approved by".to_string(),
+    };
+    format!(
+        "\n{} ______________________ Date: __________\n\n{}: ______________________ Date: __________",
+        preparer, approver
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '|' | '*' | '_' | '`' | '[' | ']' | '<' | '>') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{TimePoint, WorkRecord};
+    use time::macros::{date, datetime};
+
+    fn day(date: Date, records: &[(&str, &str, &str, &str)]) -> DayData {
+        let mut day = DayData::new(date);
+        for (id, (name, start, end, description)) in records.iter().enumerate() {
+            let mut record = WorkRecord::new(
+                id as u32 + 1,
+                name.to_string(),
+                TimePoint::parse(start).unwrap(),
+                TimePoint::parse(end).unwrap(),
+            );
+            record.description = description.to_string();
+            day.add_record(record);
+        }
+        day
+    }
+
+    fn sample_days() -> Vec<DayData> {
+        vec![
+            day(
+                date!(2026 - 10 - 12),
+                &[
+                    ("PROJ-1 Review", "09:00", "10:20", "PR #42"),
+                    ("Standup", "10:30", "10:37", ""),
+                    ("PROJ-1 Review", "13:00", "13:30", "PR #42"),
+                ],
+            ),
+            day(date!(2026 - 10 - 14), &[("Standup", "10:30", "10:45", "")]),
+            // Outside the week
+            day(date!(2026 - 10 - 19), &[("Standup", "10:30", "10:45", "")]),
+        ]
+    }
+
+    #[test]
+    fn test_period_range() {
+        assert_eq!(
+            Period::Week.range(date!(2026 - 10 - 17)),
+            (date!(2026 - 10 - 12), date!(2026 - 10 - 18))
+        );
+        assert_eq!(
+            Period::Month.range(date!(2028 - 02 - 10)),
+            (date!(2028 - 02 - 01), date!(2028 - 02 - 29))
+        );
+    }
+
+    #[test]
+    fn test_build_groups_tasks_by_day() {
+        let (from, to) = Period::Week.range(date!(2026 - 10 - 12));
+        let sheet = Timesheet::build(&sample_days(), from, to, 0);
+
+        assert_eq!(sheet.days.len(), 7);
+        assert_eq!(sheet.rows[0].name, "PROJ-1 Review");
+        assert_eq!(sheet.rows[0].minutes[0], 110);
+        assert_eq!(sheet.rows[1].minutes, vec![7, 0, 15, 0, 0, 0, 0]);
+        assert_eq!(sheet.day_totals()[0], 117);
+        assert_eq!(sheet.total(), 132);
+        // Repeated descriptions are listed once
+        assert_eq!(sheet.notes.len(), 1);
+        assert_eq!(sheet.period_label(), "Week of 2026-10-12 – 2026-10-18");
+    }
+
+    #[test]
+    fn test_build_rounds_cells() {
+        let (from, to) = Period::Week.range(date!(2026 - 10 - 12));
+        let sheet = Timesheet::build(&sample_days(), from, to, 15);
+
+        // 110 → 105, 7 → 0, 15 → 15
+        assert_eq!(sheet.rows[0].minutes[0], 105);
+        assert_eq!(sheet.rows[1].minutes, vec![0, 0, 15, 0, 0, 0, 0]);
+        assert_eq!(sheet.total(), 120);
+
+        let sheet = Timesheet::build(&sample_days(), from, to, 60);
+        assert_eq!(sheet.rows.len(), 1);
+        assert_eq!(round(30, 60), 60);
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let (from, to) = Period::Week.range(date!(2026 - 10 - 12));
+        let sheet = Timesheet::build(&sample_days(), from, to, 0);
+        let details = SheetDetails {
+            name: Some("Ana".to_string()),
+            client: Some("Acme".to_string()),
+            descriptions: true,
+        };
+
+        let doc = render(
+            &sheet,
+            &details,
+            DocumentFormat::Markdown,
+            DocumentFormat::Markdown.default_template(),
+            datetime!(2026-10-18 18:05 UTC),
+        );
+
+        assert!(doc.starts_with("# Timesheet — Acme\n"));
+        assert!(doc.contains("Total **2:12** (2.20 h)"));
+        assert!(doc.contains(
+            "| Task | Mon 12 | Tue 13 | Wed 14 | Thu 15 | Fri 16 | Sat 17 | Sun 18 | Total |"
+        ));
+        assert!(doc.contains("| PROJ-1 Review | 1:50 |  |  |  |  |  |  | 1:50 |"));
+        assert!(doc.contains("| **Total** | 1:57 |  | 0:15 |"));
+        assert!(doc.contains("- **Mon 12** PROJ-1 Review: PR #42"));
+        assert!(doc.contains("Prepared by: Ana"));
+        assert!(doc.contains("Approved by (Acme)"));
+        assert!(doc.contains("_Generated 2026-10-18 18:05_"));
+    }
+
+    #[test]
+    fn test_render_html_escapes_and_custom_template() {
+        let days = vec![day(
+            date!(2026 - 10 - 12),
+            &[("<b>Fix</b> & ship", "09:00", "10:00", "")],
+        )];
+        let (from, to) = Period::Week.range(date!(2026 - 10 - 12));
+        let sheet = Timesheet::build(&days, from, to, 0);
+
+        let doc = render(
+            &sheet,
+            &SheetDetails::default(),
+            DocumentFormat::Html,
+            DocumentFormat::Html.default_template(),
+            datetime!(2026-10-18 18:05 UTC),
+        );
+        assert!(doc.contains("<td>&lt;b&gt;Fix&lt;/b&gt; &amp; ship</td>"));
+        assert!(doc.contains("<th class=\"time weekend\">Sat 17</th>"));
+        // Descriptions are off by default
+        assert!(!doc.contains("<h2>Descriptions</h2>"));
+
+        let doc = render(
+            &sheet,
+            &SheetDetails::default(),
+            DocumentFormat::Html,
+            "<p>{period}: {total} by {name}</p>",
+            datetime!(2026-10-18 18:05 UTC),
+        );
+        assert_eq!(doc, "<p>Week of 2026-10-12 – 2026-10-18: 1:00 by </p>");
+    }
+
+    #[test]
+    fn test_fill_substitutes_once() {
+        let values = [("a", "{b}".to_string()), ("b", "B".to_string())];
+        assert_eq!(fill("x { {a} {b} {c}", &values), "x { {b} B {c}");
+    }
+}
@@ -0,0 +1,351 @@
+//! Timewarrior data files and `timew export` JSON
+//!
+//! Timewarrior has no task names, only tags and an annotation. A record's name is the
+//! first tag (or the annotation if untagged); further tags become `#tag` words in the
+//! description, after the annotation. Exports map records back the same way.
+
+use super::{ProposedRecord, join_tags, record_span, split_tags};
+use crate::models::{DayData, LocalZone};
+use serde::{Deserialize, Serialize};
+use time::macros::format_description;
+use time::{PrimitiveDateTime, UtcOffset};
+
+/// A Timewarrior interval, with times in UTC
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interval {
+    pub start: PrimitiveDateTime,
+    /// None while the interval is still being tracked
+    pub end: Option<PrimitiveDateTime>,
+    pub tags: Vec<String>,
+    pub annotation: String,
+}
+
+/// An interval as written by `timew export`
+#[derive(Serialize, Deserialize)]
+struct JsonInterval {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<u32>,
+    start: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    end: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    annotation: String,
+}
+
+/// Parse a Timewarrior data file (`inc ...` lines) or `timew export` JSON
+pub fn parse(text: &str) -> Result<Vec<Interval>, String> {
+    if text.trim_start().starts_with('[') {
+        parse_json(text)
+    } else {
+        parse_data(text)
+    }
+}
+
+fn parse_json(text: &str) -> Result<Vec<Interval>, String> {
+    let intervals: Vec<JsonInterval> =
+        serde_json::from_str(text).map_err(|e| format!("Invalid Timewarrior JSON: {}", e))?;
+    intervals
+        .into_iter()
+        .map(|interval| {
+            Ok(Interval {
+                start: parse_utc(&interval.start)?,
+                end: interval.end.as_deref().map(parse_utc).transpose()?,
+                tags: interval.tags,
+                annotation: interval.annotation,
+            })
+        })
+        .collect()
+}
+
+fn parse_data(text: &str) -> Result<Vec<Interval>, String> {
+    let mut intervals = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let interval =
+            parse_line(line).map_err(|e| format!("Line {}: {} ('{}')", index + 1, e, line))?;
+        intervals.push(interval);
+    }
+    Ok(intervals)
+}
+
+/// Parse `inc <start> [- <end>] [# <tags...>] [# <annotation>]`
+fn parse_line(line: &str) -> Result<Interval, String> {
+    let tokens = tokenize(line)?;
+    let mut sections = tokens.split(|(token, quoted)| token == "#" && !quoted);
+    let times: Vec<&str> = sections
+        .next()
+        .unwrap_or_default()
+        .iter()
+        .map(|(token, _)| token.as_str())
+        .collect();
+    let tags = sections
+        .next()
+        .unwrap_or_default()
+        .iter()
+        .map(|(token, _)| token.clone())
+        .collect();
+    let annotation = sections
+        .next()
+        .unwrap_or_default()
+        .iter()
+        .map(|(token, _)| token.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let (start, end) = match times.as_slice() {
+        ["inc", start] => (parse_utc(start)?, None),
+        ["inc", start, "-", end] => (parse_utc(start)?, Some(parse_utc(end)?)),
+        _ => return Err("Expected 'inc <start> [- <end>]'".to_string()),
+    };
+
+    Ok(Interval {
+        start,
+        end,
+        tags,
+        annotation,
+    })
+}
+
+/// Split a line into words, keeping "quoted strings" (with \" escapes) together
+///
+/// Each word is returned with whether it was quoted, so a quoted "#" stays a tag.
+fn tokenize(line: &str) -> Result<Vec<(String, bool)>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if c != '"' {
+            let mut word = String::new();
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                word.push(c);
+            }
+            tokens.push((word, false));
+            continue;
+        }
+
+        chars.next();
+        let mut word = String::new();
+        loop {
+            match chars.next() {
+                Some('\\') => word.extend(chars.next()),
+                Some('"') => break,
+                Some(c) => word.push(c),
+                None => return Err("Unterminated quote".to_string()),
+            }
+        }
+        tokens.push((word, true));
+    }
+    Ok(tokens)
+}
+
+fn parse_utc(value: &str) -> Result<PrimitiveDateTime, String> {
+    let format = format_description!("[year][month][day]T[hour][minute][second]Z");
+    PrimitiveDateTime::parse(value, &format).map_err(|_| format!("Invalid time '{}'", value))
+}
+
+fn format_utc(date_time: PrimitiveDateTime) -> String {
+    let format = format_description!("[year][month][day]T[hour][minute][second]Z");
+    date_time
+        .format(&format)
+        .expect("date-time formats as ISO basic")
+}
+
+/// Propose records for closed intervals, converting UTC to local times in `zone`
+pub fn propose_records(intervals: &[Interval], zone: LocalZone) -> Vec<ProposedRecord> {
+    let to_local = |utc: PrimitiveDateTime| zone.to_local(utc.assume_utc());
+
+    intervals
+        .iter()
+        .filter_map(|interval| {
+            let (name, rest) = match interval.tags.split_first() {
+                Some((first, rest)) => (first.as_str(), rest),
+                None => (interval.annotation.as_str(), &[][..]),
+            };
+            let annotation = match interval.tags.is_empty() {
+                true => "",
+                false => interval.annotation.as_str(),
+            };
+            ProposedRecord::from_span(
+                to_local(interval.start),
+                to_local(interval.end?),
+                name,
+                &join_tags(annotation, rest),
+            )
+        })
+        .collect()
+}
+
+/// Intervals for the records of `days`, converting local times in `zone` to UTC
+fn intervals(days: &[DayData], zone: LocalZone) -> Vec<Interval> {
+    let to_utc = |local: PrimitiveDateTime| {
+        let utc = zone.assume(local).to_offset(UtcOffset::UTC);
+        PrimitiveDateTime::new(utc.date(), utc.time())
+    };
+
+    let mut intervals = Vec::new();
+    for day in days {
+        for record in day.get_sorted_records() {
+            let (start, end) = record_span(day, record);
+            let (annotation, tags) = split_tags(&record.description);
+            intervals.push(Interval {
+                start: to_utc(start),
+                end: Some(to_utc(end)),
+                tags: std::iter::once(record.name.clone()).chain(tags).collect(),
+                annotation,
+            });
+        }
+    }
+    intervals
+}
+
+/// Render the records of `days` as data file lines (for `~/.timewarrior/data/*.data`)
+pub fn export_data(days: &[DayData], zone: LocalZone) -> String {
+    let mut out = String::new();
+    for interval in intervals(days, zone) {
+        out.push_str("inc ");
+        out.push_str(&format_utc(interval.start));
+        if let Some(end) = interval.end {
+            out.push_str(" - ");
+            out.push_str(&format_utc(end));
+        }
+        if !interval.tags.is_empty() || !interval.annotation.is_empty() {
+            out.push_str(" #");
+            for tag in &interval.tags {
+                out.push(' ');
+                out.push_str(&quote_if_needed(tag));
+            }
+        }
+        if !interval.annotation.is_empty() {
+            out.push_str(" # ");
+            out.push_str(&quote(&interval.annotation));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Render the records of `days` like `timew export`
+pub fn export_json(days: &[DayData], zone: LocalZone) -> String {
+    let intervals: Vec<JsonInterval> = intervals(days, zone)
+        .into_iter()
+        .enumerate()
+        .map(|(index, interval)| JsonInterval {
+            id: Some(index as u32 + 1),
+            start: format_utc(interval.start),
+            end: interval.end.map(format_utc),
+            tags: interval.tags,
+            annotation: interval.annotation,
+        })
+        .collect();
+    serde_json::to_string_pretty(&intervals).expect("intervals serialize to JSON")
+}
+
+fn quote_if_needed(word: &str) -> String {
+    if word.is_empty() || word == "#" || word.contains(|c: char| c.is_whitespace() || c == '"') {
+        quote(word)
+    } else {
+        word.to_string()
+    }
+}
+
+fn quote(text: &str) -> String {
+    let text: String = text
+        .chars()
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .collect();
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{TimePoint, WorkRecord};
+    use time::macros::{date, datetime, offset};
+
+    fn names(proposals: &[ProposedRecord]) -> Vec<(String, String)> {
+        proposals
+            .iter()
+            .map(|p| (p.name.clone(), p.description.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_data_lines() {
+        let text = "\
+inc 20261017T070000Z - 20261017T083000Z # \"PROJ-1 review\" billable # \"with \\\"Ana\\\"\"
+inc 20261017T090000Z - 20261017T091500Z # standup
+inc 20261017T100000Z - 20261017T110000Z # # \"Untagged work\"
+inc 20261017T120000Z # running
+";
+        let intervals = parse(text).unwrap();
+
+        assert_eq!(intervals.len(), 4);
+        assert_eq!(intervals[0].start, datetime!(2026-10-17 07:00));
+        assert_eq!(intervals[0].tags, vec!["PROJ-1 review", "billable"]);
+        assert_eq!(intervals[0].annotation, "with \"Ana\"");
+        assert_eq!(intervals[3].end, None);
+
+        let proposals = propose_records(&intervals, LocalZone::Fixed(offset!(+2)));
+        assert_eq!(
+            names(&proposals),
+            vec![
+                (
+                    "PROJ-1 review".to_string(),
+                    "with \"Ana\" #billable".to_string()
+                ),
+                ("standup".to_string(), String::new()),
+                ("Untagged work".to_string(), String::new()),
+            ]
+        );
+        assert_eq!(proposals[0].date, date!(2026 - 10 - 17));
+        assert_eq!(proposals[0].start, TimePoint::new(9, 0).unwrap());
+        assert_eq!(proposals[0].end, TimePoint::new(10, 30).unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors_name_the_line() {
+        let error = parse("inc 20261017T070000Z\nexc whatever\n").unwrap_err();
+        assert!(error.starts_with("Line 2:"));
+        assert!(parse("inc 2026-10-17").is_err());
+        assert!(parse("[{\"start\": \"nope\"}]").is_err());
+    }
+
+    #[test]
+    fn test_export_round_trips() {
+        let mut day = DayData::new(date!(2026 - 10 - 17));
+        let mut record = WorkRecord::new(
+            1,
+            "PROJ-1 review".to_string(),
+            TimePoint::new(9, 0).unwrap(),
+            TimePoint::new(10, 30).unwrap(),
+        );
+        record.description = "with \"Ana\" #billable".to_string();
+        day.add_record(record);
+
+        let data = export_data(std::slice::from_ref(&day), LocalZone::Fixed(offset!(+2)));
+        assert_eq!(
+            data,
+            "inc 20261017T070000Z - 20261017T083000Z # \"PROJ-1 review\" billable # \"with \\\"Ana\\\"\"\n"
+        );
+
+        for text in [data, export_json(&[day], LocalZone::Fixed(offset!(+2)))] {
+            let proposals = propose_records(&parse(&text).unwrap(), LocalZone::Fixed(offset!(+2)));
+            assert_eq!(
+                names(&proposals),
+                vec![(
+                    "PROJ-1 review".to_string(),
+                    "with \"Ana\" #billable".to_string()
+                )]
+            );
+            assert_eq!(proposals[0].start, TimePoint::new(9, 0).unwrap());
+        }
+    }
+}
@@ -0,0 +1,477 @@
+//! Toggl Track CSV and JSON exports
+//!
+//! A Toggl entry's description becomes the record name (the project if it has none);
+//! the project and tags go into the record description as `Project #tag`. Exports map
+//! records back the same way.
+
+use super::{ProposedRecord, join_tags, record_span, split_tags};
+use crate::models::{DayData, LocalZone};
+use serde::Serialize;
+use serde_json::Value;
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// A Toggl time entry, with times in local time
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub description: String,
+    pub project: String,
+    pub tags: Vec<String>,
+    pub start: PrimitiveDateTime,
+    /// None while the entry is still running
+    pub end: Option<PrimitiveDateTime>,
+}
+
+/// Parse a Toggl CSV report or JSON export (time entries or a detailed report)
+///
+/// JSON times carry their offset and are converted to local times in `zone`; CSV times
+/// are already local.
+pub fn parse(text: &str, zone: LocalZone) -> Result<Vec<Entry>, String> {
+    let text = text.trim_start_matches('\u{feff}');
+    match text.trim_start().chars().next() {
+        Some('[' | '{') => parse_json(text, zone),
+        _ => parse_csv(text),
+    }
+}
+
+fn parse_json(text: &str, zone: LocalZone) -> Result<Vec<Entry>, String> {
+    let value: Value =
+        serde_json::from_str(text).map_err(|e| format!("Invalid Toggl JSON: {}", e))?;
+    // Detailed reports wrap their entries in "data"
+    let items = match value.get("data").unwrap_or(&value) {
+        Value::Array(items) => items,
+        _ => return Err("Toggl JSON must be a list of time entries".to_string()),
+    };
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            parse_json_entry(item, zone).map_err(|e| format!("Entry {}: {}", index + 1, e))
+        })
+        .collect()
+}
+
+fn parse_json_entry(item: &Value, zone: LocalZone) -> Result<Entry, String> {
+    let text = |key: &str| item.get(key).and_then(Value::as_str).unwrap_or_default();
+    let to_local = |key: &str| -> Result<Option<PrimitiveDateTime>, String> {
+        let Some(value) = item.get(key).and_then(Value::as_str) else {
+            return Ok(None);
+        };
+        let time = OffsetDateTime::parse(value, &Rfc3339)
+            .map_err(|_| format!("Invalid {} '{}'", key, value))?;
+        Ok(Some(zone.to_local(time)))
+    };
+
+    let start = to_local("start")?.ok_or("Missing start")?;
+    let end = match to_local("stop")?.or(to_local("end")?) {
+        Some(end) => Some(end),
+        // Time entries have seconds ("duration", negative while running), reports
+        // milliseconds ("dur")
+        None => match (item.get("duration"), item.get("dur")) {
+            (Some(seconds), _) => seconds
+                .as_i64()
+                .filter(|s| *s >= 0)
+                .map(|s| start + Duration::seconds(s)),
+            (None, Some(millis)) => millis.as_i64().map(|ms| start + Duration::milliseconds(ms)),
+            (None, None) => None,
+        },
+    };
+
+    Ok(Entry {
+        description: text("description").to_string(),
+        project: match text("project") {
+            "" => text("project_name").to_string(),
+            project => project.to_string(),
+        },
+        tags: item
+            .get("tags")
+            .and_then(Value::as_array)
+            .map(|tags| {
+                tags.iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        start,
+        end,
+    })
+}
+
+fn parse_csv(text: &str) -> Result<Vec<Entry>, String> {
+    let mut rows = parse_csv_rows(text)?.into_iter();
+    let header: Vec<String> = rows
+        .next()
+        .ok_or("Empty CSV file")?
+        .iter()
+        .map(|h| h.trim().to_lowercase())
+        .collect();
+    let column = |name: &str| header.iter().position(|h| h == name);
+    let required = |name: &str| column(name).ok_or(format!("CSV has no '{}' column", name));
+
+    let start_date = required("start date")?;
+    let start_time = required("start time")?;
+    let end_columns = column("end date").zip(column("end time"));
+    let duration = column("duration");
+    if end_columns.is_none() && duration.is_none() {
+        return Err("CSV needs 'End date'/'End time' or 'Duration' columns".to_string());
+    }
+
+    let mut entries = Vec::new();
+    for (index, row) in rows.enumerate() {
+        if row.iter().all(|field| field.trim().is_empty()) {
+            continue;
+        }
+        let field = |i: Option<usize>| i.and_then(|i| row.get(i)).map_or("", |f| f.trim());
+        let line_error = |e: String| format!("Row {}: {}", index + 2, e);
+
+        let start =
+            parse_local(field(Some(start_date)), field(Some(start_time))).map_err(line_error)?;
+        let end = match end_columns {
+            Some((date, time)) if !field(Some(date)).is_empty() => {
+                parse_local(field(Some(date)), field(Some(time))).map_err(line_error)?
+            }
+            _ => start + parse_hms(field(duration)).map_err(line_error)?,
+        };
+
+        entries.push(Entry {
+            description: field(column("description")).to_string(),
+            project: field(column("project")).to_string(),
+            tags: field(column("tags"))
+                .split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string)
+                .collect(),
+            start,
+            end: Some(end),
+        });
+    }
+    Ok(entries)
+}
+
+/// Split CSV text into rows of fields (RFC 4180 quoting)
+fn parse_csv_rows(text: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if field.is_empty() => loop {
+                match chars.next() {
+                    Some('"') if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => return Err("Unterminated quote in CSV".to_string()),
+                }
+            },
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+fn parse_local(date: &str, time: &str) -> Result<PrimitiveDateTime, String> {
+    let date = Date::parse(date, format_description!("[year]-[month]-[day]"))
+        .map_err(|_| format!("Invalid date '{}', expected YYYY-MM-DD", date))?;
+    let time = Time::parse(time, format_description!("[hour]:[minute]:[second]"))
+        .or_else(|_| Time::parse(time, format_description!("[hour]:[minute]")))
+        .map_err(|_| format!("Invalid time '{}', expected HH:MM:SS", time))?;
+    Ok(PrimitiveDateTime::new(date, time))
+}
+
+/// Parse an `H:MM:SS` duration (hours may exceed 24)
+fn parse_hms(value: &str) -> Result<Duration, String> {
+    let invalid = || format!("Invalid duration '{}', expected HH:MM:SS", value);
+    let parts: Vec<i64> = value
+        .split(':')
+        .map(|part| part.trim().parse().map_err(|_| invalid()))
+        .collect::<Result<_, _>>()?;
+    match parts.as_slice() {
+        [h, m, s] if *h >= 0 && (0..60).contains(m) && (0..60).contains(s) => {
+            Ok(Duration::hours(*h) + Duration::minutes(*m) + Duration::seconds(*s))
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Propose records for finished entries
+pub fn propose_records(entries: &[Entry]) -> Vec<ProposedRecord> {
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let (name, project) = match entry.description.trim() {
+                "" => (entry.project.as_str(), ""),
+                description => (description, entry.project.as_str()),
+            };
+            ProposedRecord::from_span(
+                entry.start,
+                entry.end?,
+                name,
+                &join_tags(project, &entry.tags),
+            )
+        })
+        .collect()
+}
+
+/// Toggl entries for the records of `days`
+fn entries(days: &[DayData]) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    for day in days {
+        for record in day.get_sorted_records() {
+            let (start, end) = record_span(day, record);
+            let (project, tags) = split_tags(&record.description);
+            entries.push(Entry {
+                description: record.name.clone(),
+                project,
+                tags,
+                start,
+                end: Some(end),
+            });
+        }
+    }
+    entries
+}
+
+/// Render the records of `days` as a CSV file Toggl can import
+///
+/// Toggl's importer requires the `email` of the workspace member the entries belong to.
+pub fn export_csv(days: &[DayData], email: &str) -> String {
+    let date_format = format_description!("[year]-[month]-[day]");
+    let time_format = format_description!("[hour]:[minute]:[second]");
+
+    let mut out = String::from(
+        "Email,Description,Project,Tags,Start date,Start time,End date,End time,Duration\r\n",
+    );
+    for entry in entries(days) {
+        let end = entry.end.expect("exported entries are finished");
+        let seconds = (end - entry.start).whole_seconds();
+        let fields = [
+            email.to_string(),
+            entry.description,
+            entry.project,
+            entry.tags.join(", "),
+            entry
+                .start
+                .date()
+                .format(&date_format)
+                .expect("date formats"),
+            entry
+                .start
+                .time()
+                .format(&time_format)
+                .expect("time formats"),
+            end.date().format(&date_format).expect("date formats"),
+            end.time().format(&time_format).expect("time formats"),
+            format!(
+                "{:02}:{:02}:{:02}",
+                seconds / 3600,
+                seconds % 3600 / 60,
+                seconds % 60
+            ),
+        ];
+        let fields: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&fields.join(","));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// A time entry in Toggl's JSON shape
+#[derive(Serialize)]
+struct JsonEntry {
+    description: String,
+    project: String,
+    tags: Vec<String>,
+    start: String,
+    stop: String,
+    duration: i64,
+}
+
+/// Render the records of `days` as Toggl time entries, with the offsets of `zone`
+pub fn export_json(days: &[DayData], zone: LocalZone) -> String {
+    let rfc3339 = |time: PrimitiveDateTime| {
+        zone.assume(time)
+            .format(&Rfc3339)
+            .expect("date-time formats as RFC 3339")
+    };
+    let entries: Vec<JsonEntry> = entries(days)
+        .into_iter()
+        .map(|entry| {
+            let end = entry.end.expect("exported entries are finished");
+            JsonEntry {
+                description: entry.description,
+                project: entry.project,
+                tags: entry.tags,
+                start: rfc3339(entry.start),
+                stop: rfc3339(end),
+                duration: (end - entry.start).whole_seconds(),
+            }
+        })
+        .collect();
+    serde_json::to_string_pretty(&entries).expect("entries serialize to JSON")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{TimePoint, WorkRecord};
+    use time::UtcOffset;
+    use time::macros::{date, datetime, offset};
+
+    fn summary(proposals: &[ProposedRecord]) -> Vec<(String, String, String, String)> {
+        proposals
+            .iter()
+            .map(|p| {
+                (
+                    p.name.clone(),
+                    p.description.clone(),
+                    p.start.to_string(),
+                    p.end.to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_detailed_csv() {
+        let csv = "\u{feff}User,Email,Client,Project,Task,Description,Billable,Start date,Start time,End date,End time,Duration,Tags\r\n\
+Ana,ana@example.com,,Website,,\"Header, footer\",Yes,2026-10-17,09:00:00,2026-10-17,10:30:00,01:30:00,\"billable, design\"\r\n\
+Ana,ana@example.com,,Internal,,,No,2026-10-17,23:30:00,2026-10-18,00:15:00,00:45:00,\r\n";
+
+        let entries = parse(csv, LocalZone::Fixed(UtcOffset::UTC)).unwrap();
+        assert_eq!(entries[0].tags, vec!["billable", "design"]);
+
+        assert_eq!(
+            summary(&propose_records(&entries)),
+            vec![
+                (
+                    "Header, footer".to_string(),
+                    "Website #billable #design".to_string(),
+                    "09:00".to_string(),
+                    "10:30".to_string()
+                ),
+                (
+                    "Internal".to_string(),
+                    String::new(),
+                    "23:30".to_string(),
+                    "00:15".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_with_duration_only() {
+        let csv = "Email,Start date,Start time,Duration,Description\n\
+a@b.c,2026-10-17,13:00:00,1:15:00,Review\n";
+
+        let proposals = propose_records(&parse(csv, LocalZone::Fixed(UtcOffset::UTC)).unwrap());
+        assert_eq!(proposals[0].end, TimePoint::new(14, 15).unwrap());
+
+        let error = parse("Description\nx\n", LocalZone::Fixed(UtcOffset::UTC)).unwrap_err();
+        assert!(error.contains("start date"));
+        let error = parse(
+            "Start date,Start time,Duration\n2026-10-17,9am,1:00:00\n",
+            LocalZone::Fixed(UtcOffset::UTC),
+        )
+        .unwrap_err();
+        assert!(error.starts_with("Row 2:"));
+    }
+
+    #[test]
+    fn test_parse_json_entries() {
+        let json = r#"[
+            {"description": "Standup", "start": "2026-10-17T07:00:00+00:00", "stop": "2026-10-17T07:15:00Z", "tags": ["meeting"]},
+            {"description": "Running", "start": "2026-10-17T08:00:00Z", "duration": -1760000000},
+            {"description": "", "project_name": "Ops", "start": "2026-10-17T08:00:00Z", "duration": 1800}
+        ]"#;
+        let entries = parse(json, LocalZone::Fixed(offset!(+2))).unwrap();
+        assert_eq!(entries[0].start, datetime!(2026-10-17 09:00));
+        assert_eq!(entries[1].end, None);
+
+        let report = r#"{"data": [{"description": "Review", "project": "Web", "start": "2026-10-17T09:00:00+02:00", "dur": 3600000}]}"#;
+        let mut entries = entries;
+        entries.extend(parse(report, LocalZone::Fixed(offset!(+2))).unwrap());
+
+        assert_eq!(
+            summary(&propose_records(&entries)),
+            vec![
+                (
+                    "Standup".to_string(),
+                    "#meeting".to_string(),
+                    "09:00".to_string(),
+                    "09:15".to_string()
+                ),
+                (
+                    "Ops".to_string(),
+                    String::new(),
+                    "10:00".to_string(),
+                    "10:30".to_string()
+                ),
+                (
+                    "Review".to_string(),
+                    "Web".to_string(),
+                    "09:00".to_string(),
+                    "10:00".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_export_round_trips() {
+        let mut day = DayData::new(date!(2026 - 10 - 17));
+        let mut record = WorkRecord::new(
+            1,
+            "Review \"PR\", part 2".to_string(),
+            TimePoint::new(23, 0).unwrap(),
+            TimePoint::new(0, 30).unwrap(),
+        );
+        record.description = "Website #billable".to_string();
+        day.add_record(record);
+
+        let csv = export_csv(std::slice::from_ref(&day), "me@example.com");
+        assert!(csv.contains(
+            "me@example.com,\"Review \"\"PR\"\", part 2\",Website,billable,2026-10-17,23:00:00,2026-10-18,00:30:00,01:30:00\r\n"
+        ));
+
+        for text in [csv, export_json(&[day], LocalZone::Fixed(offset!(+2)))] {
+            let proposals = propose_records(&parse(&text, LocalZone::Fixed(offset!(+2))).unwrap());
+            assert_eq!(
+                summary(&proposals),
+                vec![(
+                    "Review \"PR\", part 2".to_string(),
+                    "Website #billable".to_string(),
+                    "23:00".to_string(),
+                    "00:30".to_string()
+                )]
+            );
+        }
+    }
+}
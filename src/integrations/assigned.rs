@@ -0,0 +1,98 @@
+//! Issues assigned to the user, offered as task names in the task picker
+
+use super::sync::{Issue, adapter_for};
+use crate::config::Config;
+use std::collections::BTreeMap;
+
+/// Assigned issues per tracker name
+pub type AssignedIssues = BTreeMap<String, Vec<Issue>>;
+
+/// Refetch assigned issues from every enabled tracker with a sync backend
+///
+/// Trackers that fail keep their previously fetched issues, so a cached list still works
+/// offline; their errors are returned as "tracker: message". Trackers that are no longer
+/// configured are dropped.
+pub fn refresh_assigned_issues(config: &Config, issues: &mut AssignedIssues) -> Vec<String> {
+    let mut errors = Vec::new();
+    let trackers: Vec<_> = config
+        .integrations
+        .trackers
+        .iter()
+        .filter(|(_, t)| t.enabled && t.sync.is_some())
+        .collect();
+
+    issues.retain(|name, _| trackers.iter().any(|(tracker, _)| *tracker == name));
+
+    for (name, tracker) in trackers {
+        match adapter_for(tracker).and_then(|adapter| adapter.assigned_issues()) {
+            Ok(fetched) => {
+                issues.insert(name.clone(), fetched);
+            }
+            Err(e) => errors.push(format!("{}: {}", name, e)),
+        }
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::mock_server;
+
+    fn create_config(jira_url: &str) -> Config {
+        let toml_str = format!(
+            r#"
+[integrations.trackers.jira]
+enabled = true
+base_url = "{}"
+ticket_patterns = ["^PROJ-\\d+$"]
+sync = "jira"
+api_token = "secret"
+
+[integrations.trackers.docs]
+enabled = true
+base_url = "https://docs.example.com"
+ticket_patterns = ["^DOC-\\d+$"]
+            "#,
+            jira_url
+        );
+        toml::from_str(&toml_str).unwrap()
+    }
+
+    fn issue(ticket: &str, title: &str) -> Issue {
+        Issue {
+            ticket: ticket.to_string(),
+            title: title.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_refresh_replaces_tracker_issues() {
+        let (url, _requests) = mock_server::serve(vec![(
+            200,
+            r#"{"issues":[{"key":"PROJ-9","fields":{"summary":"New work"}}]}"#,
+        )]);
+        let mut issues = AssignedIssues::new();
+        issues.insert("jira".to_string(), vec![issue("PROJ-1", "Done already")]);
+        issues.insert("removed".to_string(), vec![issue("OLD-1", "Gone")]);
+
+        let errors = refresh_assigned_issues(&create_config(&url), &mut issues);
+
+        assert!(errors.is_empty());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues["jira"], vec![issue("PROJ-9", "New work")]);
+    }
+
+    #[test]
+    fn test_failed_refresh_keeps_cached_issues() {
+        let mut issues = AssignedIssues::new();
+        issues.insert("jira".to_string(), vec![issue("PROJ-1", "Cached")]);
+
+        let errors = refresh_assigned_issues(&create_config("http://127.0.0.1:1"), &mut issues);
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("jira: "));
+        assert_eq!(issues["jira"], vec![issue("PROJ-1", "Cached")]);
+    }
+}
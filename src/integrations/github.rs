@@ -0,0 +1,293 @@
+//! GitHub time sync
+//!
+//! Posts one comment per issue per day summarizing the time spent, via the REST API.
+//! Re-syncing edits that comment.
+
+use super::sync::{
+    Granularity, Issue, SyncAdapter, SyncEntry, SyncError, http_agent, require_token,
+};
+use crate::config::TrackerConfig;
+use serde::Deserialize;
+use serde_json::json;
+
+pub const DEFAULT_API_URL: &str = "https://api.github.com";
+
+#[derive(Deserialize)]
+struct CommentResponse {
+    id: u64,
+}
+
+#[derive(Deserialize)]
+struct IssueResponse {
+    title: String,
+    #[serde(default)]
+    number: u64,
+    repository: Option<RepositoryResponse>,
+    /// Present when the "issue" is a pull request
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct RepositoryResponse {
+    full_name: String,
+}
+
+/// GitHub REST adapter, one issue comment per ticket per day
+pub struct GitHubAdapter {
+    agent: ureq::Agent,
+    api_url: String,
+    /// `owner/repo` from the tracker's `base_url`, used for tickets without a repo
+    default_repo: Option<String>,
+    authorization: String,
+}
+
+impl GitHubAdapter {
+    /// Create an adapter from a tracker's `api_token`, `base_url` and optional `api_url`
+    pub fn from_tracker(tracker: &TrackerConfig) -> Result<Self, SyncError> {
+        Ok(GitHubAdapter {
+            agent: http_agent(),
+            api_url: tracker
+                .api_url
+                .as_deref()
+                .unwrap_or(DEFAULT_API_URL)
+                .trim_end_matches('/')
+                .to_string(),
+            default_repo: repo_from_url(&tracker.base_url),
+            authorization: format!("Bearer {}", require_token(tracker)?),
+        })
+    }
+
+    /// Split a ticket (`#123`, `123` or `owner/repo#123`) into repository and issue number
+    fn issue_ref(&self, ticket: &str) -> Result<(String, u64), SyncError> {
+        let (repo, number) = match ticket.rsplit_once('#') {
+            Some((repo, number)) if !repo.is_empty() => (Some(repo.to_string()), number),
+            Some((_, number)) => (None, number),
+            None => (None, ticket),
+        };
+        let number = number
+            .parse()
+            .map_err(|_| SyncError::fatal(format!("'{}' is not a GitHub issue number", ticket)))?;
+        let repo = repo.or_else(|| self.default_repo.clone()).ok_or_else(|| {
+            SyncError::fatal("base_url must point to a repository (https://github.com/owner/repo)")
+        })?;
+        Ok((repo, number))
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Authorization", &self.authorization)
+            .set("Accept", "application/vnd.github+json")
+            .set("X-GitHub-Api-Version", "2022-11-28")
+    }
+}
+
+impl SyncAdapter for GitHubAdapter {
+    fn granularity(&self) -> Granularity {
+        Granularity::PerTicketDay
+    }
+
+    fn create(&self, entry: &SyncEntry) -> Result<String, SyncError> {
+        let (repo, number) = self.issue_ref(&entry.ticket)?;
+        let url = format!("{}/repos/{}/issues/{}/comments", self.api_url, repo, number);
+        let comment: CommentResponse = self
+            .request("POST", &url)
+            .send_json(json!({ "body": entry.body }))
+            .map_err(|e| SyncError::from_ureq(e, "GitHub"))?
+            .into_json()
+            .map_err(|e| SyncError::fatal(format!("Unexpected response from GitHub: {}", e)))?;
+        Ok(comment.id.to_string())
+    }
+
+    fn update(&self, remote_id: &str, entry: &SyncEntry) -> Result<(), SyncError> {
+        let (repo, _) = self.issue_ref(&entry.ticket)?;
+        let url = format!(
+            "{}/repos/{}/issues/comments/{}",
+            self.api_url, repo, remote_id
+        );
+        self.request("PATCH", &url)
+            .send_json(json!({ "body": entry.body }))
+            .map_err(|e| SyncError::from_ureq(e, "GitHub"))?;
+        Ok(())
+    }
+
+    fn fetch_title(&self, ticket: &str) -> Result<String, SyncError> {
+        let (repo, number) = self.issue_ref(ticket)?;
+        let url = format!("{}/repos/{}/issues/{}", self.api_url, repo, number);
+        let issue: IssueResponse = self
+            .request("GET", &url)
+            .call()
+            .map_err(|e| SyncError::from_ureq(e, "GitHub"))?
+            .into_json()
+            .map_err(|e| SyncError::fatal(format!("Unexpected response from GitHub: {}", e)))?;
+        Ok(issue.title)
+    }
+
+    fn assigned_issues(&self) -> Result<Vec<Issue>, SyncError> {
+        let url = format!("{}/issues", self.api_url);
+        let issues: Vec<IssueResponse> = self
+            .request("GET", &url)
+            .query("filter", "assigned")
+            .query("state", "open")
+            .query("per_page", "50")
+            .call()
+            .map_err(|e| SyncError::from_ureq(e, "GitHub"))?
+            .into_json()
+            .map_err(|e| SyncError::fatal(format!("Unexpected response from GitHub: {}", e)))?;
+
+        Ok(issues
+            .into_iter()
+            .filter(|issue| issue.pull_request.is_none())
+            .map(|issue| {
+                // Issues outside the configured repository keep their repo in the ticket
+                let repo = issue.repository.map(|r| r.full_name);
+                let ticket = match repo {
+                    Some(repo) if Some(&repo) != self.default_repo.as_ref() => {
+                        format!("{}#{}", repo, issue.number)
+                    }
+                    _ => format!("#{}", issue.number),
+                };
+                Issue {
+                    ticket,
+                    title: issue.title,
+                }
+            })
+            .collect())
+    }
+}
+
+/// Extract `owner/repo` from a repository URL like `https://github.com/owner/repo`
+fn repo_from_url(url: &str) -> Option<String> {
+    let path = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let mut parts = path.trim_end_matches('/').split('/').skip(1);
+    match (parts.next(), parts.next()) {
+        (Some(owner), Some(repo)) if !owner.is_empty() && !repo.is_empty() => {
+            Some(format!("{}/{}", owner, repo.trim_end_matches(".git")))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::mock_server;
+    use crate::integrations::sync::SyncErrorKind;
+    use time::OffsetDateTime;
+    use time::macros::date;
+
+    fn create_adapter(api_url: &str) -> GitHubAdapter {
+        let tracker = TrackerConfig {
+            base_url: "https://github.com/acme/app".to_string(),
+            api_token: Some("ghp_token".to_string()),
+            api_url: Some(api_url.to_string()),
+            ..Default::default()
+        };
+        GitHubAdapter::from_tracker(&tracker).unwrap()
+    }
+
+    fn create_entry(ticket: &str) -> SyncEntry {
+        SyncEntry {
+            ticket: ticket.to_string(),
+            date: date!(2026 - 10 - 17),
+            started: OffsetDateTime::UNIX_EPOCH,
+            seconds: 3600,
+            body: "Time logged".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_create_and_update_comment() {
+        let (url, requests) = mock_server::serve(vec![(201, r#"{"id":555}"#), (200, "{}")]);
+        let adapter = create_adapter(&url);
+
+        let id = adapter.create(&create_entry("#12")).unwrap();
+        assert_eq!(id, "555");
+        adapter.update(&id, &create_entry("#12")).unwrap();
+
+        let create = requests.recv().unwrap();
+        assert_eq!(create.method, "POST");
+        assert_eq!(create.url, "/repos/acme/app/issues/12/comments");
+        assert_eq!(create.authorization.as_deref(), Some("Bearer ghp_token"));
+        assert_eq!(create.body["body"], "Time logged");
+
+        let update = requests.recv().unwrap();
+        assert_eq!(update.method, "PATCH");
+        assert_eq!(update.url, "/repos/acme/app/issues/comments/555");
+    }
+
+    #[test]
+    fn test_fetch_title() {
+        let (url, requests) =
+            mock_server::serve(vec![(200, r#"{"number":12,"title":"Crash on start"}"#)]);
+        let adapter = create_adapter(&url);
+
+        assert_eq!(adapter.fetch_title("#12").unwrap(), "Crash on start");
+        assert_eq!(requests.recv().unwrap().url, "/repos/acme/app/issues/12");
+    }
+
+    #[test]
+    fn test_assigned_issues() {
+        let (url, requests) = mock_server::serve(vec![(
+            200,
+            r#"[{"number":12,"title":"Crash on start","repository":{"full_name":"acme/app"}},
+                {"number":3,"title":"Bump deps","repository":{"full_name":"acme/lib"}},
+                {"number":4,"title":"PR","repository":{"full_name":"acme/app"},"pull_request":{}}]"#,
+        )]);
+        let adapter = create_adapter(&url);
+
+        let names: Vec<String> = adapter
+            .assigned_issues()
+            .unwrap()
+            .iter()
+            .map(Issue::task_name)
+            .collect();
+        assert_eq!(
+            names,
+            vec!["#12 - Crash on start", "acme/lib#3 - Bump deps"]
+        );
+        assert!(
+            requests
+                .recv()
+                .unwrap()
+                .url
+                .starts_with("/issues?filter=assigned")
+        );
+    }
+
+    #[test]
+    fn test_ticket_repo_overrides_base_url() {
+        let adapter = create_adapter("http://unused");
+        assert_eq!(
+            adapter.issue_ref("other/lib#7").unwrap(),
+            ("other/lib".to_string(), 7)
+        );
+        assert_eq!(
+            adapter.issue_ref("42").unwrap(),
+            ("acme/app".to_string(), 42)
+        );
+        assert!(adapter.issue_ref("#abc").is_err());
+    }
+
+    #[test]
+    fn test_missing_comment_is_not_found() {
+        let (url, _requests) = mock_server::serve(vec![(404, r#"{"message":"Not Found"}"#)]);
+        let adapter = create_adapter(&url);
+
+        let error = adapter.update("1", &create_entry("#12")).unwrap_err();
+        assert_eq!(error.kind, SyncErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_repo_from_url() {
+        assert_eq!(
+            repo_from_url("https://github.com/acme/app/"),
+            Some("acme/app".to_string())
+        );
+        assert_eq!(
+            repo_from_url("https://github.com/acme/app.git"),
+            Some("acme/app".to_string())
+        );
+        assert_eq!(repo_from_url("https://github.com/acme"), None);
+    }
+}
@@ -0,0 +1,488 @@
+//! Jira worklog sync
+//!
+//! Pushes each ticketed work record to the issue's worklog via the REST API (v2).
+//! The created worklog ID is stored on the record, so syncing the same day again
+//! updates the existing worklogs instead of adding duplicates.
+
+use super::sync::{
+    Granularity, Issue, SyncAdapter, SyncEntry, SyncError, http_agent, require_token,
+};
+use crate::config::TrackerConfig;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+/// Worklog payload for `POST/PUT /rest/api/2/issue/{ticket}/worklog`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Worklog {
+    /// Start of the work in Jira's format (`2026-10-17T14:00:00.000+0200`)
+    pub started: String,
+    pub time_spent_seconds: u64,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub comment: String,
+}
+
+impl Worklog {
+    pub fn from_entry(entry: &SyncEntry) -> Self {
+        let format = time::macros::format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second].000[offset_hour sign:mandatory][offset_minute]"
+        );
+
+        Worklog {
+            started: entry
+                .started
+                .format(&format)
+                .expect("worklog date format is valid"),
+            time_spent_seconds: entry.seconds,
+            comment: entry.body.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct WorklogResponse {
+    id: String,
+}
+
+/// Open issues assigned to the API user, most recently updated first
+const ASSIGNED_JQL: &str =
+    "assignee = currentUser() AND resolution = Unresolved ORDER BY updated DESC";
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    issues: Vec<IssueResponse>,
+}
+
+#[derive(Deserialize)]
+struct IssueResponse {
+    #[serde(default)]
+    key: String,
+    fields: IssueFields,
+}
+
+#[derive(Deserialize)]
+struct IssueFields {
+    summary: String,
+}
+
+/// Jira REST adapter, one worklog per record
+pub struct JiraAdapter {
+    agent: ureq::Agent,
+    base_url: String,
+    authorization: String,
+}
+
+impl JiraAdapter {
+    /// Create an adapter from a tracker's `base_url`, `api_token` and optional `api_user`
+    pub fn from_tracker(tracker: &TrackerConfig) -> Result<Self, SyncError> {
+        let token = require_token(tracker)?;
+
+        // Jira Cloud uses basic auth with email:token, Server/Data Center personal tokens use Bearer
+        let authorization = match tracker.api_user.as_deref().filter(|u| !u.is_empty()) {
+            Some(user) => format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", user, token))
+            ),
+            None => format!("Bearer {}", token),
+        };
+
+        Ok(JiraAdapter {
+            agent: http_agent(),
+            base_url: tracker.base_url.trim_end_matches('/').to_string(),
+            authorization,
+        })
+    }
+
+    fn worklog_url(&self, ticket: &str, worklog_id: Option<&str>) -> String {
+        match worklog_id {
+            Some(id) => format!(
+                "{}/rest/api/2/issue/{}/worklog/{}",
+                self.base_url, ticket, id
+            ),
+            None => format!("{}/rest/api/2/issue/{}/worklog", self.base_url, ticket),
+        }
+    }
+}
+
+impl SyncAdapter for JiraAdapter {
+    fn granularity(&self) -> Granularity {
+        Granularity::PerRecord
+    }
+
+    fn create(&self, entry: &SyncEntry) -> Result<String, SyncError> {
+        let response = self
+            .agent
+            .post(&self.worklog_url(&entry.ticket, None))
+            .set("Authorization", &self.authorization)
+            .set("Accept", "application/json")
+            .send_json(Worklog::from_entry(entry))
+            .map_err(|e| SyncError::from_ureq(e, "Jira"))?;
+
+        let body: WorklogResponse = response
+            .into_json()
+            .map_err(|e| SyncError::fatal(format!("Unexpected response from Jira: {}", e)))?;
+        Ok(body.id)
+    }
+
+    fn update(&self, remote_id: &str, entry: &SyncEntry) -> Result<(), SyncError> {
+        self.agent
+            .put(&self.worklog_url(&entry.ticket, Some(remote_id)))
+            .set("Authorization", &self.authorization)
+            .set("Accept", "application/json")
+            .send_json(Worklog::from_entry(entry))
+            .map_err(|e| SyncError::from_ureq(e, "Jira"))?;
+        Ok(())
+    }
+
+    fn delete(&self, remote_id: &str, ticket: &str) -> Result<(), SyncError> {
+        self.agent
+            .delete(&self.worklog_url(ticket, Some(remote_id)))
+            .set("Authorization", &self.authorization)
+            .set("Accept", "application/json")
+            .call()
+            .map_err(|e| SyncError::from_ureq(e, "Jira"))?;
+        Ok(())
+    }
+
+    fn fetch_title(&self, ticket: &str) -> Result<String, SyncError> {
+        let issue: IssueResponse = self
+            .agent
+            .get(&format!("{}/rest/api/2/issue/{}", self.base_url, ticket))
+            .query("fields", "summary")
+            .set("Authorization", &self.authorization)
+            .set("Accept", "application/json")
+            .call()
+            .map_err(|e| SyncError::from_ureq(e, "Jira"))?
+            .into_json()
+            .map_err(|e| SyncError::fatal(format!("Unexpected response from Jira: {}", e)))?;
+        Ok(issue.fields.summary)
+    }
+
+    fn assigned_issues(&self) -> Result<Vec<Issue>, SyncError> {
+        let search: SearchResponse = self
+            .agent
+            .get(&format!("{}/rest/api/2/search", self.base_url))
+            .query("jql", ASSIGNED_JQL)
+            .query("fields", "summary")
+            .query("maxResults", "50")
+            .set("Authorization", &self.authorization)
+            .set("Accept", "application/json")
+            .call()
+            .map_err(|e| SyncError::from_ureq(e, "Jira"))?
+            .into_json()
+            .map_err(|e| SyncError::fatal(format!("Unexpected response from Jira: {}", e)))?;
+
+        Ok(search
+            .issues
+            .into_iter()
+            .map(|issue| Issue {
+                ticket: issue.key,
+                title: issue.fields.summary,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::integrations::mock_server;
+    use crate::integrations::sync::{RetryPolicy, SyncAction, SyncOptions, sync_day};
+    use crate::models::{DayData, LocalZone, TimePoint, WorkRecord};
+    use std::time::Duration;
+    use time::UtcOffset;
+    use time::macros::date;
+
+    fn create_config(base_url: &str) -> Config {
+        let toml_str = format!(
+            r#"
+[integrations.trackers.work-jira]
+enabled = true
+base_url = "{}"
+ticket_patterns = ["^PROJ-\\d+$"]
+browse_url = "{{base_url}}/browse/{{ticket}}"
+sync = "jira"
+api_token = "secret"
+api_user = "me@example.com"
+            "#,
+            base_url
+        );
+        toml::from_str(&toml_str).unwrap()
+    }
+
+    fn options(dry_run: bool) -> SyncOptions {
+        SyncOptions {
+            zone: LocalZone::Fixed(UtcOffset::UTC),
+            dry_run,
+            retry: RetryPolicy {
+                attempts: 1,
+                base_delay: Duration::ZERO,
+            },
+        }
+    }
+
+    fn create_day() -> DayData {
+        let mut day = DayData::new(date!(2026 - 10 - 17));
+        let mut record = WorkRecord::new(
+            1,
+            "PROJ-42 Code review".to_string(),
+            TimePoint::new(14, 0).unwrap(),
+            TimePoint::new(15, 30).unwrap(),
+        );
+        record.description = "Reviewed PR".to_string();
+        day.add_record(record);
+        day.add_record(WorkRecord::new(
+            2,
+            "Lunch".to_string(),
+            TimePoint::new(12, 0).unwrap(),
+            TimePoint::new(13, 0).unwrap(),
+        ));
+        day
+    }
+
+    #[test]
+    fn test_worklog_from_entry() {
+        let entry = SyncEntry {
+            ticket: "PROJ-42".to_string(),
+            date: date!(2026 - 10 - 17),
+            started: time::macros::datetime!(2026-10-17 14:00 +2),
+            seconds: 5400,
+            body: "Reviewed PR".to_string(),
+        };
+        let worklog = Worklog::from_entry(&entry);
+
+        assert_eq!(worklog.started, "2026-10-17T14:00:00.000+0200");
+        assert_eq!(worklog.time_spent_seconds, 5400);
+        assert_eq!(worklog.comment, "Reviewed PR");
+    }
+
+    #[test]
+    fn test_sync_creates_then_updates_worklog() {
+        let (url, requests) =
+            mock_server::serve(vec![(201, r#"{"id":"10001"}"#), (200, r#"{"id":"10001"}"#)]);
+        let config = create_config(&url);
+        let mut day = create_day();
+
+        let outcomes = sync_day(&mut day, &config, &options(false));
+        let synced = outcomes.iter().find(|o| o.record_ids == [1]).unwrap();
+        assert_eq!(synced.action, SyncAction::Created);
+        assert_eq!(synced.tracker.as_deref(), Some("work-jira"));
+        assert_eq!(day.work_records[&1].worklog_id.as_deref(), Some("10001"));
+
+        let lunch = outcomes.iter().find(|o| o.record_ids == [2]).unwrap();
+        assert_eq!(lunch.action, SyncAction::Skipped);
+
+        let created = requests.recv().unwrap();
+        assert_eq!(created.method, "POST");
+        assert_eq!(created.url, "/rest/api/2/issue/PROJ-42/worklog");
+        assert_eq!(
+            created.authorization.as_deref(),
+            Some("Basic bWVAZXhhbXBsZS5jb206c2VjcmV0")
+        );
+        assert_eq!(created.body["timeSpentSeconds"], 5400);
+        assert_eq!(created.body["comment"], "Reviewed PR");
+
+        // Second sync updates the same worklog instead of creating another
+        day.work_records.get_mut(&1).unwrap().total_minutes = 120;
+        let outcomes = sync_day(&mut day, &config, &options(false));
+        let synced = outcomes.iter().find(|o| o.record_ids == [1]).unwrap();
+        assert_eq!(synced.action, SyncAction::Updated);
+
+        let updated = requests.recv().unwrap();
+        assert_eq!(updated.method, "PUT");
+        assert_eq!(updated.url, "/rest/api/2/issue/PROJ-42/worklog/10001");
+        assert_eq!(updated.body["timeSpentSeconds"], 7200);
+    }
+
+    #[test]
+    fn test_sync_recreates_deleted_worklog() {
+        let (url, requests) = mock_server::serve(vec![(404, "{}"), (201, r#"{"id":"20002"}"#)]);
+        let config = create_config(&url);
+        let mut day = create_day();
+        day.work_records.get_mut(&1).unwrap().worklog_id = Some("10001".to_string());
+
+        let outcomes = sync_day(&mut day, &config, &options(false));
+        let synced = outcomes.iter().find(|o| o.record_ids == [1]).unwrap();
+        assert_eq!(synced.action, SyncAction::Created);
+        assert_eq!(day.work_records[&1].worklog_id.as_deref(), Some("20002"));
+
+        assert_eq!(requests.recv().unwrap().method, "PUT");
+        assert_eq!(requests.recv().unwrap().method, "POST");
+    }
+
+    #[test]
+    fn test_sync_moves_worklog_of_renamed_record() {
+        let (url, requests) = mock_server::serve(vec![(204, ""), (201, r#"{"id":"20002"}"#)]);
+        let config = create_config(&url);
+        let mut day = create_day();
+        let record = day.work_records.get_mut(&1).unwrap();
+        record.name = "PROJ-43 Code review".to_string();
+        record.worklog_id = Some("10001".to_string());
+        record.worklog_ticket = Some("work-jira:PROJ-42".to_string());
+
+        let outcomes = sync_day(&mut day, &config, &options(false));
+        let actions: Vec<_> = outcomes
+            .iter()
+            .filter(|o| o.record_ids == [1])
+            .map(|o| (o.action.clone(), o.ticket.clone().unwrap()))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                (SyncAction::Deleted, "PROJ-42".to_string()),
+                (SyncAction::Created, "PROJ-43".to_string())
+            ]
+        );
+        let record = &day.work_records[&1];
+        assert_eq!(record.worklog_id.as_deref(), Some("20002"));
+        assert_eq!(record.worklog_ticket.as_deref(), Some("work-jira:PROJ-43"));
+
+        let deleted = requests.recv().unwrap();
+        assert_eq!(deleted.method, "DELETE");
+        assert_eq!(deleted.url, "/rest/api/2/issue/PROJ-42/worklog/10001");
+        assert_eq!(
+            requests.recv().unwrap().url,
+            "/rest/api/2/issue/PROJ-43/worklog"
+        );
+    }
+
+    #[test]
+    fn test_sync_keeps_record_when_old_worklog_survives() {
+        let (url, requests) = mock_server::serve(vec![(403, "{}")]);
+        let config = create_config(&url);
+        let mut day = create_day();
+        let record = day.work_records.get_mut(&1).unwrap();
+        record.name = "PROJ-43 Code review".to_string();
+        record.worklog_id = Some("10001".to_string());
+        record.worklog_ticket = Some("work-jira:PROJ-42".to_string());
+
+        let outcomes = sync_day(&mut day, &config, &options(false));
+        let failed: Vec<_> = outcomes.iter().filter(|o| o.record_ids == [1]).collect();
+        assert_eq!(failed.len(), 2);
+        assert!(failed.iter().all(|o| o.action == SyncAction::Failed));
+        assert_eq!(day.work_records[&1].worklog_id.as_deref(), Some("10001"));
+
+        // Only the DELETE was sent; no second worklog was created
+        assert_eq!(requests.recv().unwrap().method, "DELETE");
+        assert!(requests.recv().is_err());
+    }
+
+    #[test]
+    fn test_sync_deletes_worklog_of_record_without_ticket() {
+        let (url, requests) = mock_server::serve(vec![(404, "{}")]);
+        let config = create_config(&url);
+        let mut day = create_day();
+        let record = day.work_records.get_mut(&1).unwrap();
+        record.name = "Code review".to_string();
+        record.worklog_id = Some("10001".to_string());
+        record.worklog_ticket = Some("work-jira:PROJ-42".to_string());
+
+        let outcomes = sync_day(&mut day, &config, &options(false));
+        assert!(
+            outcomes
+                .iter()
+                .any(|o| o.record_ids == [1] && o.action == SyncAction::Deleted)
+        );
+        assert!(day.work_records[&1].worklog_id.is_none());
+        assert_eq!(requests.recv().unwrap().method, "DELETE");
+    }
+
+    #[test]
+    fn test_sync_reports_server_errors() {
+        let (url, _requests) =
+            mock_server::serve(vec![(403, r#"{"errorMessages":["Forbidden"]}"#)]);
+        let config = create_config(&url);
+        let mut day = create_day();
+
+        let outcomes = sync_day(&mut day, &config, &options(false));
+        let failed = outcomes.iter().find(|o| o.record_ids == [1]).unwrap();
+        assert_eq!(failed.action, SyncAction::Failed);
+        assert!(failed.detail.contains("403"));
+        assert!(day.work_records[&1].worklog_id.is_none());
+    }
+
+    #[test]
+    fn test_dry_run_sends_nothing() {
+        // Unroutable URL: any request would fail the test
+        let config = create_config("http://127.0.0.1:1");
+        let mut day = create_day();
+
+        let outcomes = sync_day(&mut day, &config, &options(true));
+        let planned = outcomes.iter().find(|o| o.record_ids == [1]).unwrap();
+        assert_eq!(planned.action, SyncAction::Created);
+        assert_eq!(planned.detail, "dry run");
+        assert!(day.work_records[&1].worklog_id.is_none());
+    }
+
+    #[test]
+    fn test_trackers_without_sync_are_skipped() {
+        let mut config = create_config("http://127.0.0.1:1");
+        config
+            .integrations
+            .trackers
+            .get_mut("work-jira")
+            .unwrap()
+            .sync = None;
+        let mut day = create_day();
+
+        let outcomes = sync_day(&mut day, &config, &options(false));
+        assert!(outcomes.iter().all(|o| o.action == SyncAction::Skipped));
+    }
+
+    #[test]
+    fn test_fetch_title() {
+        let (url, requests) = mock_server::serve(vec![(
+            200,
+            r#"{"key":"PROJ-42","fields":{"summary":"Fix login"}}"#,
+        )]);
+        let tracker = &create_config(&url).integrations.trackers["work-jira"];
+        let adapter = JiraAdapter::from_tracker(tracker).unwrap();
+
+        assert_eq!(adapter.fetch_title("PROJ-42").unwrap(), "Fix login");
+        assert_eq!(
+            requests.recv().unwrap().url,
+            "/rest/api/2/issue/PROJ-42?fields=summary"
+        );
+    }
+
+    #[test]
+    fn test_assigned_issues() {
+        let (url, requests) = mock_server::serve(vec![(
+            200,
+            r#"{"issues":[{"key":"PROJ-1","fields":{"summary":"Fix login"}},
+                          {"key":"PROJ-2","fields":{"summary":"Add export"}}]}"#,
+        )]);
+        let tracker = &create_config(&url).integrations.trackers["work-jira"];
+        let adapter = JiraAdapter::from_tracker(tracker).unwrap();
+
+        let issues = adapter.assigned_issues().unwrap();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].task_name(), "PROJ-1 - Fix login");
+        assert!(
+            requests
+                .recv()
+                .unwrap()
+                .url
+                .starts_with("/rest/api/2/search?jql=assignee")
+        );
+    }
+
+    #[test]
+    fn test_bearer_auth_without_user() {
+        let tracker = TrackerConfig {
+            base_url: "https://jira.example.com/".to_string(),
+            api_token: Some("pat".to_string()),
+            ..Default::default()
+        };
+        let adapter = JiraAdapter::from_tracker(&tracker).unwrap();
+        assert_eq!(adapter.authorization, "Bearer pat");
+        assert_eq!(
+            adapter.worklog_url("PROJ-1", None),
+            "https://jira.example.com/rest/api/2/issue/PROJ-1/worklog"
+        );
+
+        let missing = TrackerConfig::default();
+        assert!(JiraAdapter::from_tracker(&missing).is_err());
+    }
+}
@@ -0,0 +1,287 @@
+//! Linear time sync
+//!
+//! Linear has no worklogs, so sync posts one comment per issue per day summarizing
+//! the time spent, via the GraphQL API. Re-syncing edits that comment.
+
+use super::sync::{
+    Granularity, Issue, SyncAdapter, SyncEntry, SyncError, http_agent, require_token,
+};
+use crate::config::TrackerConfig;
+use serde_json::{Value, json};
+
+pub const DEFAULT_API_URL: &str = "https://api.linear.app/graphql";
+
+const ISSUE_QUERY: &str = "query Issue($id: String!) { issue(id: $id) { id title } }";
+const ASSIGNED_QUERY: &str = "query Assigned { viewer { assignedIssues(first: 50, \
+     filter: { state: { type: { nin: [\"completed\", \"canceled\"] } } }) \
+     { nodes { identifier title } } } }";
+const CREATE_MUTATION: &str = "mutation CommentCreate($input: CommentCreateInput!) { \
+     commentCreate(input: $input) { success comment { id } } }";
+const UPDATE_MUTATION: &str = "mutation CommentUpdate($id: String!, $input: CommentUpdateInput!) { \
+     commentUpdate(id: $id, input: $input) { success } }";
+
+/// Linear GraphQL adapter, one comment per ticket per day
+pub struct LinearAdapter {
+    agent: ureq::Agent,
+    api_url: String,
+    api_key: String,
+}
+
+impl LinearAdapter {
+    /// Create an adapter from a tracker's `api_token` (a personal API key) and optional `api_url`
+    pub fn from_tracker(tracker: &TrackerConfig) -> Result<Self, SyncError> {
+        Ok(LinearAdapter {
+            agent: http_agent(),
+            api_url: tracker
+                .api_url
+                .clone()
+                .unwrap_or_else(|| DEFAULT_API_URL.to_string()),
+            api_key: require_token(tracker)?.to_string(),
+        })
+    }
+
+    /// Run a GraphQL request and return its `data`
+    fn graphql(&self, query: &str, variables: Value) -> Result<Value, SyncError> {
+        let response: Value = self
+            .agent
+            .post(&self.api_url)
+            // Personal API keys are sent as-is, without a Bearer prefix
+            .set("Authorization", &self.api_key)
+            .send_json(json!({ "query": query, "variables": variables }))
+            .map_err(|e| SyncError::from_ureq(e, "Linear"))?
+            .into_json()
+            .map_err(|e| SyncError::fatal(format!("Unexpected response from Linear: {}", e)))?;
+
+        // GraphQL reports errors with a 200 status
+        if let Some(message) = response["errors"][0]["message"].as_str() {
+            let error = format!("Linear returned an error: {}", message);
+            return Err(if message.to_lowercase().contains("not found") {
+                SyncError::not_found(error)
+            } else {
+                SyncError::fatal(error)
+            });
+        }
+        Ok(response["data"].clone())
+    }
+
+    /// Look up an issue by identifier (`ENG-123`) and return one of its fields
+    fn issue_field(&self, ticket: &str, field: &str) -> Result<String, SyncError> {
+        let data = self.graphql(ISSUE_QUERY, json!({ "id": ticket }))?;
+        data["issue"][field]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| SyncError::fatal(format!("Linear issue {} not found", ticket)))
+    }
+}
+
+impl SyncAdapter for LinearAdapter {
+    fn granularity(&self) -> Granularity {
+        Granularity::PerTicketDay
+    }
+
+    fn create(&self, entry: &SyncEntry) -> Result<String, SyncError> {
+        let issue_id = self.issue_field(&entry.ticket, "id")?;
+        let data = self.graphql(
+            CREATE_MUTATION,
+            json!({ "input": { "issueId": issue_id, "body": entry.body } }),
+        )?;
+        data["commentCreate"]["comment"]["id"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| SyncError::fatal("Linear did not return a comment ID"))
+    }
+
+    fn update(&self, remote_id: &str, entry: &SyncEntry) -> Result<(), SyncError> {
+        self.graphql(
+            UPDATE_MUTATION,
+            json!({ "id": remote_id, "input": { "body": entry.body } }),
+        )?;
+        Ok(())
+    }
+
+    fn fetch_title(&self, ticket: &str) -> Result<String, SyncError> {
+        self.issue_field(ticket, "title")
+    }
+
+    fn assigned_issues(&self) -> Result<Vec<Issue>, SyncError> {
+        let data = self.graphql(ASSIGNED_QUERY, json!({}))?;
+        let nodes = data["viewer"]["assignedIssues"]["nodes"]
+            .as_array()
+            .ok_or_else(|| SyncError::fatal("Unexpected response from Linear"))?;
+
+        Ok(nodes
+            .iter()
+            .filter_map(|node| {
+                Some(Issue {
+                    ticket: node["identifier"].as_str()?.to_string(),
+                    title: node["title"].as_str()?.to_string(),
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::integrations::mock_server;
+    use crate::integrations::sync::{RetryPolicy, SyncAction, SyncOptions, sync_day};
+    use crate::models::{DayData, LocalZone, TimePoint, WorkRecord};
+    use std::time::Duration;
+    use time::UtcOffset;
+    use time::macros::date;
+
+    const ISSUE: &str = r#"{"data":{"issue":{"id":"issue-uuid"}}}"#;
+    const CREATED: &str =
+        r#"{"data":{"commentCreate":{"success":true,"comment":{"id":"comment-1"}}}}"#;
+
+    fn create_config(api_url: &str) -> Config {
+        let toml_str = format!(
+            r#"
+[integrations.trackers.linear]
+enabled = true
+base_url = "https://linear.app/team"
+ticket_patterns = ["^ENG-\\d+$"]
+sync = "linear"
+api_token = "lin_api_key"
+api_url = "{}/graphql"
+            "#,
+            api_url
+        );
+        toml::from_str(&toml_str).unwrap()
+    }
+
+    fn options() -> SyncOptions {
+        SyncOptions {
+            zone: LocalZone::Fixed(UtcOffset::UTC),
+            dry_run: false,
+            retry: RetryPolicy {
+                attempts: 1,
+                base_delay: Duration::ZERO,
+            },
+        }
+    }
+
+    fn create_day() -> DayData {
+        let mut day = DayData::new(date!(2026 - 10 - 17));
+        for (id, start, end) in [(1, 9, 10), (2, 13, 15)] {
+            day.add_record(WorkRecord::new(
+                id,
+                "ENG-7 Onboarding flow".to_string(),
+                TimePoint::new(start, 0).unwrap(),
+                TimePoint::new(end, 0).unwrap(),
+            ));
+        }
+        day
+    }
+
+    #[test]
+    fn test_sync_posts_one_comment_per_ticket() {
+        let (url, requests) = mock_server::serve(vec![(200, ISSUE), (200, CREATED)]);
+        let mut day = create_day();
+
+        let outcomes = sync_day(&mut day, &create_config(&url), &options());
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].action, SyncAction::Created);
+        assert_eq!(outcomes[0].record_ids, vec![1, 2]);
+        assert_eq!(
+            day.synced_comments.get("linear:ENG-7").map(String::as_str),
+            Some("comment-1")
+        );
+
+        let lookup = requests.recv().unwrap();
+        assert_eq!(lookup.url, "/graphql");
+        assert_eq!(lookup.authorization.as_deref(), Some("lin_api_key"));
+        assert_eq!(lookup.body["variables"]["id"], "ENG-7");
+
+        let create = requests.recv().unwrap();
+        assert_eq!(create.body["variables"]["input"]["issueId"], "issue-uuid");
+        let body = create.body["variables"]["input"]["body"].as_str().unwrap();
+        assert!(body.contains("3h 00m"));
+    }
+
+    #[test]
+    fn test_resync_updates_existing_comment() {
+        let (url, requests) = mock_server::serve(vec![(
+            200,
+            r#"{"data":{"commentUpdate":{"success":true}}}"#,
+        )]);
+        let mut day = create_day();
+        day.synced_comments
+            .insert("linear:ENG-7".to_string(), "comment-1".to_string());
+
+        let outcomes = sync_day(&mut day, &create_config(&url), &options());
+        assert_eq!(outcomes[0].action, SyncAction::Updated);
+
+        let update = requests.recv().unwrap();
+        assert_eq!(update.body["variables"]["id"], "comment-1");
+    }
+
+    #[test]
+    fn test_deleted_comment_is_recreated() {
+        let (url, _requests) = mock_server::serve(vec![
+            (
+                200,
+                r#"{"errors":[{"message":"Entity not found: Comment"}]}"#,
+            ),
+            (200, ISSUE),
+            (200, CREATED),
+        ]);
+        let mut day = create_day();
+        day.synced_comments
+            .insert("linear:ENG-7".to_string(), "stale".to_string());
+
+        let outcomes = sync_day(&mut day, &create_config(&url), &options());
+        assert_eq!(outcomes[0].action, SyncAction::Created);
+        assert_eq!(day.synced_comments["linear:ENG-7"], "comment-1");
+    }
+
+    #[test]
+    fn test_fetch_title() {
+        let (url, _requests) = mock_server::serve(vec![(
+            200,
+            r#"{"data":{"issue":{"id":"issue-uuid","title":"Onboarding flow"}}}"#,
+        )]);
+        let tracker = &create_config(&url).integrations.trackers["linear"];
+        let adapter = LinearAdapter::from_tracker(tracker).unwrap();
+
+        assert_eq!(adapter.fetch_title("ENG-7").unwrap(), "Onboarding flow");
+    }
+
+    #[test]
+    fn test_assigned_issues() {
+        let (url, requests) = mock_server::serve(vec![(
+            200,
+            r#"{"data":{"viewer":{"assignedIssues":{"nodes":[
+                {"identifier":"ENG-7","title":"Onboarding flow"}]}}}}"#,
+        )]);
+        let tracker = &create_config(&url).integrations.trackers["linear"];
+        let adapter = LinearAdapter::from_tracker(tracker).unwrap();
+
+        let issues = adapter.assigned_issues().unwrap();
+        assert_eq!(
+            issues,
+            vec![Issue {
+                ticket: "ENG-7".to_string(),
+                title: "Onboarding flow".to_string(),
+            }]
+        );
+        let query = requests.recv().unwrap().body["query"].to_string();
+        assert!(query.contains("assignedIssues"));
+    }
+
+    #[test]
+    fn test_graphql_errors_fail_sync() {
+        let (url, _requests) = mock_server::serve(vec![(
+            200,
+            r#"{"errors":[{"message":"Authentication required"}]}"#,
+        )]);
+        let mut day = create_day();
+
+        let outcomes = sync_day(&mut day, &create_config(&url), &options());
+        assert_eq!(outcomes[0].action, SyncAction::Failed);
+        assert!(outcomes[0].detail.contains("Authentication required"));
+        assert!(day.synced_comments.is_empty());
+    }
+}
@@ -0,0 +1,47 @@
+//! Local HTTP server standing in for tracker APIs in tests
+
+use std::sync::mpsc;
+use std::thread;
+
+/// Request captured by the mock server
+#[derive(Debug)]
+pub struct Captured {
+    pub method: String,
+    pub url: String,
+    pub authorization: Option<String>,
+    pub body: serde_json::Value,
+}
+
+/// Serve `responses` (status, body) in order on a local port, then stop
+///
+/// Returns the server's base URL and a channel yielding each request received.
+pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<Captured>) {
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        for (status, body) in responses {
+            let Ok(mut request) = server.recv() else {
+                return;
+            };
+            let mut content = String::new();
+            request.as_reader().read_to_string(&mut content).unwrap();
+            let authorization = request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Authorization"))
+                .map(|h| h.value.to_string());
+            let _ = sender.send(Captured {
+                method: request.method().to_string(),
+                url: request.url().to_string(),
+                authorization,
+                body: serde_json::from_str(&content).unwrap_or_default(),
+            });
+            let response = tiny_http::Response::from_string(body).with_status_code(status);
+            let _ = request.respond(response);
+        }
+    });
+
+    (url, receiver)
+}
@@ -1,24 +1,134 @@
+pub mod assigned;
+pub mod github;
+pub mod jira;
+pub mod linear;
+#[cfg(test)]
+pub(crate) mod mock_server;
+pub mod patterns;
+pub mod sync;
+pub mod titles;
+
 use crate::config::Config;
 use anyhow::Result;
-use regex::Regex;
+use patterns::GENERIC_TICKET_RE;
+use std::collections::HashMap;
+
+/// A ticket reference found in a task name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TicketRef {
+    /// The `ticket` capture group, or the whole match if the pattern has none
+    pub ticket: String,
+    /// The whole matched text, e.g. `owner/repo#45`
+    pub text: String,
+    /// Tracker whose pattern matched, or the default tracker for the generic pattern
+    pub tracker: Option<String>,
+    /// Named capture groups of the matching pattern (e.g. `repo`), for URL templates
+    pub groups: HashMap<String, String>,
+}
 
-/// Extract ticket ID from task name using regex pattern: "PROJ-123 - Task name" -> "PROJ-123"
-pub fn extract_ticket_from_name(name: &str) -> Option<String> {
-    // Match common ticket patterns: WORD-NUMBER (e.g., PROJ-123, WL-1, LIN-456)
-    let re = Regex::new(r"\b([A-Z]{2,10}-\d+)\b").ok()?;
+impl TicketRef {
+    /// Identifier to send to the tracker's API: the ticket, prefixed with `repo#` if
+    /// a repo was captured (`owner/repo#45`)
+    pub fn key(&self) -> String {
+        match self.groups.get("repo") {
+            Some(repo) if !repo.is_empty() => format!("{}#{}", repo, self.ticket),
+            _ => self.ticket.clone(),
+        }
+    }
+}
+
+/// Find all tickets in a task name, in order of appearance
+///
+/// Each enabled tracker's `ticket_patterns` are searched in the name (a leading `^` and
+/// trailing `$` only anchor the ticket itself, so `^#\d+$` finds `#123` anywhere). A
+/// `(?P<ticket>...)` group selects the ticket ID within the match, and other named groups
+/// such as `repo` are kept for URL templates. Uppercase `PROJ-123` keys that no tracker
+/// claims still match the generic pattern and go to the default tracker.
+pub fn extract_tickets(name: &str, config: &Config) -> Vec<TicketRef> {
+    // (start, end, priority, ticket): tracker patterns win over the generic one
+    let mut candidates: Vec<(usize, usize, u8, TicketRef)> = Vec::new();
+
+    for tracker in &config.integrations.matchers().trackers {
+        for pattern in &tracker.patterns {
+            let re = &pattern.search;
+            for caps in re.captures_iter(name) {
+                let whole = caps.get(0).expect("group 0 always matches");
+                if whole.is_empty() || !at_word_boundaries(name, whole.start(), whole.end()) {
+                    continue;
+                }
+                let groups: HashMap<String, String> = re
+                    .capture_names()
+                    .flatten()
+                    .filter_map(|group| {
+                        Some((group.to_string(), caps.name(group)?.as_str().to_string()))
+                    })
+                    .collect();
+                let ticket = groups
+                    .get("ticket")
+                    .cloned()
+                    .unwrap_or_else(|| whole.as_str().to_string());
+                candidates.push((
+                    whole.start(),
+                    whole.end(),
+                    0,
+                    TicketRef {
+                        ticket,
+                        text: whole.as_str().to_string(),
+                        tracker: Some(tracker.tracker.clone()),
+                        groups,
+                    },
+                ));
+            }
+        }
+    }
+
+    for m in GENERIC_TICKET_RE.find_iter(name) {
+        candidates.push((
+            m.start(),
+            m.end(),
+            1,
+            TicketRef {
+                ticket: m.as_str().to_string(),
+                text: m.as_str().to_string(),
+                tracker: detect_tracker(m.as_str(), config),
+                groups: HashMap::new(),
+            },
+        ));
+    }
+
+    // Leftmost first; at the same position prefer tracker patterns, then the longest match.
+    // The sort is stable, so remaining ties go to the tracker listed first in the config.
+    candidates.sort_by(|a, b| a.0.cmp(&b.0).then(a.2.cmp(&b.2)).then(b.1.cmp(&a.1)));
+
+    let mut tickets: Vec<TicketRef> = Vec::new();
+    let mut taken_until = 0;
+    for (start, end, _, ticket) in candidates {
+        if start < taken_until {
+            continue;
+        }
+        taken_until = end;
+        if !tickets.contains(&ticket) {
+            tickets.push(ticket);
+        }
+    }
+    tickets
+}
 
-    re.captures(name)
-        .and_then(|caps| caps.get(1))
-        .map(|m| m.as_str().to_string())
+/// Whether a match isn't glued to surrounding letters or digits ("xPROJ-1" or "#12a")
+fn at_word_boundaries(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].chars().next_back();
+    let after = text[end..].chars().next();
+    !before.is_some_and(|c| c.is_alphanumeric() || c == '_')
+        && !after.is_some_and(|c| c.is_alphanumeric() || c == '_')
 }
 
 /// Detect which tracker a ticket belongs to based on config patterns
 /// Returns the tracker name if a match is found
 pub fn detect_tracker(ticket: &str, config: &Config) -> Option<String> {
-    // Try each enabled tracker's patterns
-    for (name, tracker_config) in &config.integrations.trackers {
-        if tracker_config.enabled && matches_patterns(ticket, &tracker_config.ticket_patterns) {
-            return Some(name.clone());
+    // Try each enabled tracker's patterns, in config order
+    for tracker in &config.integrations.matchers().trackers {
+        if tracker.matches(ticket) {
+            return Some(tracker.tracker.clone());
         }
     }
 
@@ -26,23 +136,16 @@ pub fn detect_tracker(ticket: &str, config: &Config) -> Option<String> {
     config.integrations.default_tracker.clone()
 }
 
-/// Check if ticket matches any of the provided patterns
-fn matches_patterns(ticket: &str, patterns: &[String]) -> bool {
-    patterns.iter().any(|pattern| {
-        Regex::new(pattern)
-            .ok()
-            .map(|re| re.is_match(ticket))
-            .unwrap_or(false)
-    })
-}
-
-/// Build a URL for the given ticket and tracker name
-pub fn build_url(
-    ticket: &str,
-    tracker_name: &str,
-    config: &Config,
-    for_worklog: bool,
-) -> Result<String> {
+/// Build a URL for an extracted ticket
+///
+/// Templates can use `{base_url}`, `{ticket}` and any named group of the matching
+/// pattern (e.g. `{repo}`). If the template uses a group that didn't participate in the
+/// match, the URL can't be built and the tracker's `base_url` is returned instead.
+pub fn build_ticket_url(ticket: &TicketRef, config: &Config, for_worklog: bool) -> Result<String> {
+    let tracker_name = ticket
+        .tracker
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("No tracker for ticket '{}'", ticket.ticket))?;
     let tracker_config = config
         .integrations
         .trackers
@@ -59,9 +162,22 @@ pub fn build_url(
         &tracker_config.browse_url
     };
 
-    let url = template
+    let mut url = template
         .replace("{base_url}", &tracker_config.base_url)
-        .replace("{ticket}", ticket);
+        .replace("{ticket}", &ticket.ticket);
+
+    if let Some(patterns) = config.integrations.matchers().tracker(tracker_name) {
+        for group in patterns.group_names() {
+            let placeholder = format!("{{{}}}", group);
+            if !url.contains(&placeholder) {
+                continue;
+            }
+            match ticket.groups.get(group).filter(|value| !value.is_empty()) {
+                Some(value) => url = url.replace(&placeholder, value),
+                None => return Ok(tracker_config.base_url.clone()),
+            }
+        }
+    }
 
     Ok(url)
 }
@@ -73,50 +189,48 @@ mod tests {
     #[test]
     fn test_extract_ticket_simple() {
         let name = "PROJ-123 Fix login bug";
-        let ticket = extract_ticket_from_name(name);
-        assert_eq!(ticket, Some("PROJ-123".to_string()));
+        let tickets = extract_tickets(name, &Config::default());
+        assert_eq!(tickets[0].ticket, "PROJ-123");
     }
 
     #[test]
     fn test_extract_ticket_wl_format() {
         let name = "WL-1 Morning standup";
-        let ticket = extract_ticket_from_name(name);
-        assert_eq!(ticket, Some("WL-1".to_string()));
+        let tickets = extract_tickets(name, &Config::default());
+        assert_eq!(tickets[0].ticket, "WL-1");
     }
 
     #[test]
     fn test_extract_ticket_lin_format() {
         let name = "LIN-456 Code review";
-        let ticket = extract_ticket_from_name(name);
-        assert_eq!(ticket, Some("LIN-456".to_string()));
+        let tickets = extract_tickets(name, &Config::default());
+        assert_eq!(tickets[0].ticket, "LIN-456");
     }
 
     #[test]
     fn test_extract_ticket_bracketed() {
         let name = "[ABC-789] Task name";
-        let ticket = extract_ticket_from_name(name);
-        assert_eq!(ticket, Some("ABC-789".to_string()));
+        let tickets = extract_tickets(name, &Config::default());
+        assert_eq!(tickets[0].ticket, "ABC-789");
     }
 
     #[test]
     fn test_extract_ticket_in_middle() {
         let name = "Work on PROJ-456 - code cleanup";
-        let ticket = extract_ticket_from_name(name);
-        assert_eq!(ticket, Some("PROJ-456".to_string()));
+        let tickets = extract_tickets(name, &Config::default());
+        assert_eq!(tickets[0].ticket, "PROJ-456");
     }
 
     #[test]
     fn test_extract_ticket_no_ticket() {
         let name = "Just a regular task";
-        let ticket = extract_ticket_from_name(name);
-        assert_eq!(ticket, None);
+        assert!(extract_tickets(name, &Config::default()).is_empty());
     }
 
     #[test]
     fn test_extract_ticket_invalid_format() {
         let name = "task-123 invalid";
-        let ticket = extract_ticket_from_name(name);
-        assert_eq!(ticket, None); // lowercase doesn't match
+        assert!(extract_tickets(name, &Config::default()).is_empty()); // lowercase doesn't match
     }
 
     #[test]
@@ -188,7 +302,7 @@ browse_url = "{base_url}/issues/{ticket}"
 
     #[test]
     fn test_detect_tracker_overlapping_patterns_first_wins() {
-        // Test that when multiple trackers match, the first one in iteration order wins
+        // Test that when multiple trackers match, the first one in config order wins
         let toml_str = r#"
 [integrations]
 default_tracker = "fallback"
@@ -207,18 +321,14 @@ browse_url = "{base_url}/issue/{ticket}"
         "#;
         let config: Config = toml::from_str(toml_str).unwrap();
 
-        // PROJ-123 matches both patterns - should use whichever tracker appears first
-        let tracker = detect_tracker("PROJ-123", &config);
-        assert!(tracker.is_some());
-
-        // The result should be deterministic (either "jira" or "linear")
-        // Note: HashMap iteration order is not guaranteed in Rust, but it should be consistent
-        let tracker_name = tracker.unwrap();
-        assert!(tracker_name == "jira" || tracker_name == "linear");
+        // PROJ-123 matches both patterns - the tracker listed first in the config wins
+        assert_eq!(
+            detect_tracker("PROJ-123", &config),
+            Some("jira".to_string())
+        );
 
-        // Verify the same ticket always resolves to the same tracker
-        let tracker2 = detect_tracker("PROJ-123", &config);
-        assert_eq!(tracker2, Some(tracker_name));
+        let tickets = extract_tickets("PROJ-123: fix", &config);
+        assert_eq!(tickets[0].tracker.as_deref(), Some("jira"));
     }
 
     #[test]
@@ -254,7 +364,7 @@ browse_url = "{base_url}/browse/{ticket}"
 worklog_url = "{base_url}/browse/{ticket}?focusedWorklogId=-1"
         "#;
         let config: Config = toml::from_str(toml_str).unwrap();
-        let url = build_url("WL-1", "my-jira", &config, false);
+        let url = build_ticket_url(&extract_tickets("WL-1", &config)[0], &config, false);
         assert!(url.is_ok());
         assert_eq!(url.unwrap(), "https://test.atlassian.net/browse/WL-1");
     }
@@ -273,7 +383,7 @@ browse_url = "{base_url}/browse/{ticket}"
 worklog_url = "{base_url}/browse/{ticket}?focusedWorklogId=-1"
         "#;
         let config: Config = toml::from_str(toml_str).unwrap();
-        let url = build_url("WL-1", "my-jira", &config, true);
+        let url = build_ticket_url(&extract_tickets("WL-1", &config)[0], &config, true);
         assert!(url.is_ok());
         assert_eq!(
             url.unwrap(),
@@ -295,25 +405,121 @@ worklog_url = ""
         "#;
 
         let config: Config = toml::from_str(toml_str).unwrap();
-        let url = build_url("#456", "github", &config, false);
+        let url = build_ticket_url(&extract_tickets("#456", &config)[0], &config, false);
         assert!(url.is_ok());
         assert_eq!(url.unwrap(), "https://github.com/user/repo/issues/#456");
     }
 
     #[test]
     fn test_matches_patterns() {
-        let patterns = vec!["^[A-Z]+-\\d+$".to_string()];
-        assert!(matches_patterns("PROJ-123", &patterns));
-        assert!(matches_patterns("WL-1", &patterns));
-        assert!(!matches_patterns("invalid", &patterns));
+        let toml_str = r#"
+[integrations.trackers.my-jira]
+enabled = true
+ticket_patterns = ["^[A-Z]+-\\d+$"]
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let patterns = config.integrations.matchers().tracker("my-jira").unwrap();
+        assert!(patterns.matches("PROJ-123"));
+        assert!(patterns.matches("WL-1"));
+        assert!(!patterns.matches("invalid"));
     }
 
     #[test]
     fn test_extract_first_ticket_only() {
-        // If there are multiple tickets, extract the first one
+        // If there are multiple tickets, the first one comes first
         let name = "PROJ-123 and WL-456 task";
-        let ticket = extract_ticket_from_name(name);
-        assert_eq!(ticket, Some("PROJ-123".to_string()));
+        let tickets = extract_tickets(name, &Config::default());
+        assert_eq!(tickets[0].ticket, "PROJ-123");
+    }
+
+    fn github_config() -> Config {
+        let toml_str = r#"
+[integrations]
+default_tracker = "jira"
+
+[integrations.trackers.jira]
+enabled = true
+base_url = "https://jira.example.com"
+ticket_patterns = ["^PROJ-\\d+$"]
+browse_url = "{base_url}/browse/{ticket}"
+
+[integrations.trackers.github]
+enabled = true
+base_url = "https://github.com/acme/app"
+ticket_patterns = ["^(?:(?P<repo>[\\w.-]+/[\\w.-]+))?#(?P<ticket>\\d+)$"]
+browse_url = "https://github.com/{repo}/issues/{ticket}"
+
+[integrations.trackers.lower]
+enabled = true
+base_url = "https://tracker.example.com"
+ticket_patterns = ["^ops-\\d+$"]
+browse_url = "{base_url}/{ticket}"
+        "#;
+        toml::from_str(toml_str).unwrap()
+    }
+
+    #[test]
+    fn test_extract_tickets_with_tracker_patterns() {
+        let config = github_config();
+
+        let tickets = extract_tickets("#123 Fix crash", &config);
+        assert_eq!(tickets.len(), 1);
+        assert_eq!(tickets[0].ticket, "123");
+        assert_eq!(tickets[0].text, "#123");
+        assert_eq!(tickets[0].tracker.as_deref(), Some("github"));
+
+        let tickets = extract_tickets("Review acme/lib#45", &config);
+        assert_eq!(tickets[0].ticket, "45");
+        assert_eq!(tickets[0].groups["repo"], "acme/lib");
+        assert_eq!(tickets[0].key(), "acme/lib#45");
+
+        let tickets = extract_tickets("ops-7 rotate keys", &config);
+        assert_eq!(tickets[0].ticket, "ops-7");
+        assert_eq!(tickets[0].tracker.as_deref(), Some("lower"));
+
+        // Patterns must not match inside other words
+        assert!(extract_tickets("stops-7 and issue#9x", &config).is_empty());
+    }
+
+    #[test]
+    fn test_extract_multiple_tickets() {
+        let config = github_config();
+
+        let tickets = extract_tickets("PROJ-1 / #12: wire up OTHER-3", &config);
+        let found: Vec<(&str, Option<&str>)> = tickets
+            .iter()
+            .map(|t| (t.text.as_str(), t.tracker.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("PROJ-1", Some("jira")),
+                ("#12", Some("github")),
+                // Not claimed by any tracker: generic pattern and default tracker
+                ("OTHER-3", Some("jira")),
+            ]
+        );
+
+        // Repeated tickets are listed once
+        assert_eq!(extract_tickets("PROJ-1 then PROJ-1", &config).len(), 1);
+    }
+
+    #[test]
+    fn test_build_url_substitutes_named_groups() {
+        let config = github_config();
+
+        let ticket = &extract_tickets("acme/lib#45 review", &config)[0];
+        assert_eq!(
+            build_ticket_url(ticket, &config, false).unwrap(),
+            "https://github.com/acme/lib/issues/45"
+        );
+
+        // Without the repo the URL can't be built: fall back to the tracker's base URL
+        let ticket = &extract_tickets("#7", &config)[0];
+        assert_eq!(
+            build_ticket_url(ticket, &config, false).unwrap(),
+            "https://github.com/acme/app"
+        );
     }
 
     #[test]
@@ -334,7 +540,7 @@ worklog_url = "{base_url}?m=bug&f=view&bugID={ticket}"
         let config: Config = toml::from_str(toml_str).unwrap();
 
         // Test browse URL with query params (no ticket placeholder in browse_url)
-        let url = build_url("BUG-123", "zentao", &config, false);
+        let url = build_ticket_url(&extract_tickets("BUG-123", &config)[0], &config, false);
         assert!(url.is_ok());
         let url_str = url.unwrap();
         assert_eq!(
@@ -365,7 +571,7 @@ worklog_url = "{base_url}?m=bug&f=view&bugID={ticket}"
         let config: Config = toml::from_str(toml_str).unwrap();
 
         // Test worklog URL with ticket in query string
-        let url = build_url("BUG-456", "zentao", &config, true);
+        let url = build_ticket_url(&extract_tickets("BUG-456", &config)[0], &config, true);
         assert!(url.is_ok());
         let url_str = url.unwrap();
         assert_eq!(
@@ -394,7 +600,7 @@ worklog_url = ""
         "#;
         let config: Config = toml::from_str(toml_str).unwrap();
 
-        let url = build_url("ISSUE-789", "tracker", &config, false);
+        let url = build_ticket_url(&extract_tickets("ISSUE-789", &config)[0], &config, false);
         assert!(url.is_ok());
         let url_str = url.unwrap();
         assert_eq!(
@@ -0,0 +1,176 @@
+//! Precompiled ticket patterns
+//!
+//! Compiling every `ticket_patterns` entry on each lookup is too slow for render paths,
+//! so the patterns are compiled once per config (validated by `Config::load`) and kept
+//! in config order, which makes overlapping patterns resolve deterministically.
+
+use crate::config::IntegrationConfig;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Fallback for `PROJ-123` style keys that no tracker pattern claims
+pub static GENERIC_TICKET_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b[A-Z]{2,10}-\d+\b").expect("generic ticket pattern is valid"));
+
+/// One `ticket_patterns` entry, compiled two ways
+#[derive(Debug, Clone)]
+pub struct CompiledPattern {
+    /// The pattern as written, for checking a bare ticket
+    pub exact: Regex,
+    /// The pattern without its `^`/`$` anchors, for searching inside task names
+    pub search: Regex,
+}
+
+/// Compiled patterns of one enabled tracker
+#[derive(Debug, Clone)]
+pub struct TrackerPatterns {
+    pub tracker: String,
+    pub patterns: Vec<CompiledPattern>,
+}
+
+impl TrackerPatterns {
+    /// Whether a bare ticket matches any of the tracker's patterns
+    pub fn matches(&self, ticket: &str) -> bool {
+        self.patterns.iter().any(|p| p.exact.is_match(ticket))
+    }
+
+    /// Names of all capture groups across the tracker's patterns
+    pub fn group_names(&self) -> impl Iterator<Item = &str> {
+        self.patterns
+            .iter()
+            .flat_map(|p| p.search.capture_names().flatten())
+    }
+}
+
+/// Compiled patterns of all enabled trackers, in config order
+#[derive(Debug, Clone, Default)]
+pub struct TicketMatchers {
+    pub trackers: Vec<TrackerPatterns>,
+}
+
+impl TicketMatchers {
+    /// Compile all enabled trackers' patterns, skipping invalid ones
+    ///
+    /// Returns an error message naming the pattern and its tracker for each one skipped.
+    pub fn compile(integrations: &IntegrationConfig) -> (Self, Vec<String>) {
+        let mut errors = Vec::new();
+        let matchers = Self::compile_collecting(integrations, &mut errors);
+        (matchers, errors)
+    }
+
+    /// Compile all enabled trackers' patterns, silently skipping invalid ones
+    pub fn compile_lenient(integrations: &IntegrationConfig) -> Self {
+        Self::compile_collecting(integrations, &mut Vec::new())
+    }
+
+    fn compile_collecting(integrations: &IntegrationConfig, errors: &mut Vec<String>) -> Self {
+        let trackers = integrations
+            .trackers
+            .iter()
+            .filter(|(_, tracker)| tracker.enabled)
+            .map(|(name, tracker)| TrackerPatterns {
+                tracker: name.clone(),
+                patterns: tracker
+                    .ticket_patterns
+                    .iter()
+                    .filter_map(|pattern| match compile_pattern(pattern) {
+                        Ok(compiled) => Some(compiled),
+                        Err(e) => {
+                            errors.push(format!(
+                                "Invalid ticket pattern '{}' for tracker '{}': {}",
+                                pattern, name, e
+                            ));
+                            None
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        TicketMatchers { trackers }
+    }
+
+    pub fn tracker(&self, name: &str) -> Option<&TrackerPatterns> {
+        self.trackers.iter().find(|t| t.tracker == name)
+    }
+}
+
+fn compile_pattern(pattern: &str) -> Result<CompiledPattern, regex::Error> {
+    let unanchored = pattern.strip_prefix('^').unwrap_or(pattern);
+    let unanchored = match unanchored.strip_suffix('$') {
+        Some(stripped) if !stripped.ends_with('\\') => stripped,
+        _ => unanchored,
+    };
+
+    Ok(CompiledPattern {
+        exact: Regex::new(pattern)?,
+        search: Regex::new(unanchored)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    fn create_config(patterns: &str) -> Config {
+        let toml_str = format!(
+            r#"
+[integrations.trackers.zeta]
+enabled = true
+ticket_patterns = {}
+
+[integrations.trackers.alpha]
+enabled = true
+ticket_patterns = ["^[A-Z]+-\\d+$"]
+
+[integrations.trackers.off]
+enabled = false
+ticket_patterns = ["^OFF-\\d+$"]
+            "#,
+            patterns
+        );
+        toml::from_str(&toml_str).unwrap()
+    }
+
+    #[test]
+    fn test_compile_keeps_config_order() {
+        let config = create_config(r#"["^PROJ-\\d+$"]"#);
+        let matchers = TicketMatchers::compile(&config.integrations).0;
+
+        let names: Vec<&str> = matchers
+            .trackers
+            .iter()
+            .map(|t| t.tracker.as_str())
+            .collect();
+        assert_eq!(names, vec!["zeta", "alpha"]);
+    }
+
+    #[test]
+    fn test_invalid_pattern_names_tracker() {
+        let config = create_config(r#"["^PROJ-(\\d+$"]"#);
+
+        let (matchers, errors) = TicketMatchers::compile(&config.integrations);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("tracker 'zeta'"));
+        assert!(errors[0].contains("^PROJ-(\\d+$"));
+
+        // Only the broken pattern is skipped
+        assert!(matchers.tracker("zeta").unwrap().patterns.is_empty());
+        assert!(matchers.tracker("alpha").unwrap().matches("ENG-1"));
+
+        let matchers = TicketMatchers::compile_lenient(&config.integrations);
+        assert!(matchers.tracker("alpha").unwrap().matches("ENG-1"));
+    }
+
+    #[test]
+    fn test_exact_and_search_forms() {
+        let config = create_config(r#"["^#\\d+$"]"#);
+        let matchers = TicketMatchers::compile(&config.integrations).0;
+        let zeta = matchers.tracker("zeta").unwrap();
+
+        assert!(zeta.matches("#12"));
+        assert!(!zeta.matches("see #12"));
+        assert!(zeta.patterns[0].search.is_match("see #12"));
+    }
+}
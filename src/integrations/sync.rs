@@ -0,0 +1,769 @@
+//! Time sync to issue trackers
+//!
+//! Each backend implements [`SyncAdapter`]; this module decides what to send and
+//! handles what all backends share: dry runs, retrying transient failures, and
+//! remembering remote IDs so re-syncing updates entries instead of duplicating them.
+
+use super::extract_tickets;
+use super::{github::GitHubAdapter, jira::JiraAdapter, linear::LinearAdapter};
+use crate::config::{Config, TrackerConfig};
+use crate::models::{DayData, LocalZone, WorkRecord};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// How an adapter maps records to remote entries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    /// One remote entry per record (e.g. Jira worklogs)
+    PerRecord,
+    /// One remote entry per ticket per day (e.g. a summary comment)
+    PerTicketDay,
+}
+
+/// Data sent to the tracker for one remote entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncEntry {
+    pub ticket: String,
+    pub date: Date,
+    /// Start of the (first) record, in local time
+    pub started: OffsetDateTime,
+    pub seconds: u64,
+    /// Record description (per record) or a time summary (per ticket per day)
+    pub body: String,
+}
+
+/// An open issue in a tracker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Issue {
+    pub ticket: String,
+    pub title: String,
+}
+
+impl Issue {
+    /// Task name for records of this issue: `"PROJ-123 - Title"`
+    pub fn task_name(&self) -> String {
+        format!("{} - {}", self.ticket, self.title)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncErrorKind {
+    /// Network failure, rate limit or server error: worth retrying
+    Transient,
+    /// The remote entry doesn't exist (anymore)
+    NotFound,
+    /// Anything else (bad credentials, validation errors)
+    Fatal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncError {
+    pub kind: SyncErrorKind,
+    pub message: String,
+}
+
+impl SyncError {
+    pub fn fatal(message: impl Into<String>) -> Self {
+        SyncError {
+            kind: SyncErrorKind::Fatal,
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        SyncError {
+            kind: SyncErrorKind::NotFound,
+            message: message.into(),
+        }
+    }
+
+    /// Classify an HTTP error from `service` by status code
+    pub fn from_ureq(error: ureq::Error, service: &str) -> Self {
+        match error {
+            ureq::Error::Status(code, response) => {
+                let kind = match code {
+                    404 => SyncErrorKind::NotFound,
+                    429 | 500..=599 => SyncErrorKind::Transient,
+                    _ => SyncErrorKind::Fatal,
+                };
+                let body = response.into_string().unwrap_or_default();
+                SyncError {
+                    kind,
+                    message: format!("{} returned {}: {}", service, code, body.trim()),
+                }
+            }
+            ureq::Error::Transport(transport) => SyncError {
+                kind: SyncErrorKind::Transient,
+                message: format!("Failed to reach {}: {}", service, transport),
+            },
+        }
+    }
+}
+
+impl fmt::Display for SyncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for SyncError {}
+
+/// A tracker backend that time can be pushed to
+pub trait SyncAdapter {
+    fn granularity(&self) -> Granularity;
+
+    /// Create a remote entry and return its ID
+    fn create(&self, entry: &SyncEntry) -> Result<String, SyncError>;
+
+    /// Update a previously created remote entry
+    fn update(&self, remote_id: &str, entry: &SyncEntry) -> Result<(), SyncError>;
+
+    /// Delete a previously created remote entry from `ticket`
+    ///
+    /// Only called for per-record entries whose record moved to another ticket.
+    fn delete(&self, remote_id: &str, ticket: &str) -> Result<(), SyncError> {
+        let _ = (remote_id, ticket);
+        Err(SyncError::fatal("deleting entries is not supported"))
+    }
+
+    /// Look up the ticket's title (issue summary)
+    fn fetch_title(&self, ticket: &str) -> Result<String, SyncError>;
+
+    /// List open issues assigned to the authenticated user
+    fn assigned_issues(&self) -> Result<Vec<Issue>, SyncError>;
+}
+
+/// Build the adapter selected by a tracker's `sync` setting
+pub fn adapter_for(tracker: &TrackerConfig) -> Result<Box<dyn SyncAdapter>, SyncError> {
+    match tracker.sync.as_deref() {
+        Some("jira") => Ok(Box::new(JiraAdapter::from_tracker(tracker)?)),
+        Some("linear") => Ok(Box::new(LinearAdapter::from_tracker(tracker)?)),
+        Some("github") => Ok(Box::new(GitHubAdapter::from_tracker(tracker)?)),
+        Some(other) => Err(SyncError::fatal(format!(
+            "Unknown sync backend '{}' (expected jira, linear or github)",
+            other
+        ))),
+        None => Err(SyncError::fatal("sync is not configured")),
+    }
+}
+
+/// Shared HTTP agent settings for adapters
+pub(crate) fn http_agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(15))
+        .user_agent(concat!("work-tuimer/", env!("CARGO_PKG_VERSION")))
+        .build()
+}
+
+/// Get the tracker's `api_token` or fail with a config error
+pub(crate) fn require_token(tracker: &TrackerConfig) -> Result<&str, SyncError> {
+    tracker
+        .api_token
+        .as_deref()
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| SyncError::fatal("api_token is not set"))
+}
+
+/// How often and how patiently to retry transient failures
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub attempts: u32,
+    /// Delay before the first retry, doubled for each further retry
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            attempts: 3,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    fn run<T>(&self, mut operation: impl FnMut() -> Result<T, SyncError>) -> Result<T, SyncError> {
+        let mut attempt = 1;
+        loop {
+            match operation() {
+                Err(e) if e.kind == SyncErrorKind::Transient && attempt < self.attempts => {
+                    std::thread::sleep(self.base_delay * 2u32.pow(attempt - 1));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct SyncOptions {
+    /// Local time zone the records were tracked in
+    pub zone: LocalZone,
+    /// Report what would be sent without contacting any tracker
+    pub dry_run: bool,
+    pub retry: RetryPolicy,
+}
+
+/// What happened to a remote entry during sync
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SyncAction {
+    Created,
+    Updated,
+    /// Entry removed from the ticket its record no longer belongs to
+    Deleted,
+    Skipped,
+    Failed,
+}
+
+/// Result for one remote entry (or one skipped record)
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncOutcome {
+    pub record_ids: Vec<u32>,
+    pub name: String,
+    pub ticket: Option<String>,
+    pub tracker: Option<String>,
+    pub action: SyncAction,
+    /// Remote ID after sync, reason for skipping, or error message
+    pub detail: String,
+}
+
+/// One remote entry to create or update
+struct Unit<'a> {
+    tracker: String,
+    ticket: String,
+    records: Vec<&'a WorkRecord>,
+}
+
+/// A per-record worklog left on a ticket the record no longer names
+struct StaleWorklog {
+    record_id: u32,
+    name: String,
+    tracker: String,
+    ticket: String,
+    worklog_id: String,
+}
+
+impl StaleWorklog {
+    /// The record's worklog, if it was pushed to a ticket other than `current`
+    /// (`tracker:TICKET`, or `None` if the record no longer syncs)
+    fn of(record: &WorkRecord, current: Option<&str>) -> Option<Self> {
+        let worklog_id = record.worklog_id.clone()?;
+        let pushed_to = record.worklog_ticket.as_deref()?;
+        if Some(pushed_to) == current {
+            return None;
+        }
+        let (tracker, ticket) = pushed_to.split_once(':')?;
+        Some(StaleWorklog {
+            record_id: record.id,
+            name: record.name.clone(),
+            tracker: tracker.to_string(),
+            ticket: ticket.to_string(),
+            worklog_id,
+        })
+    }
+}
+
+/// Push a day's ticketed records to their trackers
+///
+/// Records are matched to enabled trackers with a `sync` backend; others are reported
+/// as skipped. Remote IDs are stored on the records (per-record backends) or in
+/// `day_data.synced_comments` (per-ticket backends), so the caller must save `day_data`.
+/// A per-record worklog whose record was renamed to another ticket (or to no ticket) is
+/// deleted before the record is pushed again, so its time isn't counted twice.
+/// With `dry_run` nothing is sent and `day_data` is left untouched.
+pub fn sync_day(
+    day_data: &mut DayData,
+    config: &Config,
+    options: &SyncOptions,
+) -> Vec<SyncOutcome> {
+    let mut outcomes = Vec::new();
+    let mut adapters: HashMap<String, Result<Box<dyn SyncAdapter>, SyncError>> = HashMap::new();
+    let mut units: Vec<Unit> = Vec::new();
+    let mut stale: Vec<StaleWorklog> = Vec::new();
+
+    for record in day_data.get_sorted_records() {
+        let mut skip = |reason: &str, ticket: Option<String>| {
+            stale.extend(StaleWorklog::of(record, None));
+            SyncOutcome {
+                record_ids: vec![record.id],
+                name: record.name.clone(),
+                ticket,
+                tracker: None,
+                action: SyncAction::Skipped,
+                detail: reason.to_string(),
+            }
+        };
+
+        // Time goes to the first ticket of a tracker with sync; a record isn't split
+        // between several tickets
+        let tickets = extract_tickets(&record.name, config);
+        let Some(first) = tickets.first() else {
+            outcomes.push(skip("no ticket in name", None));
+            continue;
+        };
+        let synced = tickets.iter().find_map(|t| {
+            let tracker = t.tracker.as_ref()?;
+            config
+                .integrations
+                .trackers
+                .get(tracker)
+                .is_some_and(|c| c.enabled && c.sync.is_some())
+                .then(|| (tracker.clone(), t.key()))
+        });
+        let Some((tracker, ticket)) = synced else {
+            outcomes.push(skip("tracker has no sync configured", Some(first.key())));
+            continue;
+        };
+        // Trackers reject zero-length time entries
+        if record.total_minutes == 0 {
+            outcomes.push(skip("zero duration", Some(ticket)));
+            continue;
+        }
+
+        let adapter = adapters
+            .entry(tracker.clone())
+            .or_insert_with(|| adapter_for(&config.integrations.trackers[&tracker]));
+        let granularity = match adapter {
+            Ok(adapter) => adapter.granularity(),
+            // Reported per unit below
+            Err(_) => Granularity::PerRecord,
+        };
+
+        if granularity == Granularity::PerRecord {
+            stale.extend(StaleWorklog::of(
+                record,
+                Some(&format!("{}:{}", tracker, ticket)),
+            ));
+        }
+
+        let existing = units.iter_mut().find(|u| {
+            granularity == Granularity::PerTicketDay && u.tracker == tracker && u.ticket == ticket
+        });
+        match existing {
+            Some(unit) => unit.records.push(record),
+            None => units.push(Unit {
+                tracker,
+                ticket,
+                records: vec![record],
+            }),
+        }
+    }
+
+    // Remote IDs to store once the borrow on day_data's records ends
+    let mut record_ids: Vec<(u32, String, String)> = Vec::new();
+    let mut comment_ids: Vec<(String, String)> = Vec::new();
+    let mut deleted: Vec<u32> = Vec::new();
+    // Records whose old worklog couldn't be deleted aren't pushed again
+    let mut undeleted: Vec<u32> = Vec::new();
+
+    for worklog in &stale {
+        let mut outcome = SyncOutcome {
+            record_ids: vec![worklog.record_id],
+            name: worklog.name.clone(),
+            ticket: Some(worklog.ticket.clone()),
+            tracker: Some(worklog.tracker.clone()),
+            action: SyncAction::Failed,
+            detail: String::new(),
+        };
+        if options.dry_run {
+            outcome.action = SyncAction::Deleted;
+            outcome.detail = "dry run".to_string();
+            outcomes.push(outcome);
+            continue;
+        }
+
+        let adapter = match config.integrations.trackers.get(&worklog.tracker) {
+            Some(tracker_config) => adapters
+                .entry(worklog.tracker.clone())
+                .or_insert_with(|| adapter_for(tracker_config)),
+            None => {
+                outcome.detail = format!(
+                    "{} is no longer configured; delete worklog {} manually",
+                    worklog.tracker, worklog.worklog_id
+                );
+                undeleted.push(worklog.record_id);
+                outcomes.push(outcome);
+                continue;
+            }
+        };
+        let result = match adapter {
+            Ok(adapter) => options
+                .retry
+                .run(|| adapter.delete(&worklog.worklog_id, &worklog.ticket)),
+            Err(e) => Err(e.clone()),
+        };
+        match result {
+            Ok(()) => {
+                outcome.action = SyncAction::Deleted;
+                outcome.detail = worklog.worklog_id.clone();
+                deleted.push(worklog.record_id);
+            }
+            // Already gone on the tracker's side
+            Err(e) if e.kind == SyncErrorKind::NotFound => {
+                outcome.action = SyncAction::Deleted;
+                outcome.detail = worklog.worklog_id.clone();
+                deleted.push(worklog.record_id);
+            }
+            Err(e) => {
+                outcome.detail = format!("worklog {} was not deleted: {}", worklog.worklog_id, e);
+                undeleted.push(worklog.record_id);
+            }
+        }
+        outcomes.push(outcome);
+    }
+
+    for unit in units {
+        let mut outcome = SyncOutcome {
+            record_ids: unit.records.iter().map(|r| r.id).collect(),
+            name: unit.records[0].name.clone(),
+            ticket: Some(unit.ticket.clone()),
+            tracker: Some(unit.tracker.clone()),
+            action: SyncAction::Failed,
+            detail: String::new(),
+        };
+
+        let adapter = match &adapters[&unit.tracker] {
+            Ok(adapter) => adapter,
+            Err(e) => {
+                outcome.detail = format!("{}: {}", unit.tracker, e);
+                outcomes.push(outcome);
+                continue;
+            }
+        };
+
+        let granularity = adapter.granularity();
+        let ticket_key = format!("{}:{}", unit.tracker, unit.ticket);
+        let first_id = unit.records[0].id;
+        if undeleted.contains(&first_id) {
+            outcome.detail = "the worklog on the previous ticket is still there".to_string();
+            outcomes.push(outcome);
+            continue;
+        }
+        let remote_id = match granularity {
+            Granularity::PerRecord if stale.iter().any(|w| w.record_id == first_id) => None,
+            Granularity::PerRecord => unit.records[0].worklog_id.clone(),
+            Granularity::PerTicketDay => day_data.synced_comments.get(&ticket_key).cloned(),
+        };
+        let entry = build_entry(&unit, granularity, day_data.date, options.zone);
+
+        if options.dry_run {
+            outcome.action = if remote_id.is_some() {
+                SyncAction::Updated
+            } else {
+                SyncAction::Created
+            };
+            outcome.detail = "dry run".to_string();
+            outcomes.push(outcome);
+            continue;
+        }
+
+        match push(
+            adapter.as_ref(),
+            remote_id.as_deref(),
+            &entry,
+            &options.retry,
+        ) {
+            Ok((action, id)) => {
+                match granularity {
+                    Granularity::PerRecord => record_ids.push((first_id, id.clone(), ticket_key)),
+                    Granularity::PerTicketDay => comment_ids.push((ticket_key, id.clone())),
+                }
+                outcome.action = action;
+                outcome.detail = id;
+            }
+            Err(e) => outcome.detail = e.to_string(),
+        }
+        outcomes.push(outcome);
+    }
+
+    for id in deleted {
+        if let Some(record) = day_data.work_records.get_mut(&id) {
+            record.worklog_id = None;
+            record.worklog_ticket = None;
+        }
+    }
+    for (id, remote_id, ticket_key) in record_ids {
+        if let Some(record) = day_data.work_records.get_mut(&id) {
+            record.worklog_id = Some(remote_id);
+            record.worklog_ticket = Some(ticket_key);
+        }
+    }
+    day_data.synced_comments.extend(comment_ids);
+
+    outcomes
+}
+
+/// Update the known remote entry, or create one if it doesn't exist (yet or anymore)
+fn push(
+    adapter: &dyn SyncAdapter,
+    remote_id: Option<&str>,
+    entry: &SyncEntry,
+    retry: &RetryPolicy,
+) -> Result<(SyncAction, String), SyncError> {
+    if let Some(id) = remote_id {
+        match retry.run(|| adapter.update(id, entry)) {
+            Ok(()) => return Ok((SyncAction::Updated, id.to_string())),
+            Err(e) if e.kind == SyncErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    let id = retry.run(|| adapter.create(entry))?;
+    Ok((SyncAction::Created, id))
+}
+
+fn build_entry(unit: &Unit, granularity: Granularity, date: Date, zone: LocalZone) -> SyncEntry {
+    let first = unit.records[0];
+    let start = Time::from_hms(first.start.hour, first.start.minute, 0)
+        .expect("TimePoint is always a valid time");
+    let minutes: u32 = unit.records.iter().map(|r| r.total_minutes).sum();
+
+    let body = match granularity {
+        Granularity::PerRecord => first.description.clone(),
+        Granularity::PerTicketDay => summary_comment(&unit.records, date, minutes),
+    };
+
+    SyncEntry {
+        ticket: unit.ticket.clone(),
+        date,
+        started: zone.assume(PrimitiveDateTime::new(date, start)),
+        seconds: minutes as u64 * 60,
+        body,
+    }
+}
+
+/// Markdown summary of a ticket's records for one day
+fn summary_comment(records: &[&WorkRecord], date: Date, minutes: u32) -> String {
+    let mut body = format!(
+        "⏱ **Time logged on {}: {}h {:02}m**\n\n",
+        date,
+        minutes / 60,
+        minutes % 60
+    );
+    for record in records {
+        body.push_str(&format!(
+            "- {}-{} ({}) {}",
+            record.start,
+            record.end,
+            record.format_duration(),
+            record.name
+        ));
+        if !record.description.is_empty() {
+            body.push_str(&format!(" — {}", record.description));
+        }
+        body.push('\n');
+    }
+    body.push_str("\n_Posted by work-tuimer_");
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TimePoint;
+    use std::cell::RefCell;
+    use time::UtcOffset;
+    use time::macros::{date, offset};
+
+    /// In-memory adapter recording calls and replaying scripted errors
+    struct FakeAdapter {
+        granularity: Granularity,
+        calls: RefCell<Vec<String>>,
+        errors: RefCell<Vec<SyncError>>,
+    }
+
+    impl FakeAdapter {
+        fn new(granularity: Granularity) -> Self {
+            FakeAdapter {
+                granularity,
+                calls: RefCell::new(Vec::new()),
+                errors: RefCell::new(Vec::new()),
+            }
+        }
+
+        fn next_error(&self) -> Option<SyncError> {
+            let mut errors = self.errors.borrow_mut();
+            (!errors.is_empty()).then(|| errors.remove(0))
+        }
+    }
+
+    impl SyncAdapter for FakeAdapter {
+        fn granularity(&self) -> Granularity {
+            self.granularity
+        }
+
+        fn create(&self, entry: &SyncEntry) -> Result<String, SyncError> {
+            self.calls
+                .borrow_mut()
+                .push(format!("create {}", entry.ticket));
+            match self.next_error() {
+                Some(e) => Err(e),
+                None => Ok("new-id".to_string()),
+            }
+        }
+
+        fn update(&self, remote_id: &str, entry: &SyncEntry) -> Result<(), SyncError> {
+            self.calls
+                .borrow_mut()
+                .push(format!("update {} {}", entry.ticket, remote_id));
+            match self.next_error() {
+                Some(e) => Err(e),
+                None => Ok(()),
+            }
+        }
+
+        fn fetch_title(&self, ticket: &str) -> Result<String, SyncError> {
+            Ok(format!("Title of {}", ticket))
+        }
+
+        fn assigned_issues(&self) -> Result<Vec<Issue>, SyncError> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn transient() -> SyncError {
+        SyncError {
+            kind: SyncErrorKind::Transient,
+            message: "503".to_string(),
+        }
+    }
+
+    fn no_delay(attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            attempts,
+            base_delay: Duration::ZERO,
+        }
+    }
+
+    fn create_entry() -> SyncEntry {
+        SyncEntry {
+            ticket: "ENG-1".to_string(),
+            date: date!(2026 - 10 - 17),
+            started: OffsetDateTime::UNIX_EPOCH,
+            seconds: 60,
+            body: String::new(),
+        }
+    }
+
+    fn create_record(id: u32, name: &str, start: u8, end: u8) -> WorkRecord {
+        WorkRecord::new(
+            id,
+            name.to_string(),
+            TimePoint::new(start, 0).unwrap(),
+            TimePoint::new(end, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_retry_recovers_from_transient_errors() {
+        let adapter = FakeAdapter::new(Granularity::PerRecord);
+        adapter
+            .errors
+            .borrow_mut()
+            .extend([transient(), transient()]);
+
+        let result = push(&adapter, None, &create_entry(), &no_delay(3));
+        assert_eq!(result.unwrap(), (SyncAction::Created, "new-id".to_string()));
+        assert_eq!(adapter.calls.borrow().len(), 3);
+    }
+
+    #[test]
+    fn test_retry_gives_up_after_attempts() {
+        let adapter = FakeAdapter::new(Granularity::PerRecord);
+        adapter
+            .errors
+            .borrow_mut()
+            .extend([transient(), transient(), transient()]);
+
+        let result = push(&adapter, None, &create_entry(), &no_delay(2));
+        assert_eq!(result.unwrap_err().kind, SyncErrorKind::Transient);
+        assert_eq!(adapter.calls.borrow().len(), 2);
+    }
+
+    #[test]
+    fn test_fatal_errors_are_not_retried() {
+        let adapter = FakeAdapter::new(Granularity::PerRecord);
+        adapter.errors.borrow_mut().push(SyncError::fatal("401"));
+
+        assert!(push(&adapter, None, &create_entry(), &no_delay(3)).is_err());
+        assert_eq!(adapter.calls.borrow().len(), 1);
+    }
+
+    #[test]
+    fn test_update_falls_back_to_create_when_missing() {
+        let adapter = FakeAdapter::new(Granularity::PerRecord);
+        adapter
+            .errors
+            .borrow_mut()
+            .push(SyncError::not_found("gone"));
+
+        let result = push(&adapter, Some("old-id"), &create_entry(), &no_delay(1));
+        assert_eq!(result.unwrap(), (SyncAction::Created, "new-id".to_string()));
+        assert_eq!(
+            *adapter.calls.borrow(),
+            vec!["update ENG-1 old-id", "create ENG-1"]
+        );
+    }
+
+    #[test]
+    fn test_summary_entry_groups_ticket_records() {
+        let first = create_record(1, "ENG-1 Build", 9, 10);
+        let mut second = create_record(2, "ENG-1 Build", 14, 16);
+        second.description = "Tests".to_string();
+        let unit = Unit {
+            tracker: "linear".to_string(),
+            ticket: "ENG-1".to_string(),
+            records: vec![&first, &second],
+        };
+
+        let entry = build_entry(
+            &unit,
+            Granularity::PerTicketDay,
+            date!(2026 - 10 - 17),
+            LocalZone::Fixed(offset!(+1)),
+        );
+        assert_eq!(entry.seconds, 3 * 3600);
+        assert_eq!(entry.started.hour(), 9);
+        assert_eq!(entry.started.offset(), offset!(+1));
+        assert!(entry.body.contains("Time logged on 2026-10-17: 3h 00m"));
+        assert!(entry.body.contains("- 09:00-10:00 (1h 00m) ENG-1 Build\n"));
+        assert!(
+            entry
+                .body
+                .contains("- 14:00-16:00 (2h 00m) ENG-1 Build — Tests\n")
+        );
+    }
+
+    #[test]
+    fn test_unknown_backend_is_reported() {
+        let tracker = TrackerConfig {
+            sync: Some("redmine".to_string()),
+            ..Default::default()
+        };
+        let error = adapter_for(&tracker).err().unwrap();
+        assert!(error.message.contains("redmine"));
+    }
+
+    #[test]
+    fn test_unconfigured_records_are_skipped() {
+        let mut day = DayData::new(date!(2026 - 10 - 17));
+        day.add_record(create_record(1, "Lunch", 12, 13));
+        day.add_record(create_record(2, "ENG-1 Build", 9, 10));
+
+        let options = SyncOptions {
+            zone: LocalZone::Fixed(UtcOffset::UTC),
+            dry_run: false,
+            retry: no_delay(1),
+        };
+        let outcomes = sync_day(&mut day, &Config::default(), &options);
+
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(|o| o.action == SyncAction::Skipped));
+        assert_eq!(outcomes[0].ticket.as_deref(), Some("ENG-1"));
+    }
+}
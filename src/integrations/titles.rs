@@ -0,0 +1,174 @@
+//! Ticket title lookup
+//!
+//! Titles are fetched through the tracker's sync backend and cached locally (keyed by
+//! "tracker:ticket"), so a ticket only hits the API once and known titles work offline.
+
+use super::sync::{SyncAdapter, SyncError, adapter_for};
+use super::{TicketRef, extract_tickets};
+use crate::config::{Config, TrackerConfig};
+use std::collections::HashMap;
+
+/// Suggest `"PROJ-123 - Title"` for a name that is just a ticket (`"PROJ-123"`)
+///
+/// Returns Ok(None) if the name has more than a ticket or the ticket's tracker has no API
+/// configured. Newly fetched titles are added to `cache`.
+pub fn enrich_name(
+    name: &str,
+    config: &Config,
+    cache: &mut HashMap<String, String>,
+) -> Result<Option<String>, SyncError> {
+    let name = name.trim();
+    match extract_tickets(name, config).as_slice() {
+        [ticket] if ticket.text == name => Ok(lookup_title(ticket, config, cache)?
+            .map(|title| format!("{} - {}", ticket.text, title))),
+        _ => Ok(None),
+    }
+}
+
+/// Like [`enrich_name`], but only from `cache`: never contacts a tracker
+///
+/// Returns None if the name isn't just a ticket or its title isn't cached (yet).
+pub fn cached_name(name: &str, config: &Config, cache: &HashMap<String, String>) -> Option<String> {
+    let name = name.trim();
+    match extract_tickets(name, config).as_slice() {
+        [ticket] if ticket.text == name => {
+            let (key, _) = title_source(ticket, config)?;
+            let title = cache.get(&key)?;
+            Some(format!("{} - {}", ticket.text, title))
+        }
+        _ => None,
+    }
+}
+
+/// Whether the name is just a ticket whose tracker can be asked for its title
+pub fn has_title_source(name: &str, config: &Config) -> bool {
+    let name = name.trim();
+    match extract_tickets(name, config).as_slice() {
+        [ticket] if ticket.text == name => title_source(ticket, config).is_some(),
+        _ => false,
+    }
+}
+
+/// Get a ticket's title from `cache`, or fetch it from its tracker and cache it
+///
+/// Returns Ok(None) if the ticket's tracker has no sync backend to ask.
+pub fn lookup_title(
+    ticket: &TicketRef,
+    config: &Config,
+    cache: &mut HashMap<String, String>,
+) -> Result<Option<String>, SyncError> {
+    let Some((key, tracker)) = title_source(ticket, config) else {
+        return Ok(None);
+    };
+    if let Some(title) = cache.get(&key) {
+        return Ok(Some(title.clone()));
+    }
+
+    let title = fetch_title(adapter_for(tracker)?.as_ref(), &ticket.key())?;
+    cache.insert(key, title.clone());
+    Ok(Some(title))
+}
+
+/// Cache key and tracker of a ticket whose tracker has a sync backend to ask
+fn title_source<'a>(ticket: &TicketRef, config: &'a Config) -> Option<(String, &'a TrackerConfig)> {
+    let name = ticket.tracker.as_ref()?;
+    let tracker = config
+        .integrations
+        .trackers
+        .get(name)
+        .filter(|t| t.enabled && t.sync.is_some())?;
+    Some((format!("{}:{}", name, ticket.key()), tracker))
+}
+
+fn fetch_title(adapter: &dyn SyncAdapter, ticket: &str) -> Result<String, SyncError> {
+    let title = adapter.fetch_title(ticket)?;
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(SyncError::fatal(format!("{} has no title", ticket)));
+    }
+    Ok(title.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integrations::mock_server;
+
+    fn create_config(base_url: &str) -> Config {
+        let toml_str = format!(
+            r#"
+[integrations.trackers.jira]
+enabled = true
+base_url = "{}"
+ticket_patterns = ["^PROJ-\\d+$"]
+sync = "jira"
+api_token = "secret"
+            "#,
+            base_url
+        );
+        toml::from_str(&toml_str).unwrap()
+    }
+
+    #[test]
+    fn test_enrich_fetches_and_caches_title() {
+        // Only one response: a second request would fail
+        let (url, _requests) = mock_server::serve(vec![(
+            200,
+            r#"{"fields":{"summary":"Fix login redirect"}}"#,
+        )]);
+        let config = create_config(&url);
+        let mut cache = HashMap::new();
+
+        let name = enrich_name("PROJ-123 ", &config, &mut cache).unwrap();
+        assert_eq!(name.as_deref(), Some("PROJ-123 - Fix login redirect"));
+        assert_eq!(cache["jira:PROJ-123"], "Fix login redirect");
+
+        let cached = enrich_name("PROJ-123", &config, &mut cache).unwrap();
+        assert_eq!(cached.as_deref(), Some("PROJ-123 - Fix login redirect"));
+    }
+
+    #[test]
+    fn test_cached_title_works_offline() {
+        let config = create_config("http://127.0.0.1:1");
+        let mut cache = HashMap::new();
+        cache.insert("jira:PROJ-7".to_string(), "Cached".to_string());
+
+        let name = enrich_name("PROJ-7", &config, &mut cache).unwrap();
+        assert_eq!(name.as_deref(), Some("PROJ-7 - Cached"));
+        assert!(enrich_name("PROJ-8", &config, &mut cache).is_err());
+    }
+
+    #[test]
+    fn test_cached_name_never_fetches() {
+        let config = create_config("http://127.0.0.1:1");
+        let mut cache = HashMap::new();
+        cache.insert("jira:PROJ-7".to_string(), "Cached".to_string());
+
+        assert_eq!(
+            cached_name(" PROJ-7", &config, &cache).as_deref(),
+            Some("PROJ-7 - Cached")
+        );
+        assert_eq!(cached_name("PROJ-8", &config, &cache), None);
+        assert_eq!(cached_name("PROJ-7 - Cached", &config, &cache), None);
+    }
+
+    #[test]
+    fn test_only_bare_tickets_are_enriched() {
+        let config = create_config("http://127.0.0.1:1");
+        let mut cache = HashMap::new();
+
+        assert_eq!(enrich_name("PROJ-1 - Mine", &config, &mut cache), Ok(None));
+        assert_eq!(enrich_name("Lunch", &config, &mut cache), Ok(None));
+    }
+
+    #[test]
+    fn test_trackers_without_api_are_ignored() {
+        let mut config = create_config("http://127.0.0.1:1");
+        config.integrations.trackers.get_mut("jira").unwrap().sync = None;
+
+        assert_eq!(
+            enrich_name("PROJ-1", &config, &mut HashMap::new()),
+            Ok(None)
+        );
+    }
+}
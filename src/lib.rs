@@ -3,6 +3,7 @@
 
 pub mod cli;
 pub mod config;
+pub mod formats;
 pub mod integrations;
 pub mod models;
 pub mod storage;
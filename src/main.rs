@@ -1,5 +1,6 @@
 mod cli;
 mod config;
+mod formats;
 mod integrations;
 mod models;
 mod storage;
@@ -9,7 +10,7 @@ mod ui;
 use anyhow::{Context, Result};
 use clap::Parser;
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
@@ -17,6 +18,7 @@ use ratatui::{Terminal, backend::CrosstermBackend};
 use std::io;
 use time::OffsetDateTime;
 use ui::AppState;
+use ui::command_line::ExCommand;
 
 fn main() -> Result<()> {
     // Try to parse CLI arguments
@@ -32,10 +34,22 @@ fn main() -> Result<()> {
 }
 
 /// Run in CLI mode
+///
+/// Exits the process with a stable code (see `cli::EXIT_*`) instead of returning,
+/// so "no session running" is distinguishable from real errors.
 fn run_cli() -> Result<()> {
     let cli = cli::Cli::parse();
-    let storage = storage::Storage::new()?;
-    cli::handle_command(cli.command, storage)
+    let format = cli.format;
+    let result = storage::Storage::new()
+        .and_then(|storage| cli::handle_command(cli.command, storage, format));
+
+    match result {
+        Ok(code) => std::process::exit(code),
+        Err(err) => {
+            cli::print_error(&err, format);
+            std::process::exit(cli::exit_code(&err))
+        }
+    }
 }
 
 /// Run in TUI mode
@@ -43,7 +57,8 @@ fn run_tui() -> Result<()> {
     let today = OffsetDateTime::now_local()
         .context("Failed to get local time")?
         .date();
-    let mut storage = storage::StorageManager::new()?;
+    let hooks = config::Config::load().unwrap_or_default().hooks;
+    let mut storage = storage::StorageManager::new()?.with_hooks(hooks);
     let day_data = storage.load_with_tracking(today)?;
 
     enable_raw_mode()?;
@@ -59,6 +74,9 @@ fn run_tui() -> Result<()> {
         app.active_timer = Some(timer);
     }
 
+    // Offer cached assigned issues in the task picker (refreshed on demand)
+    app.assigned_issues = storage.load_assigned_issues().unwrap_or_default();
+
     // Initialize last_file_modified with tracked time
     app.last_file_modified = storage.get_last_modified(&today);
 
@@ -109,6 +127,12 @@ fn run_app<B: ratatui::backend::Backend>(
             // Check if the file has been modified externally (e.g., by CLI)
             app.check_and_reload_if_modified(storage);
         }
+
+        // Session hooks run in the background and report failures when they finish
+        if let Some(failure) = storage.hook_failures().pop() {
+            app.last_error_message = Some(failure);
+        }
+        app.receive_fetched(storage);
     }
 
     Ok(())
@@ -119,95 +143,12 @@ fn handle_key_event(app: &mut AppState, key: KeyEvent, storage: &mut storage::St
     app.clear_error();
 
     match app.mode {
-        ui::AppMode::Browse => match key.code {
-            KeyCode::Char('q') => app.should_quit = true,
-            KeyCode::Char('?') => app.open_command_palette(),
-            KeyCode::Char('C') => app.open_calendar(),
-            KeyCode::Char('T') if app.config.has_integrations() => app.open_ticket_in_browser(),
-            KeyCode::Char('L') if app.config.has_integrations() => app.open_worklog_in_browser(),
-            // Timer keybindings
-            KeyCode::Char('S') => {
-                // Start/Stop toggle - Start if no timer active, Stop if timer is running
-                if let Some(timer) = app.get_timer_status() {
-                    use crate::timer::TimerStatus;
-                    if matches!(timer.status, TimerStatus::Running | TimerStatus::Paused) {
-                        if let Err(e) = app.stop_active_timer(storage) {
-                            app.last_error_message = Some(e);
-                        }
-                    } else if let Err(e) = app.start_timer_for_selected(storage) {
-                        app.last_error_message = Some(e);
-                    }
-                } else if let Err(e) = app.start_timer_for_selected(storage) {
-                    app.last_error_message = Some(e);
-                }
-            }
-            KeyCode::Char('P') => {
-                // Pause/Resume toggle
-                if let Some(timer) = app.get_timer_status() {
-                    use crate::timer::TimerStatus;
-                    match timer.status {
-                        TimerStatus::Running => {
-                            if let Err(e) = app.pause_active_timer(storage) {
-                                app.last_error_message = Some(e);
-                            }
-                        }
-                        TimerStatus::Paused => {
-                            if let Err(e) = app.resume_active_timer(storage) {
-                                app.last_error_message = Some(e);
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-            }
-            KeyCode::Up | KeyCode::Char('k') => app.move_selection_up(),
-            KeyCode::Down | KeyCode::Char('j') => app.move_selection_down(),
-            KeyCode::Left | KeyCode::Char('h') => app.move_field_left(),
-            KeyCode::Right | KeyCode::Char('l') => app.move_field_right(),
-            KeyCode::Enter | KeyCode::Char('i') => app.enter_edit_mode(),
-            KeyCode::Char('c') => app.change_task_name(),
-            KeyCode::Char('n') => {
-                app.add_new_record();
-                let _ = storage.save(&app.day_data);
-                app.last_file_modified = storage.get_last_modified(&app.current_date);
-            }
-            KeyCode::Char('b') => {
-                app.add_break();
-                let _ = storage.save(&app.day_data);
-                app.last_file_modified = storage.get_last_modified(&app.current_date);
-            }
-            KeyCode::Char('d') => {
-                app.delete_selected_record();
-                let _ = storage.save(&app.day_data);
-                app.last_file_modified = storage.get_last_modified(&app.current_date);
-            }
-            KeyCode::Char('v') => app.enter_visual_mode(),
-            KeyCode::Char('t') => {
-                app.set_current_time_on_field();
-                let _ = storage.save(&app.day_data);
-                app.last_file_modified = storage.get_last_modified(&app.current_date);
-            }
-            KeyCode::Char('u') => {
-                app.undo();
-                let _ = storage.save(&app.day_data);
-                app.last_file_modified = storage.get_last_modified(&app.current_date);
-            }
-            KeyCode::Char('r') => {
-                app.redo();
-                let _ = storage.save(&app.day_data);
-                app.last_file_modified = storage.get_last_modified(&app.current_date);
-            }
-            KeyCode::Char('s') => {
-                let _ = storage.save(&app.day_data);
-                app.last_file_modified = storage.get_last_modified(&app.current_date);
-            }
-            KeyCode::Char('[') => app.navigate_to_previous_day(),
-            KeyCode::Char(']') => app.navigate_to_next_day(),
-            _ => {}
-        },
+        ui::AppMode::Browse => handle_browse_key(app, key, storage),
         ui::AppMode::Edit => match key.code {
             KeyCode::Esc => app.exit_edit_mode(),
             KeyCode::Tab => app.next_field(),
+            // A bare ticket name is first expanded with its title, Enter again saves
+            KeyCode::Enter if app.offer_ticket_title(storage) => {}
             KeyCode::Enter => {
                 let _ = app.save_edit();
                 let _ = storage.save(&app.day_data);
@@ -221,6 +162,16 @@ fn handle_key_event(app: &mut AppState, key: KeyEvent, storage: &mut storage::St
             KeyCode::Esc => app.exit_visual_mode(),
             KeyCode::Up | KeyCode::Char('k') => app.move_selection_up(),
             KeyCode::Down | KeyCode::Char('j') => app.move_selection_down(),
+            KeyCode::Char('y') => app.yank_visual_selection(),
+            KeyCode::Char('c') => app.open_bulk_rename(),
+            KeyCode::Char('e') => app.open_bulk_edit(ui::app_state::BulkEditField::Description),
+            KeyCode::Char('s') => app.open_bulk_edit(ui::app_state::BulkEditField::Shift),
+            KeyCode::Char('J') => {
+                app.merge_visual_selection();
+                let _ = storage.save(&app.day_data);
+                app.last_file_modified = storage.get_last_modified(&app.current_date);
+            }
+            KeyCode::Char('m') => app.open_move_visual_selection(),
             KeyCode::Char('d') => {
                 app.delete_visual_selection();
                 let _ = storage.save(&app.day_data);
@@ -246,7 +197,11 @@ fn handle_key_event(app: &mut AppState, key: KeyEvent, storage: &mut storage::St
         },
         ui::AppMode::Calendar => match key.code {
             KeyCode::Esc => app.close_calendar(),
-            KeyCode::Enter => app.calendar_select_date(),
+            KeyCode::Enter => {
+                app.calendar_select_date(storage);
+                let _ = storage.save(&app.day_data);
+                app.last_file_modified = storage.get_last_modified(&app.current_date);
+            }
             KeyCode::Left | KeyCode::Char('h') => app.calendar_navigate_left(),
             KeyCode::Right | KeyCode::Char('l') => app.calendar_navigate_right(),
             KeyCode::Up | KeyCode::Char('k') => app.calendar_navigate_up(),
@@ -261,6 +216,14 @@ fn handle_key_event(app: &mut AppState, key: KeyEvent, storage: &mut storage::St
         },
         ui::AppMode::TaskPicker => match key.code {
             KeyCode::Esc => app.close_task_picker(),
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                app.refresh_assigned_issues()
+            }
+            KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Err(e) = app.start_timer_from_picker(storage) {
+                    app.last_error_message = Some(e);
+                }
+            }
             KeyCode::Up => app.move_task_picker_up(),
             KeyCode::Down => {
                 let filtered_tasks = app.get_filtered_task_names();
@@ -275,9 +238,264 @@ fn handle_key_event(app: &mut AppState, key: KeyEvent, storage: &mut storage::St
             KeyCode::Char(c) => app.handle_task_picker_char(c),
             _ => {}
         },
+        ui::AppMode::QuickAdd => match key.code {
+            KeyCode::Esc => app.close_quick_add(),
+            KeyCode::Enter => match app.submit_quick_add(storage) {
+                Ok(()) => {
+                    let _ = storage.save(&app.day_data);
+                    app.last_file_modified = storage.get_last_modified(&app.current_date);
+                }
+                Err(e) => app.last_error_message = Some(e),
+            },
+            KeyCode::Backspace => app.handle_quick_add_backspace(),
+            KeyCode::Char(c) => app.handle_quick_add_char(c),
+            _ => {}
+        },
+        ui::AppMode::BulkEdit => match key.code {
+            KeyCode::Esc => app.close_bulk_edit(),
+            KeyCode::Enter => match app.submit_bulk_edit() {
+                Ok(()) => {
+                    let _ = storage.save(&app.day_data);
+                    app.last_file_modified = storage.get_last_modified(&app.current_date);
+                }
+                Err(e) => app.last_error_message = Some(e),
+            },
+            KeyCode::Backspace => app.handle_bulk_edit_backspace(),
+            KeyCode::Char(c) => app.handle_bulk_edit_char(c),
+            _ => {}
+        },
+        ui::AppMode::CommandLine => match key.code {
+            KeyCode::Esc => app.close_command_line(),
+            KeyCode::Enter => match app.submit_command_line() {
+                Ok(ExCommand::Write) => {
+                    let _ = storage.save(&app.day_data);
+                    app.last_file_modified = storage.get_last_modified(&app.current_date);
+                }
+                Ok(ExCommand::Quit) => app.should_quit = true,
+                Ok(_) => {}
+                Err(e) => app.last_error_message = Some(e),
+            },
+            KeyCode::Backspace => app.handle_command_line_backspace(),
+            KeyCode::Char(c) => app.handle_command_line_char(c),
+            _ => {}
+        },
+        ui::AppMode::Split => match key.code {
+            KeyCode::Esc => app.close_split(),
+            KeyCode::Enter => match app.submit_split() {
+                Ok(()) => {
+                    let _ = storage.save(&app.day_data);
+                    app.last_file_modified = storage.get_last_modified(&app.current_date);
+                }
+                Err(e) => app.last_error_message = Some(e),
+            },
+            KeyCode::Backspace => app.handle_split_backspace(),
+            KeyCode::Char(c) => app.handle_split_char(c),
+            _ => {}
+        },
+        ui::AppMode::Filter => match key.code {
+            KeyCode::Esc => app.cancel_filter(),
+            KeyCode::Enter => app.apply_filter(),
+            KeyCode::Up => app.move_selection_up(),
+            KeyCode::Down => app.move_selection_down(),
+            KeyCode::Backspace => app.handle_filter_backspace(),
+            KeyCode::Char(c) => app.handle_filter_char(c),
+            _ => {}
+        },
+        ui::AppMode::Search => match key.code {
+            KeyCode::Esc => app.close_search(),
+            KeyCode::Up => app.move_search_up(),
+            KeyCode::Down => app.move_search_down(),
+            KeyCode::Enter => app.select_search_result(),
+            KeyCode::Backspace => app.handle_search_backspace(),
+            KeyCode::Char(c) => app.handle_search_char(c),
+            _ => {}
+        },
+        ui::AppMode::Stats => match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('D') => app.close_stats(),
+            KeyCode::Char('[') | KeyCode::Left | KeyCode::Char('h') => {
+                app.stats_shorter_range(storage)
+            }
+            KeyCode::Char(']') | KeyCode::Right | KeyCode::Char('l') => {
+                app.stats_longer_range(storage)
+            }
+            _ => {}
+        },
     }
 }
 
+/// Browse mode keys, with vim-style counts (`5j`, `3dd`), `gg`/`G`, `.` and `:` commands
+fn handle_browse_key(app: &mut AppState, key: KeyEvent, storage: &mut storage::StorageManager) {
+    use ui::app_state::Change;
+
+    if let KeyCode::Char(c) = key.code
+        && app.push_count_digit(c)
+    {
+        return;
+    }
+    let count = app.take_count();
+    let times = count.unwrap_or(1);
+
+    if let Some(first) = app.take_pending_key() {
+        match (first, key.code) {
+            ('g', KeyCode::Char('g')) => app.go_to_row(count.unwrap_or(1) as usize),
+            ('d', KeyCode::Char('d')) => apply_change(app, Change::Delete, count, storage),
+            // Anything else cancels, like Esc
+            _ => {}
+        }
+        return;
+    }
+    if key.code == KeyCode::Esc && count.is_some() {
+        return;
+    }
+
+    match key.code {
+        KeyCode::Char('q') => app.should_quit = true,
+        KeyCode::Char('?') => app.open_command_palette(),
+        KeyCode::Char('C') => app.open_calendar(),
+        KeyCode::Char('D') => app.open_stats(storage),
+        KeyCode::Char('/') => app.open_search(storage),
+        KeyCode::Char('f') => app.open_filter(),
+        KeyCode::Char('o') => app.cycle_sort_order(),
+        KeyCode::Char('w') => app.toggle_timeline(),
+        KeyCode::Char('*') => app.cycle_nudge_step(),
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            apply_change(app, Change::Nudge(1), count, storage)
+        }
+        KeyCode::Char('-') => apply_change(app, Change::Nudge(-1), count, storage),
+        KeyCode::Esc if app.is_filtered() => app.clear_filter(),
+        KeyCode::Char('T') if app.config.has_integrations() => app.open_ticket_in_browser(),
+        KeyCode::Char('L') if app.config.has_integrations() => app.open_worklog_in_browser(),
+        KeyCode::Char('I') => app.open_issue_picker(),
+        // Timer keybindings
+        KeyCode::Char('S') => {
+            // Start/Stop toggle - Start if no timer active, Stop if timer is running
+            if let Some(timer) = app.get_timer_status() {
+                use crate::timer::TimerStatus;
+                if matches!(timer.status, TimerStatus::Running | TimerStatus::Paused) {
+                    if let Err(e) = app.stop_active_timer(storage) {
+                        app.last_error_message = Some(e);
+                    }
+                } else if let Err(e) = app.start_timer_for_selected(storage) {
+                    app.last_error_message = Some(e);
+                }
+            } else if let Err(e) = app.start_timer_for_selected(storage) {
+                app.last_error_message = Some(e);
+            }
+        }
+        KeyCode::Char('P') => {
+            // Pause/Resume toggle
+            if let Some(timer) = app.get_timer_status() {
+                use crate::timer::TimerStatus;
+                match timer.status {
+                    TimerStatus::Running => {
+                        if let Err(e) = app.pause_active_timer(storage) {
+                            app.last_error_message = Some(e);
+                        }
+                    }
+                    TimerStatus::Paused => {
+                        if let Err(e) = app.resume_active_timer(storage) {
+                            app.last_error_message = Some(e);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            for _ in 0..times {
+                app.move_selection_up();
+            }
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            for _ in 0..times {
+                app.move_selection_down();
+            }
+        }
+        KeyCode::Left | KeyCode::Char('h') => app.move_field_left(),
+        KeyCode::Right | KeyCode::Char('l') => app.move_field_right(),
+        KeyCode::Enter | KeyCode::Char('i') => app.enter_edit_mode(),
+        KeyCode::Char('c') => app.change_task_name(),
+        KeyCode::Char('n') => apply_change(app, Change::AddRecord, count, storage),
+        KeyCode::Char('b') => apply_change(app, Change::AddBreak, count, storage),
+        KeyCode::Char(key @ ('g' | 'd')) => app.set_pending_key(key, count),
+        KeyCode::Char('G') => app.go_to_row(count.map_or(usize::MAX, |row| row as usize)),
+        KeyCode::Char('.') => {
+            if let Some((change, last_count)) = app.last_change {
+                apply_change(app, change, count.or(last_count), storage);
+            }
+        }
+        KeyCode::Char(':') => app.open_command_line(),
+        KeyCode::Char('v') => app.enter_visual_mode(),
+        KeyCode::Char('y') => app.yank_selected_record(),
+        KeyCode::Char('m') => app.open_move_to_date(),
+        KeyCode::Char('x') => app.open_split(),
+        KeyCode::Char('J') => apply_change(app, Change::MergeWithNext, count, storage),
+        KeyCode::Char('p') => apply_change(app, Change::Paste, count, storage),
+        KeyCode::Char('Y') => {
+            app.duplicate_previous_day(storage);
+            let _ = storage.save(&app.day_data);
+            app.last_file_modified = storage.get_last_modified(&app.current_date);
+        }
+        KeyCode::Char('t') => apply_change(app, Change::SetCurrentTime, count, storage),
+        KeyCode::Char('u') => {
+            for _ in 0..times {
+                app.undo(storage);
+            }
+            let _ = storage.save(&app.day_data);
+            app.last_file_modified = storage.get_last_modified(&app.current_date);
+        }
+        KeyCode::Char('r') => {
+            for _ in 0..times {
+                app.redo(storage);
+            }
+            let _ = storage.save(&app.day_data);
+            app.last_file_modified = storage.get_last_modified(&app.current_date);
+        }
+        KeyCode::Char('s') => {
+            let _ = storage.save(&app.day_data);
+            app.last_file_modified = storage.get_last_modified(&app.current_date);
+        }
+        KeyCode::Char('a') => app.open_quick_add(),
+        KeyCode::Char('[') => {
+            for _ in 0..times {
+                app.navigate_to_previous_day();
+            }
+        }
+        KeyCode::Char(']') => {
+            for _ in 0..times {
+                app.navigate_to_next_day();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Apply a browse-mode change and remember it for `.`
+fn apply_change(
+    app: &mut AppState,
+    change: ui::app_state::Change,
+    count: Option<u32>,
+    storage: &mut storage::StorageManager,
+) {
+    use ui::app_state::Change;
+
+    let times = count.unwrap_or(1);
+    match change {
+        Change::Delete => app.delete_selected_records(times as usize),
+        Change::Nudge(steps) => app.nudge_selected_time(steps * times as i32),
+        Change::MergeWithNext => app.merge_with_next(times),
+        Change::Paste => app.paste_yanked(times),
+        Change::AddRecord => app.add_new_records(times),
+        Change::AddBreak => app.add_breaks(times),
+        Change::SetCurrentTime => app.set_current_time_on_field(),
+    }
+    // Setting the time again doesn't change anything, so a count means nothing to `t`
+    let count = count.filter(|_| change != Change::SetCurrentTime);
+    app.last_change = Some((change, count));
+    let _ = storage.save(&app.day_data);
+    app.last_file_modified = storage.get_last_modified(&app.current_date);
+}
+
 fn execute_command_action(
     app: &mut AppState,
     action: ui::app_state::CommandAction,
@@ -292,6 +510,7 @@ fn execute_command_action(
         CommandAction::MoveRight => app.move_field_right(),
         CommandAction::Edit => app.enter_edit_mode(),
         CommandAction::Change => app.change_task_name(),
+        CommandAction::PickIssue => app.open_issue_picker(),
         CommandAction::New => {
             app.add_new_record();
             let _ = storage.save(&app.day_data);
@@ -308,18 +527,48 @@ fn execute_command_action(
             app.last_file_modified = storage.get_last_modified(&app.current_date);
         }
         CommandAction::Visual => app.enter_visual_mode(),
+        CommandAction::Yank => app.yank_selected_record(),
+        CommandAction::MoveToDate => app.open_move_to_date(),
+        CommandAction::Split => app.open_split(),
+        CommandAction::Timeline => app.toggle_timeline(),
+        CommandAction::NudgeStep => app.cycle_nudge_step(),
+        CommandAction::NudgeLater => {
+            app.nudge_selected_time(1);
+            let _ = storage.save(&app.day_data);
+            app.last_file_modified = storage.get_last_modified(&app.current_date);
+        }
+        CommandAction::NudgeEarlier => {
+            app.nudge_selected_time(-1);
+            let _ = storage.save(&app.day_data);
+            app.last_file_modified = storage.get_last_modified(&app.current_date);
+        }
+        CommandAction::MergeWithNext => {
+            app.merge_with_next(1);
+            let _ = storage.save(&app.day_data);
+            app.last_file_modified = storage.get_last_modified(&app.current_date);
+        }
+        CommandAction::Paste => {
+            app.paste_yanked(1);
+            let _ = storage.save(&app.day_data);
+            app.last_file_modified = storage.get_last_modified(&app.current_date);
+        }
+        CommandAction::DuplicatePreviousDay => {
+            app.duplicate_previous_day(storage);
+            let _ = storage.save(&app.day_data);
+            app.last_file_modified = storage.get_last_modified(&app.current_date);
+        }
         CommandAction::SetNow => {
             app.set_current_time_on_field();
             let _ = storage.save(&app.day_data);
             app.last_file_modified = storage.get_last_modified(&app.current_date);
         }
         CommandAction::Undo => {
-            app.undo();
+            app.undo(storage);
             let _ = storage.save(&app.day_data);
             app.last_file_modified = storage.get_last_modified(&app.current_date);
         }
         CommandAction::Redo => {
-            app.redo();
+            app.redo(storage);
             let _ = storage.save(&app.day_data);
             app.last_file_modified = storage.get_last_modified(&app.current_date);
         }
@@ -352,6 +601,11 @@ fn execute_command_action(
                 }
             }
         }
+        CommandAction::QuickAdd => app.open_quick_add(),
+        CommandAction::Stats => app.open_stats(storage),
+        CommandAction::Search => app.open_search(storage),
+        CommandAction::Filter => app.open_filter(),
+        CommandAction::Sort => app.cycle_sort_order(),
         CommandAction::Quit => app.should_quit = true,
     }
 }
@@ -1,4 +1,4 @@
-use super::WorkRecord;
+use super::{TimePoint, WorkRecord};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use time::Date;
@@ -8,6 +8,16 @@ pub struct DayData {
     pub date: Date,
     pub last_id: u32,
     pub work_records: HashMap<u32, WorkRecord>,
+    /// IDs of per-ticket time summary comments posted by sync, keyed by "tracker:ticket"
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub synced_comments: HashMap<String, String>,
+}
+
+/// Start or end of a record
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    Start,
+    End,
 }
 
 impl DayData {
@@ -16,6 +26,7 @@ impl DayData {
             date,
             last_id: 0,
             work_records: HashMap::new(),
+            synced_comments: HashMap::new(),
         }
     }
 
@@ -35,9 +46,258 @@ impl DayData {
         self.last_id
     }
 
+    /// Add copies of records (e.g. from another day) under new ids, returning the ids
+    ///
+    /// Times and descriptions are kept; a copy isn't synced to any tracker worklog.
+    pub fn paste_records(&mut self, records: &[WorkRecord]) -> Vec<u32> {
+        records
+            .iter()
+            .map(|record| {
+                let id = self.next_id();
+                self.add_record(WorkRecord {
+                    id,
+                    worklog_id: None,
+                    worklog_ticket: None,
+                    ..record.clone()
+                });
+                id
+            })
+            .collect()
+    }
+
+    /// Move records into another day, where they get new ids (returned in the given order)
+    ///
+    /// Unlike pasted copies, moved records keep their worklog id, so a sync updates the
+    /// tracker's entry instead of adding a second one.
+    pub fn move_records(&mut self, ids: &[u32], target: &mut DayData) -> Vec<u32> {
+        ids.iter()
+            .filter_map(|id| self.remove_record(*id))
+            .map(|record| {
+                let id = target.next_id();
+                target.add_record(WorkRecord { id, ..record });
+                id
+            })
+            .collect()
+    }
+
+    /// Move records earlier (negative `minutes`) or later, keeping their durations
+    ///
+    /// Nothing changes if any of them would leave the day.
+    pub fn shift_records(&mut self, ids: &[u32], minutes: i32) -> Result<(), String> {
+        let mut shifted = Vec::new();
+        for id in ids {
+            let Some(record) = self.work_records.get(id) else {
+                continue;
+            };
+            match (record.start.shifted(minutes), record.end.shifted(minutes)) {
+                (Some(start), Some(end)) => shifted.push((*id, start, end)),
+                _ => return Err(format!("Can't shift \"{}\" past midnight", record.name)),
+            }
+        }
+
+        for (id, start, end) in shifted {
+            if let Some(record) = self.work_records.get_mut(&id) {
+                record.start = start;
+                record.end = end;
+                record.update_duration();
+            }
+        }
+        Ok(())
+    }
+
+    /// Move the start or end of a record earlier (negative `minutes`) or later
+    ///
+    /// With `adjacent`, records whose boundary touched the moved one move along and
+    /// stay contiguous. Nothing changes if a record would end before it starts.
+    pub fn nudge_time(
+        &mut self,
+        id: u32,
+        boundary: Boundary,
+        minutes: i32,
+        adjacent: bool,
+    ) -> Result<(), String> {
+        let record = self.work_records.get(&id).ok_or("Record not found")?;
+        let old = match boundary {
+            Boundary::Start => record.start,
+            Boundary::End => record.end,
+        };
+        let new = old
+            .shifted(minutes)
+            .ok_or_else(|| format!("Can't move \"{}\" past midnight", record.name))?;
+
+        let mut moves = vec![(id, boundary)];
+        if adjacent {
+            moves.extend(
+                self.work_records
+                    .values()
+                    .filter_map(|other| match boundary {
+                        Boundary::Start if other.id != id && other.end == old => {
+                            Some((other.id, Boundary::End))
+                        }
+                        Boundary::End if other.id != id && other.start == old => {
+                            Some((other.id, Boundary::Start))
+                        }
+                        _ => None,
+                    }),
+            );
+        }
+
+        let mut nudged = Vec::new();
+        for (id, boundary) in moves {
+            let mut record = self.work_records[&id].clone();
+            let expected = match boundary {
+                Boundary::Start => {
+                    record.start = new;
+                    record.total_minutes as i32 - minutes
+                }
+                Boundary::End => {
+                    record.end = new;
+                    record.total_minutes as i32 + minutes
+                }
+            };
+            record.update_duration();
+            // Moving past the other end wraps the duration around midnight instead
+            if expected <= 0 || record.total_minutes as i32 != expected {
+                return Err(format!("\"{}\" would end before it starts", record.name));
+            }
+            nudged.push(record);
+        }
+
+        for record in nudged {
+            self.work_records.insert(record.id, record);
+        }
+        Ok(())
+    }
+
+    /// Merge records of one task that follow each other into the earliest of them
+    ///
+    /// The merged record runs from the first start to the last end, with the
+    /// descriptions joined. No other record may start in between, and at most one
+    /// tracker worklog may be involved. Returns its id.
+    pub fn merge_records(&mut self, ids: &[u32]) -> Result<u32, String> {
+        let sorted = self.get_sorted_records();
+        let positions: Vec<usize> = (0..sorted.len())
+            .filter(|&i| ids.contains(&sorted[i].id))
+            .collect();
+        if positions.len() < 2 {
+            return Err("Select at least two records to merge".to_string());
+        }
+        if positions.windows(2).any(|pair| pair[1] != pair[0] + 1) {
+            return Err("Only records next to each other can be merged".to_string());
+        }
+        let records: Vec<WorkRecord> = positions.iter().map(|&i| sorted[i].clone()).collect();
+        if records.iter().any(|r| r.name != records[0].name) {
+            return Err("Only records of the same task can be merged".to_string());
+        }
+
+        self.join_records(&records)
+    }
+
+    /// Join a record with the one starting next, if it starts where this one ends (or earlier)
+    ///
+    /// The joined record keeps this one's id and name. Records synced to different
+    /// worklogs aren't joined. Returns its id.
+    pub fn merge_with_next(&mut self, id: u32) -> Result<u32, String> {
+        let sorted = self.get_sorted_records();
+        let position = sorted
+            .iter()
+            .position(|r| r.id == id)
+            .ok_or("Record not found")?;
+        let (record, next) = match (sorted.get(position), sorted.get(position + 1)) {
+            (Some(record), Some(next)) => ((*record).clone(), (*next).clone()),
+            _ => return Err("There's no next record to merge with".to_string()),
+        };
+        if next.start > record.end {
+            return Err(format!(
+                "\"{}\" starts at {}, not where this record ends",
+                next.name, next.start
+            ));
+        }
+
+        self.join_records(&[record, next])
+    }
+
+    /// Replace records (in time order) with one running from the first start to the last end
+    ///
+    /// The merged record keeps the worklog of the synced one. Fails if they're synced to
+    /// different worklogs, as all but one of those would be left behind on the tracker.
+    fn join_records(&mut self, records: &[WorkRecord]) -> Result<u32, String> {
+        let mut synced = records.iter().filter(|r| r.worklog_id.is_some());
+        let worklog = synced.next();
+        if let Some(worklog) = worklog
+            && synced.any(|r| {
+                (&r.worklog_id, &r.worklog_ticket) != (&worklog.worklog_id, &worklog.worklog_ticket)
+            })
+        {
+            return Err(
+                "These records are synced to different worklogs and can't be merged".to_string(),
+            );
+        }
+
+        let mut descriptions: Vec<&str> = Vec::new();
+        for record in records {
+            let description = record.description.trim();
+            if !description.is_empty() && !descriptions.contains(&description) {
+                descriptions.push(description);
+            }
+        }
+        let mut merged = records[0].clone();
+        merged.end = records.iter().map(|r| r.end).max().unwrap_or(merged.end);
+        merged.description = descriptions.join("; ");
+        if let Some(worklog) = worklog {
+            merged.worklog_id = worklog.worklog_id.clone();
+            merged.worklog_ticket = worklog.worklog_ticket.clone();
+        }
+        merged.update_duration();
+
+        for record in &records[1..] {
+            self.remove_record(record.id);
+        }
+        let id = merged.id;
+        self.add_record(merged);
+        Ok(id)
+    }
+
+    /// Split a record in two at `at`, optionally naming the second part differently
+    ///
+    /// The second part gets a new id and the description; a synced worklog stays with
+    /// the first part. Returns the second part's id.
+    pub fn split_record(
+        &mut self,
+        id: u32,
+        at: TimePoint,
+        second_name: Option<String>,
+    ) -> Result<u32, String> {
+        let record = self.work_records.get(&id).ok_or("Record not found")?;
+        let offset = WorkRecord::calculate_duration(&record.start, &at);
+        if offset == 0 || offset >= record.total_minutes {
+            return Err(format!(
+                "Pick a time between {} and {}",
+                record.start, record.end
+            ));
+        }
+
+        let mut second = WorkRecord::new(
+            0,
+            second_name.unwrap_or_else(|| record.name.clone()),
+            at,
+            record.end,
+        );
+        second.description = record.description.clone();
+        if let Some(first) = self.work_records.get_mut(&id) {
+            first.end = at;
+            first.update_duration();
+        }
+
+        second.id = self.next_id();
+        let second_id = second.id;
+        self.add_record(second);
+        Ok(second_id)
+    }
+
     pub fn get_sorted_records(&self) -> Vec<&WorkRecord> {
         let mut records: Vec<&WorkRecord> = self.work_records.values().collect();
-        records.sort_by_key(|r| r.start);
+        records.sort_by_key(|r| (r.start, r.id));
         records
     }
 
@@ -159,6 +419,230 @@ mod tests {
         assert_eq!(day.next_id(), 7);
     }
 
+    #[test]
+    fn test_paste_records_assigns_new_ids() {
+        let mut day = DayData::new(create_test_date());
+        day.add_record(create_test_record(1, "Coding", 9, 12));
+        day.add_record(create_test_record(2, "Meeting", 13, 14));
+
+        let mut synced = create_test_record(1, "Coding", 9, 12);
+        synced.description = "Login form".to_string();
+        synced.worklog_id = Some("10042".to_string());
+        let ids = day.paste_records(&[synced, create_test_record(5, "Review", 14, 15)]);
+
+        assert_eq!(ids, vec![3, 4]);
+        assert_eq!(day.work_records.len(), 4);
+        assert_eq!(day.work_records[&1].name, "Coding");
+        let copy = &day.work_records[&3];
+        assert_eq!(copy.start, TimePoint::new(9, 0).unwrap());
+        assert_eq!(copy.description, "Login form");
+        assert_eq!(copy.worklog_id, None);
+        assert_eq!(day.work_records[&4].name, "Review");
+    }
+
+    #[test]
+    fn test_move_records_between_days() {
+        let mut day = DayData::new(create_test_date());
+        day.add_record(create_test_record(1, "Coding", 9, 12));
+        day.add_record(create_test_record(2, "Meeting", 13, 14));
+        day.add_record(create_test_record(3, "Review", 14, 15));
+        day.work_records.get_mut(&3).unwrap().worklog_id = Some("10042".to_string());
+        let mut target = DayData::new(create_test_date().next_day().unwrap());
+        target.add_record(create_test_record(1, "Standup", 9, 10));
+
+        let ids = day.move_records(&[3, 9, 2], &mut target);
+
+        assert_eq!(ids, vec![2, 3]);
+        assert_eq!(day.work_records.len(), 1);
+        assert!(day.work_records.contains_key(&1));
+        assert_eq!(target.work_records.len(), 3);
+        assert_eq!(target.work_records[&2].name, "Review");
+        assert_eq!(target.work_records[&2].worklog_id.as_deref(), Some("10042"));
+        assert_eq!(
+            target.work_records[&3].start,
+            TimePoint::new(13, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_shift_records() {
+        let mut day = DayData::new(create_test_date());
+        day.add_record(create_test_record(1, "Coding", 9, 12));
+        day.add_record(create_test_record(2, "Meeting", 13, 14));
+        day.add_record(create_test_record(3, "Late", 22, 23));
+
+        day.shift_records(&[1, 2], -30).unwrap();
+        assert_eq!(day.work_records[&1].start, TimePoint::new(8, 30).unwrap());
+        assert_eq!(day.work_records[&1].end, TimePoint::new(11, 30).unwrap());
+        assert_eq!(day.work_records[&1].total_minutes, 180);
+        assert_eq!(day.work_records[&2].start, TimePoint::new(12, 30).unwrap());
+
+        // One record leaving the day stops them all
+        assert!(day.shift_records(&[2, 3], 90).is_err());
+        assert_eq!(day.work_records[&2].start, TimePoint::new(12, 30).unwrap());
+        assert_eq!(day.work_records[&3].start, TimePoint::new(22, 0).unwrap());
+    }
+
+    #[test]
+    fn test_nudge_time() {
+        let mut day = DayData::new(create_test_date());
+        day.add_record(create_test_record(1, "Coding", 9, 10));
+        day.add_record(create_test_record(2, "Meeting", 10, 11));
+        day.add_record(create_test_record(3, "Lunch", 12, 13));
+
+        day.nudge_time(1, Boundary::End, 15, false).unwrap();
+        assert_eq!(day.work_records[&1].end, TimePoint::new(10, 15).unwrap());
+        assert_eq!(day.work_records[&1].total_minutes, 75);
+        assert_eq!(day.work_records[&2].start, TimePoint::new(10, 0).unwrap());
+
+        day.nudge_time(2, Boundary::Start, 15, true).unwrap();
+        day.nudge_time(2, Boundary::Start, -5, true).unwrap();
+        assert_eq!(day.work_records[&2].start, TimePoint::new(10, 10).unwrap());
+        assert_eq!(day.work_records[&1].end, TimePoint::new(10, 10).unwrap());
+        assert_eq!(day.work_records[&2].total_minutes, 50);
+
+        // Only touching neighbours move along
+        day.nudge_time(2, Boundary::End, 5, true).unwrap();
+        assert_eq!(day.work_records[&3].start, TimePoint::new(12, 0).unwrap());
+    }
+
+    #[test]
+    fn test_nudge_time_rejects_inverted_records() {
+        let mut day = DayData::new(create_test_date());
+        day.add_record(create_test_record(1, "Coding", 9, 10));
+        day.add_record(create_test_record(2, "Meeting", 10, 11));
+
+        assert!(day.nudge_time(1, Boundary::End, -60, false).is_err());
+        assert!(day.nudge_time(1, Boundary::Start, 90, false).is_err());
+        // The neighbour would vanish, so the record doesn't move either
+        assert!(day.nudge_time(1, Boundary::End, 60, true).is_err());
+        assert_eq!(day.work_records[&1].end, TimePoint::new(10, 0).unwrap());
+        assert!(day.nudge_time(1, Boundary::Start, -600, false).is_err());
+    }
+
+    #[test]
+    fn test_merge_records() {
+        let mut day = DayData::new(create_test_date());
+        day.add_record(create_test_record(1, "Coding", 9, 10));
+        day.add_record(create_test_record(2, "Coding", 10, 11));
+        day.add_record(create_test_record(3, "Coding", 11, 12));
+        day.add_record(create_test_record(4, "Lunch", 12, 13));
+        day.add_record(create_test_record(5, "Coding", 13, 15));
+        day.work_records.get_mut(&1).unwrap().description = "Login".to_string();
+        day.work_records.get_mut(&2).unwrap().description = "Tests".to_string();
+        day.work_records.get_mut(&3).unwrap().description = "Login".to_string();
+        day.work_records.get_mut(&2).unwrap().worklog_id = Some("10042".to_string());
+
+        assert_eq!(day.merge_records(&[3, 1, 2]), Ok(1));
+        assert_eq!(day.work_records.len(), 3);
+        let merged = &day.work_records[&1];
+        assert_eq!(merged.end, TimePoint::new(12, 0).unwrap());
+        assert_eq!(merged.total_minutes, 180);
+        assert_eq!(merged.description, "Login; Tests");
+        assert_eq!(merged.worklog_id.as_deref(), Some("10042"));
+    }
+
+    #[test]
+    fn test_merge_records_rejects_gaps_and_other_tasks() {
+        let mut day = DayData::new(create_test_date());
+        day.add_record(create_test_record(1, "Coding", 9, 10));
+        day.add_record(create_test_record(2, "Lunch", 12, 13));
+        day.add_record(create_test_record(3, "Coding", 13, 15));
+
+        assert!(day.merge_records(&[1, 3]).is_err());
+        assert!(day.merge_records(&[2, 3]).is_err());
+        assert!(day.merge_records(&[1]).is_err());
+        assert_eq!(day.work_records.len(), 3);
+    }
+
+    #[test]
+    fn test_merge_refuses_records_synced_to_different_worklogs() {
+        let mut day = DayData::new(create_test_date());
+        day.add_record(create_test_record(1, "Coding", 9, 10));
+        day.add_record(create_test_record(2, "Coding", 10, 11));
+        day.add_record(create_test_record(3, "Coding", 11, 12));
+        day.work_records.get_mut(&1).unwrap().worklog_id = Some("10042".to_string());
+        day.work_records.get_mut(&2).unwrap().worklog_id = Some("10043".to_string());
+
+        assert!(day.merge_records(&[1, 2]).is_err());
+        assert!(day.merge_with_next(1).is_err());
+        assert_eq!(day.work_records.len(), 3);
+
+        // Records of the same worklog can be merged
+        day.work_records.get_mut(&3).unwrap().worklog_id = Some("10043".to_string());
+        assert_eq!(day.merge_with_next(2), Ok(2));
+        assert_eq!(day.work_records[&2].worklog_id.as_deref(), Some("10043"));
+    }
+
+    #[test]
+    fn test_merge_with_next() {
+        let mut day = DayData::new(create_test_date());
+        day.add_record(create_test_record(1, "Coding", 9, 10));
+        day.add_record(create_test_record(2, "Review", 10, 12));
+        day.add_record(create_test_record(3, "Lunch", 13, 14));
+        day.work_records.get_mut(&2).unwrap().description = "PR 12".to_string();
+
+        assert_eq!(day.merge_with_next(1), Ok(1));
+        let merged = &day.work_records[&1];
+        assert_eq!(merged.name, "Coding");
+        assert_eq!(merged.end, TimePoint::new(12, 0).unwrap());
+        assert_eq!(merged.description, "PR 12");
+        assert_eq!(day.work_records.len(), 2);
+
+        // Lunch starts an hour later, and is the last record
+        assert!(day.merge_with_next(1).is_err());
+        assert!(day.merge_with_next(3).is_err());
+        assert_eq!(day.work_records.len(), 2);
+    }
+
+    #[test]
+    fn test_split_record() {
+        let mut day = DayData::new(create_test_date());
+        day.add_record(create_test_record(1, "Coding", 9, 12));
+        day.add_record(create_test_record(2, "Lunch", 12, 13));
+        let coding = day.work_records.get_mut(&1).unwrap();
+        coding.description = "Login".to_string();
+        coding.worklog_id = Some("10042".to_string());
+
+        let at = TimePoint::new(10, 30).unwrap();
+        assert_eq!(day.split_record(1, at, Some("Review".to_string())), Ok(3));
+        let first = &day.work_records[&1];
+        assert_eq!(
+            (first.name.as_str(), first.end, first.total_minutes),
+            ("Coding", at, 90)
+        );
+        assert_eq!(first.worklog_id.as_deref(), Some("10042"));
+        let second = &day.work_records[&3];
+        assert_eq!((second.name.as_str(), second.start), ("Review", at));
+        assert_eq!(
+            (second.end, second.total_minutes),
+            (TimePoint::new(12, 0).unwrap(), 90)
+        );
+        assert_eq!(second.description, "Login");
+        assert_eq!(second.worklog_id, None);
+
+        assert_eq!(
+            day.split_record(2, TimePoint::new(12, 45).unwrap(), None),
+            Ok(4)
+        );
+        assert_eq!(day.work_records[&4].name, "Lunch");
+    }
+
+    #[test]
+    fn test_split_record_outside_the_record() {
+        let mut day = DayData::new(create_test_date());
+        day.add_record(create_test_record(1, "Coding", 9, 12));
+
+        for hour in [9, 12, 13, 8] {
+            assert!(
+                day.split_record(1, TimePoint::new(hour, 0).unwrap(), None)
+                    .is_err()
+            );
+        }
+        assert_eq!(day.work_records.len(), 1);
+        assert_eq!(day.last_id, 1);
+    }
+
     #[test]
     fn test_get_sorted_records_empty() {
         let day = DayData::new(create_test_date());
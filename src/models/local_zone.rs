@@ -0,0 +1,55 @@
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// The time zone records are tracked in
+///
+/// Records hold wall-clock times. Converting them to or from instants needs the UTC offset
+/// in effect at that moment, which changes across daylight saving time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocalZone {
+    /// The system time zone
+    System,
+    /// A fixed offset, for tests and zones without daylight saving time
+    #[allow(dead_code)]
+    Fixed(UtcOffset),
+}
+
+impl LocalZone {
+    /// The UTC offset in effect at `instant`
+    pub fn offset_at(self, instant: OffsetDateTime) -> UtcOffset {
+        match self {
+            LocalZone::System => UtcOffset::local_offset_at(instant)
+                .or_else(|_| UtcOffset::current_local_offset())
+                .unwrap_or(UtcOffset::UTC),
+            LocalZone::Fixed(offset) => offset,
+        }
+    }
+
+    /// The wall-clock time of `instant`
+    pub fn to_local(self, instant: OffsetDateTime) -> PrimitiveDateTime {
+        let local = instant.to_offset(self.offset_at(instant));
+        PrimitiveDateTime::new(local.date(), local.time())
+    }
+
+    /// The instant of the wall-clock time `local`
+    ///
+    /// Times in the hour skipped or repeated by a daylight saving change resolve to one of
+    /// the offsets around it.
+    pub fn assume(self, local: PrimitiveDateTime) -> OffsetDateTime {
+        let guess = local.assume_offset(self.offset_at(local.assume_utc()));
+        local.assume_offset(self.offset_at(guess))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{datetime, offset};
+
+    #[test]
+    fn test_fixed_zone_round_trips() {
+        let zone = LocalZone::Fixed(offset!(+2));
+        let instant = zone.assume(datetime!(2026-03-29 12:30));
+        assert_eq!(instant, datetime!(2026-03-29 10:30 UTC));
+        assert_eq!(zone.to_local(instant), datetime!(2026-03-29 12:30));
+    }
+}
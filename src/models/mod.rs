@@ -1,7 +1,14 @@
 mod day_data;
+mod local_zone;
+mod quick_entry;
+mod search;
 mod time_point;
 mod work_record;
 
-pub use day_data::DayData;
+pub use day_data::{Boundary, DayData};
+pub use local_zone::LocalZone;
+pub use quick_entry::QuickEntry;
+pub(crate) use quick_entry::{parse_date, parse_duration, parse_offset};
+pub use search::{SearchHit, SearchQuery};
 pub use time_point::TimePoint;
 pub use work_record::WorkRecord;
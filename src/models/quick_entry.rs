@@ -0,0 +1,620 @@
+use super::{TimePoint, WorkRecord};
+use regex::Regex;
+use std::sync::LazyLock;
+use time::{Date, Duration, PrimitiveDateTime, Time, Weekday};
+
+/// A time entry parsed from free-form text such as
+/// `"yesterday 2pm-3:30pm PROJ-42 code review"` or `"1h30m on standup ending now"`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuickEntry {
+    pub date: Date,
+    pub name: String,
+    pub start: TimePoint,
+    pub end: TimePoint,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Meridiem {
+    Am,
+    Pm,
+}
+
+/// A clock time as written, before am/pm inference
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Clock {
+    hour: u8,
+    minute: u8,
+    meridiem: Option<Meridiem>,
+}
+
+/// How the entry's time span was expressed
+enum Span {
+    Range(Clock, Clock),
+    Duration { minutes: u32, anchor: Anchor },
+}
+
+enum Anchor {
+    Start(Clock),
+    End(Clock),
+}
+
+static CLOCK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\d{1,2})(?::(\d{2}))?(am|pm)?$").unwrap());
+
+static DURATION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:(\d+(?:\.\d+)?)(?:h|hr|hrs|hour|hours))?(?:(\d+)(?:m|min|mins|minutes?)?)?$")
+        .unwrap()
+});
+
+static ISO_DATE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap());
+
+const RANGE_SEPARATORS: &[&str] = &["-", "–", "to", "until", "till"];
+const END_ANCHORS: &[&str] = &["ending", "ended", "until", "till", "to"];
+const START_ANCHORS: &[&str] = &["starting", "started", "from", "at", "since"];
+const NAME_FILLERS: &[&str] = &["on", "for", "-", ":"];
+
+impl QuickEntry {
+    /// Parse a free-form entry
+    ///
+    /// `now` resolves relative words (`today`, `yesterday`, weekdays, `now`);
+    /// `default_date` is used when the text names no date.
+    ///
+    /// Accepted pieces, in any order (dates and weekdays must come first):
+    /// - Date: `today`, `yesterday`, `YYYY-MM-DD`, `monday`, `last friday`
+    /// - Range: `2pm-3:30pm`, `14:00 - 15:30`, `from 9 to 11am`
+    /// - Duration: `1h30m`, `90m`, `1.5h`, `1h 30m`, optionally anchored with
+    ///   `ending 5pm` / `starting 9:00` (defaults to ending now)
+    /// - Everything else becomes the task name
+    pub fn parse(input: &str, now: PrimitiveDateTime, default_date: Date) -> Result<Self, String> {
+        let mut tokens = tokenize(input);
+        if tokens.is_empty() {
+            return Err("Entry is empty".to_string());
+        }
+
+        let date = take_date(&mut tokens, now.date())?.unwrap_or(default_date);
+        let span = take_span(&mut tokens, now)?
+            .ok_or_else(|| "No time given (e.g. 2pm-3pm or 1h30m)".to_string())?;
+
+        let name = build_name(&tokens);
+        if name.is_empty() {
+            return Err("Missing task name".to_string());
+        }
+
+        let (date, start, end) = resolve_span(span, date)?;
+
+        Ok(QuickEntry {
+            date,
+            name,
+            start,
+            end,
+        })
+    }
+
+    /// Convert into a work record with the given ID
+    pub fn into_record(self, id: u32) -> WorkRecord {
+        WorkRecord::new(id, self.name, self.start, self.end)
+    }
+}
+
+/// Split on whitespace, gluing a detached `am`/`pm` onto the preceding number
+fn tokenize(input: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    for word in input.split_whitespace() {
+        let lower = word.to_lowercase();
+        let is_meridiem = matches!(lower.as_str(), "am" | "pm" | "a.m." | "p.m.");
+        if is_meridiem
+            && let Some(last) = tokens.last_mut()
+            && parse_clock(last).is_some_and(|c| c.meridiem.is_none())
+        {
+            last.push_str(&lower.replace('.', ""));
+            continue;
+        }
+        tokens.push(word.to_string());
+    }
+    tokens
+}
+
+/// Remove a date expression from the tokens and resolve it against `today`
+fn take_date(tokens: &mut Vec<String>, today: Date) -> Result<Option<Date>, String> {
+    // Weekdays are only recognised at the start so task names like "Monday sync" survive
+    let first = tokens[0].to_lowercase();
+    if first == "last"
+        && let Some(weekday) = tokens.get(1).and_then(|t| parse_weekday(t))
+    {
+        tokens.drain(..2);
+        return Ok(Some(previous_weekday(today, weekday, false)));
+    }
+    if let Some(weekday) = parse_weekday(&first) {
+        tokens.remove(0);
+        return Ok(Some(previous_weekday(today, weekday, true)));
+    }
+
+    let date_format = time::macros::format_description!("[year]-[month]-[day]");
+    for i in 0..tokens.len() {
+        let token = tokens[i].to_lowercase();
+        let date = match token.as_str() {
+            "today" => Some(today),
+            "yesterday" => Some(today - Duration::days(1)),
+            _ if ISO_DATE_RE.is_match(&token) => Some(
+                Date::parse(&token, &date_format)
+                    .map_err(|_| format!("Invalid date: {}", tokens[i]))?,
+            ),
+            _ => None,
+        };
+        if date.is_some() {
+            tokens.remove(i);
+            return Ok(date);
+        }
+    }
+
+    Ok(None)
+}
+
+fn parse_weekday(token: &str) -> Option<Weekday> {
+    match token.to_lowercase().as_str() {
+        "monday" | "mon" => Some(Weekday::Monday),
+        "tuesday" | "tue" => Some(Weekday::Tuesday),
+        "wednesday" | "wed" => Some(Weekday::Wednesday),
+        "thursday" | "thu" => Some(Weekday::Thursday),
+        "friday" | "fri" => Some(Weekday::Friday),
+        "saturday" | "sat" => Some(Weekday::Saturday),
+        "sunday" | "sun" => Some(Weekday::Sunday),
+        _ => None,
+    }
+}
+
+/// Most recent `weekday` on or before `today` (strictly before unless `include_today`)
+fn previous_weekday(today: Date, weekday: Weekday, include_today: bool) -> Date {
+    let mut date = if include_today {
+        today
+    } else {
+        today - Duration::days(1)
+    };
+    while date.weekday() != weekday {
+        date -= Duration::days(1);
+    }
+    date
+}
+
+/// Remove the time range or duration (with optional anchor) from the tokens
+fn take_span(tokens: &mut Vec<String>, now: PrimitiveDateTime) -> Result<Option<Span>, String> {
+    let now_clock = Clock {
+        hour: now.hour(),
+        minute: now.minute(),
+        meridiem: None,
+    };
+    let clock = |token: &str| -> Option<Clock> {
+        match token.to_lowercase().as_str() {
+            "now" => Some(now_clock),
+            "noon" => Some(Clock {
+                hour: 12,
+                minute: 0,
+                meridiem: None,
+            }),
+            "midnight" => Some(Clock {
+                hour: 0,
+                minute: 0,
+                meridiem: None,
+            }),
+            other => parse_clock(other),
+        }
+    };
+
+    for i in 0..tokens.len() {
+        // "2pm-3:30pm" as a single token
+        if let Some((left, right)) = tokens[i].split_once(['-', '–'])
+            && let (Some(start), Some(end)) = (clock(left), clock(right))
+        {
+            tokens.remove(i);
+            drop_filler(tokens, i, &["from"]);
+            return Ok(Some(Span::Range(start, end)));
+        }
+
+        // "2pm - 3pm", "from 9 to 11am"
+        if let (Some(start), Some(separator), Some(end)) = (
+            clock(&tokens[i]),
+            tokens.get(i + 1),
+            tokens.get(i + 2).and_then(|t| clock(t)),
+        ) && RANGE_SEPARATORS.contains(&separator.to_lowercase().as_str())
+        {
+            tokens.drain(i..i + 3);
+            drop_filler(tokens, i, &["from"]);
+            return Ok(Some(Span::Range(start, end)));
+        }
+
+        // "1h30m", "1h 30m", optionally followed by an anchor
+        if let Some(mut minutes) = parse_duration(&tokens[i]) {
+            let mut end = i + 1;
+            while let Some(more) = tokens.get(end).and_then(|t| parse_duration(t)) {
+                minutes += more;
+                end += 1;
+            }
+            tokens.drain(i..end);
+
+            let anchor = take_anchor(tokens, &clock).unwrap_or(Anchor::End(now_clock));
+            drop_filler(tokens, i, &["for"]);
+            return Ok(Some(Span::Duration { minutes, anchor }));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Remove the first `ending <time>` / `starting <time>` pair from the tokens
+fn take_anchor(tokens: &mut Vec<String>, clock: &dyn Fn(&str) -> Option<Clock>) -> Option<Anchor> {
+    for i in 0..tokens.len().saturating_sub(1) {
+        let keyword = tokens[i].to_lowercase();
+        let Some(time) = clock(&tokens[i + 1]) else {
+            continue;
+        };
+        let anchor = if END_ANCHORS.contains(&keyword.as_str()) {
+            Anchor::End(time)
+        } else if START_ANCHORS.contains(&keyword.as_str()) {
+            Anchor::Start(time)
+        } else {
+            continue;
+        };
+        tokens.drain(i..i + 2);
+        return Some(anchor);
+    }
+    None
+}
+
+/// Remove a connective word left dangling just before a removed span
+fn drop_filler(tokens: &mut Vec<String>, removed_at: usize, fillers: &[&str]) {
+    if removed_at > 0 && fillers.contains(&tokens[removed_at - 1].to_lowercase().as_str()) {
+        tokens.remove(removed_at - 1);
+    }
+}
+
+fn parse_clock(token: &str) -> Option<Clock> {
+    let token = token.to_lowercase();
+    let caps = CLOCK_RE.captures(&token)?;
+    let hour: u8 = caps[1].parse().ok()?;
+    let minute: u8 = caps.get(2).map_or(Some(0), |m| m.as_str().parse().ok())?;
+    let meridiem = caps.get(3).map(|m| match m.as_str() {
+        "am" => Meridiem::Am,
+        _ => Meridiem::Pm,
+    });
+
+    let hour_valid = match meridiem {
+        Some(_) => (1..=12).contains(&hour),
+        None => hour < 24,
+    };
+    if !hour_valid || minute >= 60 {
+        return None;
+    }
+    // A bare number is only a time when it has minutes or am/pm; "42" stays in the name
+    if caps.get(2).is_none() && meridiem.is_none() && token.len() > 2 {
+        return None;
+    }
+
+    Some(Clock {
+        hour,
+        minute,
+        meridiem,
+    })
+}
+
+/// Parse a duration token into minutes; bare numbers are not durations
+pub(crate) fn parse_duration(token: &str) -> Option<u32> {
+    let token = token.to_lowercase();
+    if token.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let caps = DURATION_RE.captures(&token)?;
+    let hours: f64 = caps.get(1).map_or(Some(0.0), |h| h.as_str().parse().ok())?;
+    let minutes: u32 = caps.get(2).map_or(Some(0), |m| m.as_str().parse().ok())?;
+    if caps.get(1).is_none() && caps.get(2).is_none() {
+        return None;
+    }
+    Some((hours * 60.0).round() as u32 + minutes)
+}
+
+/// Parse a signed offset like `+15`, `-1h30m` or `45m` into minutes; bare numbers are minutes
+pub(crate) fn parse_offset(input: &str) -> Option<i32> {
+    let input = input.trim();
+    let (sign, amount) = match input.strip_prefix('-') {
+        Some(amount) => (-1, amount),
+        None => (1, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let minutes = if !amount.is_empty() && amount.chars().all(|c| c.is_ascii_digit()) {
+        amount.parse().ok()?
+    } else {
+        i32::try_from(parse_duration(amount)?).ok()?
+    };
+    Some(sign * minutes)
+}
+
+/// Parse a date on its own, with the words quick entries accept (`yesterday`, `last friday`)
+pub(crate) fn parse_date(input: &str, today: Date) -> Result<Date, String> {
+    let mut tokens = tokenize(input);
+    if tokens.is_empty() {
+        return Ok(today);
+    }
+    match take_date(&mut tokens, today)? {
+        Some(date) if tokens.is_empty() => Ok(date),
+        _ => Err(format!("Invalid date: {}", input.trim())),
+    }
+}
+
+fn to_24h(hour: u8, meridiem: Meridiem) -> u8 {
+    match meridiem {
+        Meridiem::Am => hour % 12,
+        Meridiem::Pm => hour % 12 + 12,
+    }
+}
+
+fn clock_minutes(clock: Clock) -> u32 {
+    let hour = clock.meridiem.map_or(clock.hour, |m| to_24h(clock.hour, m));
+    hour as u32 * 60 + clock.minute as u32
+}
+
+/// Give an unmarked side of a range the other side's am/pm, flipping it if
+/// that would put the start after the end ("11-1pm" is 11:00-13:00)
+fn infer_meridiem(start: Clock, end: Clock) -> (Clock, Clock) {
+    let fill = |clock: Clock, meridiem: Meridiem| -> [Clock; 2] {
+        let flipped = match meridiem {
+            Meridiem::Am => Meridiem::Pm,
+            Meridiem::Pm => Meridiem::Am,
+        };
+        [
+            Clock {
+                meridiem: Some(meridiem),
+                ..clock
+            },
+            Clock {
+                meridiem: Some(flipped),
+                ..clock
+            },
+        ]
+    };
+
+    match (start.meridiem, end.meridiem) {
+        (None, Some(meridiem)) if (1..=12).contains(&start.hour) => {
+            let [same, flipped] = fill(start, meridiem);
+            if clock_minutes(same) <= clock_minutes(end) {
+                (same, end)
+            } else {
+                (flipped, end)
+            }
+        }
+        (Some(meridiem), None) if (1..=12).contains(&end.hour) => {
+            let [same, flipped] = fill(end, meridiem);
+            if clock_minutes(same) >= clock_minutes(start) {
+                (start, same)
+            } else {
+                (start, flipped)
+            }
+        }
+        _ => (start, end),
+    }
+}
+
+fn to_time_point(minutes: u32) -> Result<TimePoint, String> {
+    TimePoint::from_minutes_since_midnight(minutes % (24 * 60))
+}
+
+/// Turn a span into the record's date and times; duration entries that cross
+/// midnight are dated on the day they started
+fn resolve_span(span: Span, date: Date) -> Result<(Date, TimePoint, TimePoint), String> {
+    match span {
+        Span::Range(start, end) => {
+            let (start, end) = infer_meridiem(start, end);
+            Ok((
+                date,
+                to_time_point(clock_minutes(start))?,
+                to_time_point(clock_minutes(end))?,
+            ))
+        }
+        Span::Duration { minutes, anchor } => {
+            if minutes == 0 || minutes >= 24 * 60 {
+                return Err(format!(
+                    "Duration must be between 1 minute and 24 hours, got {} minutes",
+                    minutes
+                ));
+            }
+            let at = |clock: Clock| -> Result<PrimitiveDateTime, String> {
+                let total = clock_minutes(clock);
+                let time = Time::from_hms((total / 60) as u8, (total % 60) as u8, 0)
+                    .map_err(|e| e.to_string())?;
+                Ok(PrimitiveDateTime::new(date, time))
+            };
+            let length = Duration::minutes(minutes as i64);
+            let (start, end) = match anchor {
+                Anchor::End(clock) => {
+                    let end = at(clock)?;
+                    (end - length, end)
+                }
+                Anchor::Start(clock) => {
+                    let start = at(clock)?;
+                    (start, start + length)
+                }
+            };
+            Ok((
+                start.date(),
+                TimePoint::new(start.hour(), start.minute())?,
+                TimePoint::new(end.hour(), end.minute())?,
+            ))
+        }
+    }
+}
+
+fn build_name(tokens: &[String]) -> String {
+    let start = tokens
+        .iter()
+        .position(|t| !NAME_FILLERS.contains(&t.to_lowercase().as_str()))
+        .unwrap_or(tokens.len());
+    tokens[start..].join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{date, datetime};
+
+    // Saturday 2026-10-17, 16:45
+    const NOW: PrimitiveDateTime = datetime!(2026-10-17 16:45);
+
+    fn parse(input: &str) -> Result<QuickEntry, String> {
+        QuickEntry::parse(input, NOW, NOW.date())
+    }
+
+    fn tp(hour: u8, minute: u8) -> TimePoint {
+        TimePoint::new(hour, minute).unwrap()
+    }
+
+    #[test]
+    fn test_range_with_relative_date() {
+        let entry = parse("yesterday 2pm-3:30pm PROJ-42 code review").unwrap();
+        assert_eq!(entry.date, date!(2026 - 10 - 16));
+        assert_eq!(entry.name, "PROJ-42 code review");
+        assert_eq!(entry.start, tp(14, 0));
+        assert_eq!(entry.end, tp(15, 30));
+    }
+
+    #[test]
+    fn test_duration_ending_now() {
+        let entry = parse("1h30m on standup ending now").unwrap();
+        assert_eq!(entry.date, NOW.date());
+        assert_eq!(entry.name, "standup");
+        assert_eq!(entry.start, tp(15, 15));
+        assert_eq!(entry.end, tp(16, 45));
+    }
+
+    #[test]
+    fn test_duration_defaults_to_ending_now() {
+        let entry = parse("45m emails").unwrap();
+        assert_eq!(entry.start, tp(16, 0));
+        assert_eq!(entry.end, tp(16, 45));
+    }
+
+    #[test]
+    fn test_duration_with_start_anchor() {
+        let entry = parse("planning for 1.5h starting 9am").unwrap();
+        assert_eq!(entry.name, "planning");
+        assert_eq!(entry.start, tp(9, 0));
+        assert_eq!(entry.end, tp(10, 30));
+    }
+
+    #[test]
+    fn test_parse_offset() {
+        assert_eq!(parse_offset("+15"), Some(15));
+        assert_eq!(parse_offset("15"), Some(15));
+        assert_eq!(parse_offset("-15m"), Some(-15));
+        assert_eq!(parse_offset(" -1h30m "), Some(-90));
+        assert_eq!(parse_offset("+2h"), Some(120));
+        assert_eq!(parse_offset("+"), None);
+        assert_eq!(parse_offset("soon"), None);
+        assert_eq!(parse_offset("--5"), None);
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let today = NOW.date();
+        assert_eq!(parse_date("2026-10-01", today), Ok(date!(2026 - 10 - 01)));
+        assert_eq!(parse_date("yesterday", today), Ok(date!(2026 - 10 - 16)));
+        assert_eq!(parse_date("last fri", today), Ok(date!(2026 - 10 - 16)));
+        assert_eq!(parse_date("  ", today), Ok(today));
+        assert!(parse_date("2026-13-01", today).is_err());
+        assert!(parse_date("today standup", today).is_err());
+        assert!(parse_date("soon", today).is_err());
+    }
+
+    #[test]
+    fn test_split_duration_tokens() {
+        let entry = parse("2h 15m deep work ending 12:00").unwrap();
+        assert_eq!(entry.name, "deep work");
+        assert_eq!(entry.start, tp(9, 45));
+        assert_eq!(entry.end, tp(12, 0));
+    }
+
+    #[test]
+    fn test_spaced_and_worded_ranges() {
+        let entry = parse("14:00 - 15:30 Sync").unwrap();
+        assert_eq!((entry.start, entry.end), (tp(14, 0), tp(15, 30)));
+
+        let entry = parse("Workshop from 9 to 11am").unwrap();
+        assert_eq!(entry.name, "Workshop");
+        assert_eq!((entry.start, entry.end), (tp(9, 0), tp(11, 0)));
+
+        let entry = parse("2 pm - 3 pm Call").unwrap();
+        assert_eq!((entry.start, entry.end), (tp(14, 0), tp(15, 0)));
+    }
+
+    #[test]
+    fn test_meridiem_inference() {
+        let entry = parse("2-3:30pm Review").unwrap();
+        assert_eq!((entry.start, entry.end), (tp(14, 0), tp(15, 30)));
+
+        let entry = parse("11-1pm Lunch").unwrap();
+        assert_eq!((entry.start, entry.end), (tp(11, 0), tp(13, 0)));
+
+        let entry = parse("9am-12 Focus").unwrap();
+        assert_eq!((entry.start, entry.end), (tp(9, 0), tp(12, 0)));
+
+        let entry = parse("12am-1am Deploy").unwrap();
+        assert_eq!((entry.start, entry.end), (tp(0, 0), tp(1, 0)));
+    }
+
+    #[test]
+    fn test_dates() {
+        let entry = parse("2026-10-01 9:00-10:00 Task").unwrap();
+        assert_eq!(entry.date, date!(2026 - 10 - 01));
+
+        let entry = parse("9:00-10:00 Task today").unwrap();
+        assert_eq!(entry.date, NOW.date());
+        assert_eq!(entry.name, "Task");
+
+        // NOW is a Saturday
+        let entry = parse("monday 9:00-10:00 Task").unwrap();
+        assert_eq!(entry.date, date!(2026 - 10 - 12));
+        let entry = parse("saturday 9:00-10:00 Task").unwrap();
+        assert_eq!(entry.date, NOW.date());
+        let entry = parse("last saturday 9:00-10:00 Task").unwrap();
+        assert_eq!(entry.date, date!(2026 - 10 - 10));
+
+        // Weekdays later in the text belong to the name
+        let entry = parse("9:00-10:00 Monday sync").unwrap();
+        assert_eq!(entry.date, NOW.date());
+        assert_eq!(entry.name, "Monday sync");
+    }
+
+    #[test]
+    fn test_default_date() {
+        let entry = QuickEntry::parse("9:00-10:00 Task", NOW, date!(2026 - 10 - 01)).unwrap();
+        assert_eq!(entry.date, date!(2026 - 10 - 01));
+    }
+
+    #[test]
+    fn test_duration_crossing_midnight_uses_start_date() {
+        let now = datetime!(2026-10-17 00:30);
+        let entry = QuickEntry::parse("2h Incident", now, now.date()).unwrap();
+        assert_eq!(entry.date, date!(2026 - 10 - 16));
+        assert_eq!((entry.start, entry.end), (tp(22, 30), tp(0, 30)));
+    }
+
+    #[test]
+    fn test_numbers_in_name_are_kept() {
+        let entry = parse("1h Review PR 42").unwrap();
+        assert_eq!(entry.name, "Review PR 42");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse("").is_err());
+        assert!(parse("code review").is_err());
+        assert!(parse("2pm-3pm").is_err());
+        assert!(parse("0m Nothing").is_err());
+        assert!(parse("25h Too long").is_err());
+        assert!(parse("2026-13-45 1h Task").is_err());
+    }
+
+    #[test]
+    fn test_into_record() {
+        let record = parse("2pm-3:30pm Review").unwrap().into_record(7);
+        assert_eq!(record.id, 7);
+        assert_eq!(record.name, "Review");
+        assert_eq!(record.total_minutes, 90);
+    }
+}
@@ -0,0 +1,135 @@
+use super::{DayData, WorkRecord};
+use serde::Serialize;
+use time::Date;
+
+/// Words to look for in task names and descriptions
+///
+/// Matching ignores case and every word has to appear somewhere in the record. A word
+/// ending in a digit only matches whole numbers, so `PROJ-88` finds `PROJ-88` but not
+/// `PROJ-881`, and `#12` doesn't find `#123`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchQuery {
+    terms: Vec<String>,
+}
+
+/// A record found by a search, with its day
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub date: Date,
+    #[serde(flatten)]
+    pub record: WorkRecord,
+}
+
+impl SearchQuery {
+    pub fn parse(query: &str) -> Self {
+        SearchQuery {
+            terms: query.split_whitespace().map(str::to_lowercase).collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn matches(&self, record: &WorkRecord) -> bool {
+        let name = record.name.to_lowercase();
+        let description = record.description.to_lowercase();
+        self.terms
+            .iter()
+            .all(|term| contains_term(&name, term) || contains_term(&description, term))
+    }
+
+    /// Matching records of `days`, newest first
+    pub fn search(&self, days: &[DayData]) -> Vec<SearchHit> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+
+        let mut hits: Vec<SearchHit> = days
+            .iter()
+            .flat_map(|day| {
+                day.work_records
+                    .values()
+                    .filter(|record| self.matches(record))
+                    .map(|record| SearchHit {
+                        date: day.date,
+                        record: record.clone(),
+                    })
+            })
+            .collect();
+        hits.sort_by(|a, b| {
+            b.date
+                .cmp(&a.date)
+                .then(b.record.start.cmp(&a.record.start))
+        });
+        hits
+    }
+}
+
+fn contains_term(text: &str, term: &str) -> bool {
+    if !term.ends_with(|c: char| c.is_ascii_digit()) {
+        return text.contains(term);
+    }
+    text.match_indices(term)
+        .any(|(start, _)| !text[start + term.len()..].starts_with(|c: char| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TimePoint;
+    use time::macros::date;
+
+    fn record(id: u32, name: &str, start: &str, description: &str) -> WorkRecord {
+        let start = TimePoint::parse(start).unwrap();
+        let end =
+            TimePoint::from_minutes_since_midnight(start.to_minutes_since_midnight() + 30).unwrap();
+        let mut record = WorkRecord::new(id, name.to_string(), start, end);
+        record.description = description.to_string();
+        record
+    }
+
+    fn days() -> Vec<DayData> {
+        let mut first = DayData::new(date!(2026 - 10 - 12));
+        first.add_record(record(1, "PROJ-88 Login form", "09:00", "Validation"));
+        first.add_record(record(2, "PROJ-881 Logout", "10:00", ""));
+        let mut second = DayData::new(date!(2026 - 10 - 14));
+        second.add_record(record(1, "Code review", "09:00", "PR for proj-88"));
+        second.add_record(record(2, "PROJ-88 Login form", "14:00", ""));
+        second.add_record(record(3, "Standup", "08:30", ""));
+        vec![first, second]
+    }
+
+    #[test]
+    fn test_terms_match_name_or_description_ignoring_case() {
+        let query = SearchQuery::parse("login VALIDATION");
+        assert!(query.matches(&record(1, "PROJ-88 Login form", "09:00", "Validation")));
+        assert!(!query.matches(&record(1, "PROJ-88 Login form", "09:00", "")));
+        assert!(SearchQuery::parse("  ").is_empty());
+    }
+
+    #[test]
+    fn test_numbers_match_whole() {
+        let query = SearchQuery::parse("PROJ-88");
+        assert!(query.matches(&record(1, "PROJ-88: Login", "09:00", "")));
+        assert!(!query.matches(&record(1, "PROJ-881 Logout", "09:00", "")));
+        assert!(SearchQuery::parse("PROJ-8").matches(&record(1, "PROJ-8", "09:00", "")));
+        assert!(SearchQuery::parse("88").matches(&record(1, "PROJ-88", "09:00", "")));
+        assert!(SearchQuery::parse("log").matches(&record(1, "Logout", "09:00", "")));
+    }
+
+    #[test]
+    fn test_search_lists_newest_first() {
+        let hits = SearchQuery::parse("proj-88").search(&days());
+        let found: Vec<(Date, u32)> = hits.iter().map(|h| (h.date, h.record.id)).collect();
+        assert_eq!(
+            found,
+            vec![
+                (date!(2026 - 10 - 14), 2),
+                (date!(2026 - 10 - 14), 1),
+                (date!(2026 - 10 - 12), 1),
+            ]
+        );
+        assert!(SearchQuery::parse("").search(&days()).is_empty());
+    }
+}
@@ -33,6 +33,14 @@ impl TimePoint {
         (self.hour as u32) * 60 + (self.minute as u32)
     }
 
+    /// The time `minutes` later (earlier if negative), if that's still on the same day
+    pub fn shifted(self, minutes: i32) -> Option<Self> {
+        let shifted = self.to_minutes_since_midnight() as i64 + minutes as i64;
+        u32::try_from(shifted)
+            .ok()
+            .and_then(|minutes| Self::from_minutes_since_midnight(minutes).ok())
+    }
+
     pub fn parse(s: &str) -> Result<Self, String> {
         let parts: Vec<&str> = s.split(':').collect();
         if parts.len() != 2 {
@@ -201,6 +209,20 @@ mod tests {
         assert_eq!(time2, time3);
     }
 
+    #[test]
+    fn test_shifted() {
+        let time = TimePoint::new(9, 45).unwrap();
+        assert_eq!(time.shifted(30), Some(TimePoint::new(10, 15).unwrap()));
+        assert_eq!(time.shifted(-45), Some(TimePoint::new(9, 0).unwrap()));
+        assert_eq!(time.shifted(-585), Some(TimePoint::new(0, 0).unwrap()));
+        assert_eq!(time.shifted(-586), None);
+        assert_eq!(
+            time.shifted(14 * 60 + 14),
+            Some(TimePoint::new(23, 59).unwrap())
+        );
+        assert_eq!(time.shifted(14 * 60 + 15), None);
+    }
+
     #[test]
     fn test_clone_and_copy() {
         let time1 = TimePoint::new(14, 30).unwrap();
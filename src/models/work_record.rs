@@ -10,6 +10,12 @@ pub struct WorkRecord {
     pub total_minutes: u32,
     #[serde(default)]
     pub description: String,
+    /// ID of the worklog this record was pushed to in the issue tracker, if synced
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worklog_id: Option<String>,
+    /// Tracker and ticket the worklog was pushed to, as `tracker:TICKET`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worklog_ticket: Option<String>,
 }
 
 impl WorkRecord {
@@ -22,6 +28,8 @@ impl WorkRecord {
             end,
             total_minutes,
             description: String::new(),
+            worklog_id: None,
+            worklog_ticket: None,
         }
     }
 
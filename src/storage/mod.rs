@@ -1,8 +1,12 @@
+use crate::config::HookConfig;
+use crate::integrations::assigned::AssignedIssues;
 use crate::models::{DayData, WorkRecord};
 use crate::timer::TimerState;
 use anyhow::{Context, Result};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender};
 use std::time::SystemTime;
 use time::Date;
 
@@ -16,6 +20,9 @@ pub struct Storage {
 pub struct StorageManager {
     storage: Storage,
     file_modified_times: std::collections::HashMap<Date, Option<SystemTime>>,
+    hooks: HookConfig,
+    /// Failures of hooks running in the background, see `with_hooks`
+    hook_errors: (Sender<String>, Receiver<String>),
 }
 
 impl StorageManager {
@@ -24,6 +31,8 @@ impl StorageManager {
         Ok(StorageManager {
             storage: Storage::new()?,
             file_modified_times: std::collections::HashMap::new(),
+            hooks: HookConfig::default(),
+            hook_errors: mpsc::channel(),
         })
     }
 
@@ -34,9 +43,34 @@ impl StorageManager {
         Ok(StorageManager {
             storage: Storage::new_with_dir(data_dir)?,
             file_modified_times: std::collections::HashMap::new(),
+            hooks: HookConfig::default(),
+            hook_errors: mpsc::channel(),
         })
     }
 
+    /// Create a StorageManager on top of an existing Storage
+    pub fn from_storage(storage: Storage) -> Self {
+        StorageManager {
+            storage,
+            file_modified_times: std::collections::HashMap::new(),
+            hooks: HookConfig::default(),
+            hook_errors: mpsc::channel(),
+        }
+    }
+
+    /// Set the session hooks run by timer operations
+    ///
+    /// They run in the background; their failures are collected by `hook_failures`.
+    pub fn with_hooks(mut self, hooks: HookConfig) -> Self {
+        self.hooks = hooks;
+        self
+    }
+
+    /// Failures of hooks that finished since the last call
+    pub fn hook_failures(&self) -> Vec<String> {
+        self.hook_errors.1.try_iter().collect()
+    }
+
     /// Load day data with automatic file modification tracking
     /// Returns the loaded data and updates internal tracking
     pub fn load_with_tracking(&mut self, date: Date) -> Result<DayData> {
@@ -46,6 +80,16 @@ impl StorageManager {
         Ok(data)
     }
 
+    /// Load the days from `from` to `to` (inclusive) that have records, without tracking
+    pub fn load_range(&self, from: Date, to: Date) -> Result<Vec<DayData>> {
+        self.storage.load_range(from, to)
+    }
+
+    /// Load every stored day that has records, oldest first
+    pub fn load_all(&self) -> Result<(Vec<DayData>, Vec<String>)> {
+        self.storage.load_all()
+    }
+
     /// Check if file has been modified externally and reload if needed
     /// Returns Some(DayData) if file was modified and reloaded, None if no change
     pub fn check_and_reload(&mut self, date: Date) -> Result<Option<DayData>> {
@@ -74,7 +118,6 @@ impl StorageManager {
     }
 
     /// Add a new work record (transactional: load → add → save → track)
-    #[allow(dead_code)]
     pub fn add_record(&mut self, date: Date, record: WorkRecord) -> Result<()> {
         let mut day_data = self.storage.load(&date)?;
         day_data.add_record(record);
@@ -88,7 +131,6 @@ impl StorageManager {
     }
 
     /// Update an existing work record (transactional: load → update → save → track)
-    #[allow(dead_code)]
     pub fn update_record(&mut self, date: Date, record: WorkRecord) -> Result<()> {
         let mut day_data = self.storage.load(&date)?;
 
@@ -106,7 +148,6 @@ impl StorageManager {
 
     /// Remove a work record by ID (transactional: load → remove → save → track)
     /// Returns the removed record if found
-    #[allow(dead_code)]
     pub fn remove_record(&mut self, date: Date, id: u32) -> Result<WorkRecord> {
         let mut day_data = self.storage.load(&date)?;
 
@@ -156,12 +197,32 @@ impl StorageManager {
         self.storage.clear_active_timer()
     }
 
+    /// Pass-through methods for the ticket title cache
+    pub fn load_ticket_titles(&self) -> Result<HashMap<String, String>> {
+        self.storage.load_ticket_titles()
+    }
+
+    pub fn save_ticket_titles(&self, titles: &HashMap<String, String>) -> Result<()> {
+        self.storage.save_ticket_titles(titles)
+    }
+
+    /// Pass-through methods for the assigned issue cache
+    pub fn load_assigned_issues(&self) -> Result<AssignedIssues> {
+        self.storage.load_assigned_issues()
+    }
+
+    pub fn save_assigned_issues(&self, issues: &AssignedIssues) -> Result<()> {
+        self.storage.save_assigned_issues(issues)
+    }
+
     /// Create a TimerManager using the internal storage
     /// This allows timer operations while keeping storage abstraction
     fn create_timer_manager(&self) -> crate::timer::TimerManager {
         // Clone the storage for timer operations
         // This is safe because timer operations are independent
         crate::timer::TimerManager::new(self.storage.clone())
+            .with_hooks(self.hooks.clone())
+            .with_background_hooks(self.hook_errors.0.clone())
     }
 
     /// Start a new timer with the given task name and optional description
@@ -260,6 +321,55 @@ impl Storage {
         Ok(day_data)
     }
 
+    /// Load the days from `from` to `to` (inclusive) that have records
+    pub fn load_range(&self, from: Date, to: Date) -> Result<Vec<DayData>> {
+        let mut days = Vec::new();
+        let mut date = from;
+        while date <= to {
+            let day = self.load(&date)?;
+            if !day.work_records.is_empty() {
+                days.push(day);
+            }
+            date = match date.next_day() {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        Ok(days)
+    }
+
+    /// Load every stored day that has records, oldest first
+    ///
+    /// Files that can't be read are skipped rather than failing the whole load; the second
+    /// list describes each of them.
+    pub fn load_all(&self) -> Result<(Vec<DayData>, Vec<String>)> {
+        let format = time::macros::format_description!("[year]-[month]-[day]");
+        let entries = fs::read_dir(&self.data_dir)
+            .context(format!("Failed to read directory: {:?}", self.data_dir))?;
+
+        let mut dates: Vec<Date> = entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if path.extension()? != "json" {
+                    return None;
+                }
+                Date::parse(path.file_stem()?.to_str()?, &format).ok()
+            })
+            .collect();
+        dates.sort();
+
+        let mut days = Vec::new();
+        let mut skipped = Vec::new();
+        for date in dates {
+            match self.load(&date) {
+                Ok(day) if !day.work_records.is_empty() => days.push(day),
+                Ok(_) => {}
+                Err(e) => skipped.push(format!("{}.json: {:#}", date, e)),
+            }
+        }
+        Ok((days, skipped))
+    }
+
     pub fn save(&self, day_data: &DayData) -> Result<()> {
         let path = self.get_file_path(&day_data.date);
 
@@ -322,6 +432,56 @@ impl Storage {
 
         Ok(())
     }
+
+    /// Load cached ticket titles from ticket_titles.json, keyed by "tracker:ticket"
+    ///
+    /// Returns an empty map if nothing has been cached yet
+    pub fn load_ticket_titles(&self) -> Result<HashMap<String, String>> {
+        let path = self.data_dir.join("ticket_titles.json");
+
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .context(format!("Failed to read ticket title cache: {:?}", path))?;
+        serde_json::from_str(&contents).context("Failed to parse ticket title cache")
+    }
+
+    /// Save cached ticket titles to ticket_titles.json
+    pub fn save_ticket_titles(&self, titles: &HashMap<String, String>) -> Result<()> {
+        let path = self.data_dir.join("ticket_titles.json");
+        let json =
+            serde_json::to_string_pretty(titles).context("Failed to serialize ticket titles")?;
+        fs::write(&path, json)
+            .context(format!("Failed to write ticket title cache: {:?}", path))?;
+        Ok(())
+    }
+
+    /// Load issues assigned to the user from assigned_issues.json, keyed by tracker
+    ///
+    /// Returns an empty map if they haven't been fetched yet
+    pub fn load_assigned_issues(&self) -> Result<AssignedIssues> {
+        let path = self.data_dir.join("assigned_issues.json");
+
+        if !path.exists() {
+            return Ok(AssignedIssues::new());
+        }
+
+        let contents = fs::read_to_string(&path)
+            .context(format!("Failed to read assigned issue cache: {:?}", path))?;
+        serde_json::from_str(&contents).context("Failed to parse assigned issue cache")
+    }
+
+    /// Save issues assigned to the user to assigned_issues.json
+    pub fn save_assigned_issues(&self, issues: &AssignedIssues) -> Result<()> {
+        let path = self.data_dir.join("assigned_issues.json");
+        let json =
+            serde_json::to_string_pretty(issues).context("Failed to serialize assigned issues")?;
+        fs::write(&path, json)
+            .context(format!("Failed to write assigned issue cache: {:?}", path))?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -501,6 +661,56 @@ mod tests {
         assert_eq!(loaded_day2.work_records.get(&1).unwrap().name, "Day2Task");
     }
 
+    #[test]
+    fn test_load_all_and_range_skip_empty_days_and_other_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Storage::new_with_dir(temp_dir.path().to_path_buf()).unwrap();
+
+        let date1 = Date::from_calendar_date(2025, time::Month::November, 5).unwrap();
+        let date2 = Date::from_calendar_date(2025, time::Month::November, 6).unwrap();
+        let date3 = Date::from_calendar_date(2025, time::Month::November, 7).unwrap();
+
+        let mut day3 = DayData::new(date3);
+        day3.add_record(create_test_record(1, "Day3Task"));
+        let mut day1 = DayData::new(date1);
+        day1.add_record(create_test_record(1, "Day1Task"));
+        storage.save(&day3).unwrap();
+        storage.save(&DayData::new(date2)).unwrap();
+        storage.save(&day1).unwrap();
+        storage.save_ticket_titles(&HashMap::new()).unwrap();
+
+        let (days, skipped) = storage.load_all().unwrap();
+        let dates: Vec<Date> = days.iter().map(|d| d.date).collect();
+        assert_eq!(dates, vec![date1, date3]);
+        assert!(skipped.is_empty());
+
+        let dates: Vec<Date> = storage
+            .load_range(date2, date3)
+            .unwrap()
+            .iter()
+            .map(|d| d.date)
+            .collect();
+        assert_eq!(dates, vec![date3]);
+    }
+
+    #[test]
+    fn test_load_all_skips_corrupt_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Storage::new_with_dir(temp_dir.path().to_path_buf()).unwrap();
+
+        let date = Date::from_calendar_date(2025, time::Month::November, 5).unwrap();
+        let mut day = DayData::new(date);
+        day.add_record(create_test_record(1, "Task"));
+        storage.save(&day).unwrap();
+        fs::write(temp_dir.path().join("2025-11-06.json"), "{ not json").unwrap();
+
+        let (days, skipped) = storage.load_all().unwrap();
+        assert_eq!(days.len(), 1);
+        assert_eq!(days[0].date, date);
+        assert_eq!(skipped.len(), 1);
+        assert!(skipped[0].starts_with("2025-11-06.json: Failed to parse JSON"));
+    }
+
     #[test]
     fn test_json_format_is_pretty() {
         let temp_dir = TempDir::new().unwrap();
@@ -520,6 +730,40 @@ mod tests {
         assert!(contents.contains("  ")); // Indentation
     }
 
+    #[test]
+    fn test_save_and_load_ticket_titles() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Storage::new_with_dir(temp_dir.path().to_path_buf()).unwrap();
+        assert!(storage.load_ticket_titles().unwrap().is_empty());
+
+        let mut titles = HashMap::new();
+        titles.insert("jira:PROJ-1".to_string(), "Fix login".to_string());
+        storage.save_ticket_titles(&titles).unwrap();
+
+        assert_eq!(storage.load_ticket_titles().unwrap(), titles);
+    }
+
+    #[test]
+    fn test_save_and_load_assigned_issues() {
+        use crate::integrations::sync::Issue;
+
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Storage::new_with_dir(temp_dir.path().to_path_buf()).unwrap();
+        assert!(storage.load_assigned_issues().unwrap().is_empty());
+
+        let mut issues = AssignedIssues::new();
+        issues.insert(
+            "jira".to_string(),
+            vec![Issue {
+                ticket: "PROJ-1".to_string(),
+                title: "Fix login".to_string(),
+            }],
+        );
+        storage.save_assigned_issues(&issues).unwrap();
+
+        assert_eq!(storage.load_assigned_issues().unwrap(), issues);
+    }
+
     #[test]
     fn test_load_active_timer_returns_none_when_not_exists() {
         let temp_dir = TempDir::new().unwrap();
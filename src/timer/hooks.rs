@@ -0,0 +1,241 @@
+//! Session hooks
+//!
+//! Runs user-defined shell commands when a timer session changes state, so external
+//! tools (chat status, do-not-disturb, custom dashboards) can react without
+//! work-tuimer knowing anything about them.
+
+use super::TimerState;
+use crate::config::HookConfig;
+use anyhow::{Context, Result, anyhow};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Timer lifecycle event that can trigger a hook
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    Start,
+    Stop,
+    Pause,
+    Resume,
+}
+
+impl HookEvent {
+    /// Name passed to hooks in the `WORK_TUIMER_EVENT` environment variable
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HookEvent::Start => "start",
+            HookEvent::Stop => "stop",
+            HookEvent::Pause => "pause",
+            HookEvent::Resume => "resume",
+        }
+    }
+
+    /// Get the configured command for this event, if any
+    fn command(self, hooks: &HookConfig) -> Option<&str> {
+        let command = match self {
+            HookEvent::Start => hooks.on_start.as_deref(),
+            HookEvent::Stop => hooks.on_stop.as_deref(),
+            HookEvent::Pause => hooks.on_pause.as_deref(),
+            HookEvent::Resume => hooks.on_resume.as_deref(),
+        };
+        command.filter(|c| !c.trim().is_empty())
+    }
+}
+
+/// Run the hook configured for `event`, if any
+///
+/// The timer state is written to the command's stdin as JSON and summarized in
+/// `WORK_TUIMER_*` environment variables. The command runs through the platform
+/// shell and is waited on; the TUI calls this from a background thread.
+/// Output is discarded to keep the TUI clean.
+///
+/// Returns Ok(false) if no hook is configured for the event.
+pub fn run_hook(hooks: &HookConfig, event: HookEvent, timer: &TimerState) -> Result<bool> {
+    let Some(command) = event.command(hooks) else {
+        return Ok(false);
+    };
+
+    let payload = serde_json::to_string(timer).context("Failed to serialize timer for hook")?;
+
+    let mut child = shell_command(command)
+        .env("WORK_TUIMER_EVENT", event.as_str())
+        .env("WORK_TUIMER_TASK", &timer.task_name)
+        .env(
+            "WORK_TUIMER_DESCRIPTION",
+            timer.description.as_deref().unwrap_or(""),
+        )
+        .env("WORK_TUIMER_STATUS", status_name(timer))
+        .env(
+            "WORK_TUIMER_START_TIME",
+            timer.start_time.unix_timestamp().to_string(),
+        )
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .context(format!(
+            "Failed to run {} hook: {}",
+            event.as_str(),
+            command
+        ))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that ignores stdin may exit before reading it; that's not an error
+        let _ = stdin.write_all(payload.as_bytes());
+    }
+
+    let status = child
+        .wait()
+        .context(format!("Failed to wait for {} hook", event.as_str()))?;
+
+    if !status.success() {
+        return Err(anyhow!(
+            "{} hook exited with {}: {}",
+            event.as_str(),
+            status,
+            command
+        ));
+    }
+
+    Ok(true)
+}
+
+fn status_name(timer: &TimerState) -> &'static str {
+    match timer.status {
+        super::TimerStatus::Running => "running",
+        super::TimerStatus::Paused => "paused",
+        super::TimerStatus::Stopped => "stopped",
+    }
+}
+
+#[cfg(unix)]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(command);
+    cmd
+}
+
+#[cfg(windows)]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C").arg(command);
+    cmd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timer::TimerStatus;
+    use tempfile::TempDir;
+    use time::OffsetDateTime;
+
+    fn create_test_timer() -> TimerState {
+        let now = OffsetDateTime::now_utc();
+        TimerState {
+            id: None,
+            task_name: "Hooked Task".to_string(),
+            description: Some("With description".to_string()),
+            start_time: now,
+            end_time: None,
+            date: now.date(),
+            status: TimerStatus::Running,
+            paused_duration_secs: 0,
+            paused_at: None,
+            created_at: now,
+            updated_at: now,
+            source_record_id: None,
+            source_record_date: None,
+        }
+    }
+
+    #[test]
+    fn test_event_names() {
+        assert_eq!(HookEvent::Start.as_str(), "start");
+        assert_eq!(HookEvent::Stop.as_str(), "stop");
+        assert_eq!(HookEvent::Pause.as_str(), "pause");
+        assert_eq!(HookEvent::Resume.as_str(), "resume");
+    }
+
+    #[test]
+    fn test_no_hook_configured() {
+        let hooks = HookConfig::default();
+        let ran = run_hook(&hooks, HookEvent::Start, &create_test_timer()).unwrap();
+        assert!(!ran);
+    }
+
+    #[test]
+    fn test_blank_hook_is_ignored() {
+        let hooks = HookConfig {
+            on_start: Some("   ".to_string()),
+            ..Default::default()
+        };
+        let ran = run_hook(&hooks, HookEvent::Start, &create_test_timer()).unwrap();
+        assert!(!ran);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hook_receives_json_on_stdin() {
+        let temp_dir = TempDir::new().unwrap();
+        let output = temp_dir.path().join("stdin.json");
+        let hooks = HookConfig {
+            on_start: Some(format!("cat > '{}'", output.display())),
+            ..Default::default()
+        };
+
+        let ran = run_hook(&hooks, HookEvent::Start, &create_test_timer()).unwrap();
+        assert!(ran);
+
+        let contents = std::fs::read_to_string(&output).unwrap();
+        let received: TimerState = serde_json::from_str(&contents).unwrap();
+        assert_eq!(received.task_name, "Hooked Task");
+        assert_eq!(received.status, TimerStatus::Running);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_hook_receives_env_vars() {
+        let temp_dir = TempDir::new().unwrap();
+        let output = temp_dir.path().join("env.txt");
+        let hooks = HookConfig {
+            on_pause: Some(format!(
+                "echo \"$WORK_TUIMER_EVENT|$WORK_TUIMER_TASK|$WORK_TUIMER_DESCRIPTION|$WORK_TUIMER_STATUS\" > '{}'",
+                output.display()
+            )),
+            ..Default::default()
+        };
+
+        let mut timer = create_test_timer();
+        timer.status = TimerStatus::Paused;
+        run_hook(&hooks, HookEvent::Pause, &timer).unwrap();
+
+        let contents = std::fs::read_to_string(&output).unwrap();
+        assert_eq!(contents.trim(), "pause|Hooked Task|With description|paused");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_failing_hook_returns_error() {
+        let hooks = HookConfig {
+            on_stop: Some("exit 3".to_string()),
+            ..Default::default()
+        };
+        let result = run_hook(&hooks, HookEvent::Stop, &create_test_timer());
+        assert!(result.is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_only_matching_event_runs() {
+        let temp_dir = TempDir::new().unwrap();
+        let output = temp_dir.path().join("ran.txt");
+        let hooks = HookConfig {
+            on_resume: Some(format!("touch '{}'", output.display())),
+            ..Default::default()
+        };
+
+        let ran = run_hook(&hooks, HookEvent::Start, &create_test_timer()).unwrap();
+        assert!(!ran);
+        assert!(!output.exists());
+    }
+}
@@ -4,10 +4,17 @@
 //! Timers can be started, paused, resumed, and stopped, with automatic conversion
 //! to WorkRecord upon completion.
 
+mod hooks;
+
+pub use hooks::{HookEvent, run_hook};
+
+use crate::config::HookConfig;
 use crate::models::{TimePoint, WorkRecord};
 use crate::storage::Storage;
 use anyhow::{Context, Result, anyhow};
 use serde::{Deserialize, Serialize};
+use std::sync::mpsc::Sender;
+use std::thread;
 use std::time::Duration as StdDuration;
 use time::{Date, OffsetDateTime};
 
@@ -76,13 +83,58 @@ pub struct TimerState {
 /// query their current status. Manages persistence through the StorageManager layer.
 pub struct TimerManager {
     storage: Storage,
+    hooks: HookConfig,
+    /// Where hooks running in the background report failures; hooks are waited on without it
+    hook_errors: Option<Sender<String>>,
 }
 
 impl TimerManager {
     /// Create a new timer manager with low-level Storage
     /// For internal use - external callers should use storage::StorageManager instead
     pub fn new(storage: Storage) -> Self {
-        TimerManager { storage }
+        TimerManager {
+            storage,
+            hooks: HookConfig::default(),
+            hook_errors: None,
+        }
+    }
+
+    /// Set the hook commands run on start/stop/pause/resume
+    pub fn with_hooks(mut self, hooks: HookConfig) -> Self {
+        self.hooks = hooks;
+        self
+    }
+
+    /// Run hooks on a background thread and send their failures to `errors`
+    ///
+    /// For the TUI, so a slow hook doesn't freeze it.
+    pub fn with_background_hooks(mut self, errors: Sender<String>) -> Self {
+        self.hook_errors = Some(errors);
+        self
+    }
+
+    /// Run the hook for an event after the timer state has been persisted
+    ///
+    /// A broken hook script must never prevent time from being tracked, so failures
+    /// are reported rather than returned.
+    fn fire_hook(&self, event: HookEvent, timer: &TimerState) {
+        match &self.hook_errors {
+            Some(errors) => {
+                let hooks = self.hooks.clone();
+                let timer = timer.clone();
+                let errors = errors.clone();
+                thread::spawn(move || {
+                    if let Err(e) = run_hook(&hooks, event, &timer) {
+                        let _ = errors.send(format!("{:#}", e));
+                    }
+                });
+            }
+            None => {
+                if let Err(e) = run_hook(&self.hooks, event, timer) {
+                    eprintln!("Warning: {:#}", e);
+                }
+            }
+        }
     }
 
     /// Start a new timer
@@ -120,6 +172,7 @@ impl TimerManager {
         };
 
         self.storage.save_active_timer(&timer)?;
+        self.fire_hook(HookEvent::Start, &timer);
         Ok(timer)
     }
 
@@ -178,6 +231,7 @@ impl TimerManager {
 
         self.storage.save(&day_data)?;
         self.storage.clear_active_timer()?;
+        self.fire_hook(HookEvent::Stop, &timer);
 
         // Return a work record for the stopped timer (for display purposes)
         let work_record = self.to_work_record(timer)?;
@@ -209,6 +263,7 @@ impl TimerManager {
         timer.updated_at = now;
 
         self.storage.save_active_timer(&timer)?;
+        self.fire_hook(HookEvent::Pause, &timer);
         Ok(timer)
     }
 
@@ -240,6 +295,7 @@ impl TimerManager {
         timer.updated_at = now;
 
         self.storage.save_active_timer(&timer)?;
+        self.fire_hook(HookEvent::Resume, &timer);
         Ok(timer)
     }
 
@@ -606,6 +662,82 @@ mod tests {
         assert!(elapsed.as_secs() < 2);
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_hooks_fire_on_lifecycle_events() {
+        let (storage, temp) = create_test_storage();
+        let log = temp.path().join("hooks.log");
+        let append = |event: &str| {
+            Some(format!(
+                "echo \"{}:$WORK_TUIMER_TASK\" >> '{}'",
+                event,
+                log.display()
+            ))
+        };
+        let hooks = HookConfig {
+            on_start: append("start"),
+            on_stop: append("stop"),
+            on_pause: append("pause"),
+            on_resume: append("resume"),
+        };
+        let manager = TimerManager::new(storage).with_hooks(hooks);
+
+        manager
+            .start("Hooked".to_string(), None, None, None)
+            .unwrap();
+        manager.pause().unwrap();
+        manager.resume().unwrap();
+        manager.stop().unwrap();
+
+        let contents = std::fs::read_to_string(&log).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "start:Hooked",
+                "pause:Hooked",
+                "resume:Hooked",
+                "stop:Hooked"
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_failing_hook_does_not_block_timer() {
+        let (storage, _temp) = create_test_storage();
+        let hooks = HookConfig {
+            on_start: Some("exit 1".to_string()),
+            ..Default::default()
+        };
+        let manager = TimerManager::new(storage).with_hooks(hooks);
+
+        let result = manager.start("Work".to_string(), None, None, None);
+        assert!(result.is_ok());
+        assert!(manager.status().unwrap().is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_background_hooks_report_failures_without_waiting() {
+        let (storage, _temp) = create_test_storage();
+        let hooks = HookConfig {
+            on_start: Some("sleep 2; exit 4".to_string()),
+            ..Default::default()
+        };
+        let (errors, failures) = std::sync::mpsc::channel();
+        let manager = TimerManager::new(storage)
+            .with_hooks(hooks)
+            .with_background_hooks(errors);
+
+        let started = std::time::Instant::now();
+        manager.start("Work".to_string(), None, None, None).unwrap();
+        assert!(started.elapsed() < StdDuration::from_secs(1));
+
+        let failure = failures.recv_timeout(StdDuration::from_secs(10)).unwrap();
+        assert!(failure.starts_with("start hook exited"), "{}", failure);
+    }
+
     #[test]
     fn test_stop_updates_existing_record() {
         use crate::models::DayData;
@@ -1,9 +1,21 @@
-use super::history::History;
+use super::command_line::ExCommand;
+use super::filter::{RecordFilter, sort_records};
+use super::history::{DayChange, Snapshot, UndoHistory};
+use super::stats::{RANGE_PRESETS, Stats};
 use crate::config::{Config, Theme};
-use crate::models::{DayData, WorkRecord};
+use crate::integrations::assigned::AssignedIssues;
+use crate::models::{Boundary, DayData, SearchHit, SearchQuery, TimePoint, WorkRecord};
 use crate::timer::TimerState;
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
 use time::Date;
 
+/// Minutes `*` switches the nudge step between
+const NUDGE_STEPS: [u32; 3] = [1, 5, 15];
+
+/// Largest count accepted before a browse key
+const MAX_COUNT: u32 = 9999;
+
 pub enum AppMode {
     Browse,
     Edit,
@@ -11,6 +23,13 @@ pub enum AppMode {
     CommandPalette,
     Calendar,
     TaskPicker,
+    QuickAdd,
+    Stats,
+    Search,
+    Filter,
+    BulkEdit,
+    Split,
+    CommandLine,
 }
 
 pub enum EditField {
@@ -20,6 +39,48 @@ pub enum EditField {
     Description,
 }
 
+/// What the task picker's selection is applied to
+pub enum TaskPickerTarget {
+    /// Rename the selected record
+    Rename,
+    /// Add a new record with the picked name
+    NewRecord,
+    /// Rename these records (a visual selection)
+    RenameRecords(Vec<u32>),
+}
+
+/// What the bulk edit prompt sets on the records of a visual selection
+pub enum BulkEditField {
+    Description,
+    /// Move the records earlier or later
+    Shift,
+}
+
+/// What picking a date in the calendar does
+pub enum CalendarTarget {
+    /// Go to the date
+    Navigate,
+    /// Move these records of the viewed day to the date
+    MoveRecords(Vec<u32>),
+}
+
+/// A browse-mode change `.` repeats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    /// `dd`, the count is the number of records
+    Delete,
+    /// `+`/`-`, the count multiplies the steps
+    Nudge(i32),
+    /// `J`, the count is the number of joins
+    MergeWithNext,
+    /// `p`, `n` and `b`, the count is the number of copies or records
+    Paste,
+    AddRecord,
+    AddBreak,
+    /// `t`, which takes no count
+    SetCurrentTime,
+}
+
 pub struct Command {
     pub key: &'static str,
     pub description: &'static str,
@@ -44,14 +105,46 @@ pub enum CommandAction {
     Save,
     StartTimer,
     PauseTimer,
+    QuickAdd,
+    PickIssue,
+    Stats,
+    Search,
+    Filter,
+    Sort,
+    Yank,
+    Paste,
+    DuplicatePreviousDay,
+    MoveToDate,
+    Split,
+    MergeWithNext,
+    Timeline,
+    NudgeLater,
+    NudgeEarlier,
+    NudgeStep,
     Quit,
 }
 
+/// Result of a tracker request made off the UI thread
+enum Fetched {
+    /// Title lookup for the name `input` being edited, with the updated title cache
+    Title {
+        input: String,
+        result: Result<Option<String>, String>,
+        titles: HashMap<String, String>,
+    },
+    /// Assigned issues from all trackers, cached ones kept where a request failed
+    AssignedIssues {
+        issues: AssignedIssues,
+        errors: Vec<String>,
+    },
+}
+
 pub struct AppState {
     pub day_data: DayData,
     pub current_date: Date,
     pub mode: AppMode,
-    pub selected_index: usize,
+    /// Record selected in the table (the first row if unset or no longer shown)
+    selected_id: Option<u32>,
     pub edit_field: EditField,
     pub input_buffer: String,
     pub time_cursor: usize,
@@ -65,13 +158,53 @@ pub struct AppState {
     pub calendar_selected_date: Date,
     pub calendar_view_month: time::Month,
     pub calendar_view_year: i32,
+    pub calendar_target: CalendarTarget,
     pub config: Config,
     pub theme: Theme,
     pub last_error_message: Option<String>,
     pub task_picker_selected: usize,
+    pub task_picker_target: TaskPickerTarget,
+    pub bulk_edit_field: BulkEditField,
+    /// Records the bulk edit prompt applies to
+    bulk_edit_ids: Vec<u32>,
+    /// Issues assigned to the user, offered in the task picker
+    pub assigned_issues: AssignedIssues,
     pub active_timer: Option<TimerState>,
     pub last_file_modified: Option<std::time::SystemTime>,
-    history: History,
+    /// Statistics shown by the dashboard, computed when it opens
+    pub stats: Option<Stats>,
+    /// Number of days the dashboard covers
+    pub stats_days: u32,
+    /// Whether the timeline pane is shown below the record table
+    pub show_timeline: bool,
+    /// Minutes `+`/`-` move a time by
+    pub nudge_step: u32,
+    /// Count typed before a browse key, as in `5j`
+    count: Option<u32>,
+    /// First key of `gg` or `dd`, waiting for the second
+    pending_key: Option<char>,
+    /// Last change `.` repeats, with the count it was given
+    pub last_change: Option<(Change, Option<u32>)>,
+    /// What was typed after `:`
+    pub command_line: String,
+    /// Days searched by search mode, loaded when it opens
+    search_days: Vec<DayData>,
+    pub search_results: Vec<SearchHit>,
+    pub search_selected: usize,
+    /// Query of the record table filter, as typed
+    pub filter_input: String,
+    filter: RecordFilter,
+    /// Record to select once the day being switched to is loaded
+    pending_selection: Option<u32>,
+    /// Records copied with `y`, kept across days for pasting
+    yanked: Vec<WorkRecord>,
+    history: UndoHistory<Snapshot>,
+    /// Tracker requests report back here, see [`AppState::receive_fetched`]
+    fetched: (Sender<Fetched>, Receiver<Fetched>),
+    /// Name whose ticket title is being fetched
+    pub fetching_title: Option<String>,
+    /// Whether assigned issues are being fetched
+    pub loading_assigned_issues: bool,
 }
 
 impl AppState {
@@ -119,7 +252,7 @@ impl AppState {
                 action: CommandAction::Break,
             },
             Command {
-                key: "d",
+                key: "dd",
                 description: "Delete selected record",
                 action: CommandAction::Delete,
             },
@@ -133,6 +266,56 @@ impl AppState {
                 description: "Set current time on field",
                 action: CommandAction::SetNow,
             },
+            Command {
+                key: "y",
+                description: "Yank (copy) selected record",
+                action: CommandAction::Yank,
+            },
+            Command {
+                key: "p",
+                description: "Paste yanked records into this day",
+                action: CommandAction::Paste,
+            },
+            Command {
+                key: "Y",
+                description: "Duplicate previous day's records",
+                action: CommandAction::DuplicatePreviousDay,
+            },
+            Command {
+                key: "m",
+                description: "Move selected record to another date",
+                action: CommandAction::MoveToDate,
+            },
+            Command {
+                key: "x",
+                description: "Split selected record at a time",
+                action: CommandAction::Split,
+            },
+            Command {
+                key: "J",
+                description: "Merge selected record with the next one",
+                action: CommandAction::MergeWithNext,
+            },
+            Command {
+                key: "w",
+                description: "Show/hide the timeline of the day",
+                action: CommandAction::Timeline,
+            },
+            Command {
+                key: "+",
+                description: "Move the selected start/end time later",
+                action: CommandAction::NudgeLater,
+            },
+            Command {
+                key: "-",
+                description: "Move the selected start/end time earlier",
+                action: CommandAction::NudgeEarlier,
+            },
+            Command {
+                key: "*",
+                description: "Change how far +/- move a time (1, 5 or 15 minutes)",
+                action: CommandAction::NudgeStep,
+            },
             Command {
                 key: "u",
                 description: "Undo last change",
@@ -158,6 +341,36 @@ impl AppState {
                 description: "Pause/Resume active session",
                 action: CommandAction::PauseTimer,
             },
+            Command {
+                key: "a",
+                description: "Quick add entry (e.g. 2pm-3pm Review)",
+                action: CommandAction::QuickAdd,
+            },
+            Command {
+                key: "I",
+                description: "New task from assigned issue",
+                action: CommandAction::PickIssue,
+            },
+            Command {
+                key: "/",
+                description: "Search records of all days",
+                action: CommandAction::Search,
+            },
+            Command {
+                key: "f",
+                description: "Filter records (text, >1h, ticket:PROJ, #tag)",
+                action: CommandAction::Filter,
+            },
+            Command {
+                key: "o",
+                description: "Cycle sort order (start, duration, name, ticket)",
+                action: CommandAction::Sort,
+            },
+            Command {
+                key: "D",
+                description: "Statistics dashboard",
+                action: CommandAction::Stats,
+            },
             Command {
                 key: "q",
                 description: "Quit application",
@@ -165,17 +378,31 @@ impl AppState {
             },
         ];
 
-        let config = Config::load().unwrap_or_default();
+        // A broken config falls back to defaults, but says so; skipped parts are reported
+        let (config, config_error) = match Config::load() {
+            Ok(config) if config.warnings.is_empty() => (config, None),
+            Ok(config) => {
+                let warning = format!("Config: {}", config.warnings.join("; "));
+                (config, Some(warning))
+            }
+            Err(e) => (
+                Config::default(),
+                Some(format!("Config not loaded: {:#}", e)),
+            ),
+        };
         let theme = config.get_theme();
+        let stats_days = config.stats.days.max(1);
+        let show_timeline = config.timeline.show;
 
         AppState {
             calendar_selected_date: current_date,
             calendar_view_month: current_date.month(),
             calendar_view_year: current_date.year(),
+            calendar_target: CalendarTarget::Navigate,
             day_data,
             current_date,
             mode: AppMode::Browse,
-            selected_index: 0,
+            selected_id: None,
             edit_field: EditField::Name,
             input_buffer: String::new(),
             time_cursor: 0,
@@ -188,38 +415,171 @@ impl AppState {
             date_changed: false,
             config,
             theme,
-            last_error_message: None,
+            last_error_message: config_error,
             task_picker_selected: 0,
+            task_picker_target: TaskPickerTarget::Rename,
+            bulk_edit_field: BulkEditField::Description,
+            bulk_edit_ids: Vec::new(),
+            assigned_issues: AssignedIssues::new(),
             active_timer: None,
             last_file_modified: None,
-            history: History::new(),
+            stats: None,
+            stats_days,
+            show_timeline,
+            nudge_step: NUDGE_STEPS[1],
+            count: None,
+            pending_key: None,
+            last_change: None,
+            command_line: String::new(),
+            search_days: Vec::new(),
+            search_results: Vec::new(),
+            search_selected: 0,
+            filter_input: String::new(),
+            filter: RecordFilter::default(),
+            pending_selection: None,
+            yanked: Vec::new(),
+            history: UndoHistory::new(),
+            fetched: mpsc::channel(),
+            fetching_title: None,
+            loading_assigned_issues: false,
         }
     }
 
     pub fn get_selected_record(&self) -> Option<&WorkRecord> {
-        let records = self.day_data.get_sorted_records();
-        records.get(self.selected_index).copied()
+        let records = self.visible_records();
+        records.get(self.selected_index()).copied()
+    }
+
+    /// Row of the selected record in the table
+    pub fn selected_index(&self) -> usize {
+        self.selected_id
+            .and_then(|id| self.visible_records().iter().position(|r| r.id == id))
+            .unwrap_or(0)
+    }
+
+    /// Records shown in the table: the day's records in the chosen order, narrowed by the filter
+    pub fn visible_records(&self) -> Vec<&WorkRecord> {
+        let mut records: Vec<&WorkRecord> = self.day_data.work_records.values().collect();
+        sort_records(&mut records, self.config.table.sort, &self.config);
+        if !self.filter.is_empty() {
+            records.retain(|record| self.filter.matches(record, &self.config));
+        }
+        records
+    }
+
+    /// Total time per task name of the visible records, busiest first
+    pub fn visible_grouped_totals(&self) -> Vec<(String, u32)> {
+        if self.filter.is_empty() {
+            return self.day_data.get_grouped_totals();
+        }
+
+        let mut totals: Vec<(String, u32)> = Vec::new();
+        for record in self.visible_records() {
+            match totals.iter_mut().find(|(name, _)| *name == record.name) {
+                Some((_, minutes)) => *minutes += record.total_minutes,
+                None => totals.push((record.name.clone(), record.total_minutes)),
+            }
+        }
+        totals.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        totals
+    }
+
+    pub fn is_filtered(&self) -> bool {
+        !self.filter.is_empty()
+    }
+
+    /// Select the record on a row, or the last one if the table is shorter
+    fn select_row(&mut self, row: usize) {
+        let records = self.visible_records();
+        self.selected_id = records
+            .get(row.min(records.len().saturating_sub(1)))
+            .map(|r| r.id);
+    }
+
+    /// Select a record, or the first row if it's gone or filtered out
+    fn select_record(&mut self, id: u32) {
+        self.selected_id = Some(id);
+        self.keep_selection(0);
+    }
+
+    /// Select the record on `row` if the selected one is no longer shown
+    fn keep_selection(&mut self, row: usize) {
+        let shown = self
+            .selected_id
+            .is_some_and(|id| self.visible_records().iter().any(|r| r.id == id));
+        if !shown {
+            self.select_row(row);
+        }
     }
 
     pub fn move_selection_up(&mut self) {
-        if self.selected_index > 0 {
-            self.selected_index -= 1;
+        let row = self.selected_index();
+        if row > 0 {
+            self.select_row(row - 1);
         }
         if matches!(self.mode, AppMode::Visual) {
-            self.visual_end = self.selected_index;
+            self.visual_end = self.selected_index();
         }
     }
 
     pub fn move_selection_down(&mut self) {
-        let record_count = self.day_data.work_records.len();
-        if self.selected_index < record_count.saturating_sub(1) {
-            self.selected_index += 1;
+        let row = self.selected_index();
+        if row + 1 < self.visible_records().len() {
+            self.select_row(row + 1);
         }
         if matches!(self.mode, AppMode::Visual) {
-            self.visual_end = self.selected_index;
+            self.visual_end = self.selected_index();
+        }
+    }
+
+    /// Switch the table to the next sort order and remember it in the config file
+    ///
+    /// The selected record stays selected wherever it moves to.
+    pub fn cycle_sort_order(&mut self) {
+        let order = self.config.table.sort.next();
+        self.config.table.sort = order;
+        if let Err(e) = Config::save_sort_order(order) {
+            self.last_error_message = Some(format!("Could not save sort order: {:#}", e));
         }
     }
 
+    /// Move the selected record's start or end time by `steps` nudge steps
+    pub fn nudge_selected_time(&mut self, steps: i32) {
+        let Some(id) = self.get_selected_record().map(|r| r.id) else {
+            return;
+        };
+        let boundary = match self.edit_field {
+            EditField::Start => Boundary::Start,
+            EditField::End => Boundary::End,
+            _ => {
+                self.last_error_message =
+                    Some("Select the Start or End column to move a time".to_string());
+                return;
+            }
+        };
+        let mut day = self.day_data.clone();
+        let minutes = steps * self.nudge_step as i32;
+        match day.nudge_time(id, boundary, minutes, self.config.table.nudge_adjacent) {
+            Ok(()) => {
+                self.save_snapshot();
+                self.day_data = day;
+            }
+            Err(e) => self.last_error_message = Some(e),
+        }
+    }
+
+    pub fn cycle_nudge_step(&mut self) {
+        self.nudge_step = NUDGE_STEPS
+            .iter()
+            .copied()
+            .find(|&step| step > self.nudge_step)
+            .unwrap_or(NUDGE_STEPS[0]);
+    }
+
+    pub fn toggle_timeline(&mut self) {
+        self.show_timeline = !self.show_timeline;
+    }
+
     pub fn enter_edit_mode(&mut self) {
         if let Some(record) = self.get_selected_record() {
             let input_value = match self.edit_field {
@@ -237,11 +597,12 @@ impl AppState {
     pub fn change_task_name(&mut self) {
         if matches!(self.edit_field, EditField::Name) && self.get_selected_record().is_some() {
             // Check if there are any existing tasks to pick from
-            let task_names = self.get_unique_task_names();
+            let task_names = self.get_task_picker_names();
             if !task_names.is_empty() {
                 // Open task picker if tasks exist
                 self.input_buffer.clear();
                 self.task_picker_selected = 0;
+                self.task_picker_target = TaskPickerTarget::Rename;
                 self.mode = AppMode::TaskPicker;
             } else {
                 // Go directly to edit mode if no tasks exist
@@ -333,41 +694,111 @@ impl AppState {
     }
 
     fn save_current_field(&mut self) -> Result<(), String> {
-        let records = self.day_data.get_sorted_records();
-        if let Some(&record) = records.get(self.selected_index) {
-            let id = record.id;
-
-            if let Some(record_mut) = self.day_data.work_records.get_mut(&id) {
-                match self.edit_field {
-                    EditField::Name => {
-                        if self.input_buffer.trim().is_empty() {
-                            return Err("Name cannot be empty".to_string());
-                        }
-                        record_mut.name = self.input_buffer.trim().to_string();
-                    }
-                    EditField::Start => {
-                        record_mut.start = self
-                            .input_buffer
-                            .parse()
-                            .map_err(|_| "Invalid start time format (use HH:MM)".to_string())?;
-                        record_mut.update_duration();
-                    }
-                    EditField::End => {
-                        record_mut.end = self
-                            .input_buffer
-                            .parse()
-                            .map_err(|_| "Invalid end time format (use HH:MM)".to_string())?;
-                        record_mut.update_duration();
-                    }
-                    EditField::Description => {
-                        record_mut.description = self.input_buffer.trim().to_string();
+        if let Some(id) = self.get_selected_record().map(|r| r.id)
+            && let Some(record_mut) = self.day_data.work_records.get_mut(&id)
+        {
+            match self.edit_field {
+                EditField::Name => {
+                    if self.input_buffer.trim().is_empty() {
+                        return Err("Name cannot be empty".to_string());
                     }
+                    record_mut.name = self.input_buffer.trim().to_string();
+                }
+                EditField::Start => {
+                    record_mut.start = self
+                        .input_buffer
+                        .parse()
+                        .map_err(|_| "Invalid start time format (use HH:MM)".to_string())?;
+                    record_mut.update_duration();
+                }
+                EditField::End => {
+                    record_mut.end = self
+                        .input_buffer
+                        .parse()
+                        .map_err(|_| "Invalid end time format (use HH:MM)".to_string())?;
+                    record_mut.update_duration();
+                }
+                EditField::Description => {
+                    record_mut.description = self.input_buffer.trim().to_string();
                 }
             }
         }
         Ok(())
     }
 
+    /// Offer the tracker's title when the name being edited is just a ticket
+    ///
+    /// Replaces the input with "PROJ-123 - Title" and returns true, so the user can
+    /// review it before saving. A title that isn't cached is fetched in the background
+    /// (see [`AppState::receive_fetched`]); Enter meanwhile saves the name as typed.
+    /// Returns false if there's nothing to offer.
+    pub fn offer_ticket_title(&mut self, storage: &crate::storage::StorageManager) -> bool {
+        use crate::integrations::titles;
+
+        if !matches!(self.edit_field, EditField::Name) || self.fetching_title.is_some() {
+            return false;
+        }
+
+        let mut cache = storage.load_ticket_titles().unwrap_or_default();
+        if let Some(name) = titles::cached_name(&self.input_buffer, &self.config, &cache) {
+            self.input_buffer = name;
+            return true;
+        }
+        if !titles::has_title_source(&self.input_buffer, &self.config) {
+            return false;
+        }
+
+        let input = self.input_buffer.clone();
+        let config = self.config.clone();
+        let sender = self.fetched.0.clone();
+        self.fetching_title = Some(input.clone());
+        std::thread::spawn(move || {
+            let result =
+                titles::enrich_name(&input, &config, &mut cache).map_err(|e| e.to_string());
+            let _ = sender.send(Fetched::Title {
+                input,
+                result,
+                titles: cache,
+            });
+        });
+        true
+    }
+
+    /// Apply the results of background tracker requests that have finished
+    pub fn receive_fetched(&mut self, storage: &crate::storage::StorageManager) {
+        while let Ok(fetched) = self.fetched.1.try_recv() {
+            match fetched {
+                Fetched::Title {
+                    input,
+                    result,
+                    titles,
+                } => {
+                    self.fetching_title = None;
+                    let mut cache = storage.load_ticket_titles().unwrap_or_default();
+                    cache.extend(titles);
+                    let _ = storage.save_ticket_titles(&cache);
+
+                    // Only offer the title if the name is still being edited as it was
+                    let still_editing = matches!(self.mode, AppMode::Edit)
+                        && matches!(self.edit_field, EditField::Name)
+                        && self.input_buffer == input;
+                    match result {
+                        Ok(Some(name)) if still_editing => self.input_buffer = name,
+                        Err(e) if still_editing => {
+                            self.last_error_message =
+                                Some(format!("Could not fetch ticket title: {}", e));
+                        }
+                        _ => {}
+                    }
+                }
+                Fetched::AssignedIssues { issues, errors } => {
+                    self.loading_assigned_issues = false;
+                    self.store_assigned_issues(issues, errors, storage);
+                }
+            }
+        }
+    }
+
     pub fn save_edit(&mut self) -> Result<(), String> {
         self.save_snapshot();
         self.save_current_field()?;
@@ -376,75 +807,169 @@ impl AppState {
     }
 
     pub fn add_new_record(&mut self) {
-        use crate::models::{TimePoint, WorkRecord};
+        self.add_new_records(1);
+    }
 
+    /// Add `count` one-hour records after the selected one, as one undo step
+    pub fn add_new_records(&mut self, count: u32) {
         self.save_snapshot();
+        let default = (
+            TimePoint::new(9, 0).unwrap(),
+            TimePoint::new(10, 0).unwrap(),
+        );
+        for _ in 0..count {
+            self.append_record("New Task", 60, default);
+        }
+    }
 
+    pub fn add_break(&mut self) {
+        self.add_breaks(1);
+    }
+
+    /// Add `count` 15-minute breaks after the selected record, as one undo step
+    pub fn add_breaks(&mut self, count: u32) {
+        self.save_snapshot();
+        let default = (
+            TimePoint::new(12, 0).unwrap(),
+            TimePoint::new(12, 15).unwrap(),
+        );
+        for _ in 0..count {
+            self.append_record("Break", 15, default);
+        }
+    }
+
+    /// Add a record starting where the selected one ends (or at `default` times) and select it
+    fn append_record(&mut self, name: &str, minutes: u32, default: (TimePoint, TimePoint)) {
         let id = self.day_data.next_id();
 
-        let (default_start, default_end) = if let Some(current_record) = self.get_selected_record()
-        {
-            let start_minutes = current_record.end.to_minutes_since_midnight();
-            let end_minutes = (start_minutes + 60).min(24 * 60 - 1);
-            (
-                current_record.end,
-                TimePoint::from_minutes_since_midnight(end_minutes).unwrap(),
-            )
-        } else {
-            (
-                TimePoint::new(9, 0).unwrap(),
-                TimePoint::new(10, 0).unwrap(),
-            )
+        let (start, end) = match self.get_selected_record() {
+            Some(current_record) => {
+                let start_minutes = current_record.end.to_minutes_since_midnight();
+                let end_minutes = (start_minutes + minutes).min(24 * 60 - 1);
+                (
+                    current_record.end,
+                    TimePoint::from_minutes_since_midnight(end_minutes).unwrap(),
+                )
+            }
+            None => default,
         };
 
-        let record = WorkRecord::new(id, "New Task".to_string(), default_start, default_end);
-
-        self.day_data.add_record(record);
+        self.day_data
+            .add_record(WorkRecord::new(id, name.to_string(), start, end));
 
-        let records = self.day_data.get_sorted_records();
-        self.selected_index = records.iter().position(|r| r.id == id).unwrap_or(0);
+        // The new record wouldn't match the filter
+        self.clear_filter();
+        self.selected_id = Some(id);
     }
 
-    pub fn add_break(&mut self) {
-        use crate::models::{TimePoint, WorkRecord};
+    pub fn delete_selected_record(&mut self) {
+        self.delete_selected_records(1);
+    }
 
+    /// Delete `count` rows from the selected one down, as one undo step
+    pub fn delete_selected_records(&mut self, count: usize) {
         self.save_snapshot();
 
-        let id = self.day_data.next_id();
+        let row = self.selected_index();
+        let ids: Vec<u32> = self
+            .visible_records()
+            .iter()
+            .skip(row)
+            .take(count)
+            .map(|r| r.id)
+            .collect();
+        for id in ids {
+            self.day_data.remove_record(id);
+        }
+        self.keep_selection(row);
+    }
 
-        let (default_start, default_end) = if let Some(current_record) = self.get_selected_record()
-        {
-            let start_minutes = current_record.end.to_minutes_since_midnight();
-            let end_minutes = (start_minutes + 15).min(24 * 60 - 1);
-            (
-                current_record.end,
-                TimePoint::from_minutes_since_midnight(end_minutes).unwrap(),
-            )
-        } else {
-            (
-                TimePoint::new(12, 0).unwrap(),
-                TimePoint::new(12, 15).unwrap(),
-            )
+    /// Select a row counting from 1, the last one if `row` is past the end
+    pub fn go_to_row(&mut self, row: usize) {
+        self.select_row(row.saturating_sub(1));
+    }
+
+    /// Add a digit to the count typed before a browse key
+    ///
+    /// Returns false for keys that aren't part of a count (`0` only continues one).
+    pub fn push_count_digit(&mut self, c: char) -> bool {
+        let Some(digit) = c.to_digit(10) else {
+            return false;
         };
+        if digit == 0 && self.count.is_none() {
+            return false;
+        }
+        self.count = Some((self.count.unwrap_or(0) * 10 + digit).min(MAX_COUNT));
+        true
+    }
 
-        let record = WorkRecord::new(id, "Break".to_string(), default_start, default_end);
+    pub fn take_count(&mut self) -> Option<u32> {
+        self.count.take()
+    }
 
-        self.day_data.add_record(record);
+    pub fn set_pending_key(&mut self, key: char, count: Option<u32>) {
+        self.pending_key = Some(key);
+        self.count = count;
+    }
 
-        let records = self.day_data.get_sorted_records();
-        self.selected_index = records.iter().position(|r| r.id == id).unwrap_or(0);
+    pub fn take_pending_key(&mut self) -> Option<char> {
+        self.pending_key.take()
     }
 
-    pub fn delete_selected_record(&mut self) {
-        self.save_snapshot();
+    /// Count and first key typed so far, shown in the footer like vim's showcmd
+    pub fn pending_keys(&self) -> String {
+        let count = self.count.map(|c| c.to_string()).unwrap_or_default();
+        format!(
+            "{}{}",
+            count,
+            self.pending_key.map(String::from).unwrap_or_default()
+        )
+    }
 
-        let records = self.day_data.get_sorted_records();
-        if let Some(&record) = records.get(self.selected_index) {
-            self.day_data.remove_record(record.id);
+    pub fn open_command_line(&mut self) {
+        self.command_line.clear();
+        self.mode = AppMode::CommandLine;
+    }
+
+    pub fn close_command_line(&mut self) {
+        self.command_line.clear();
+        self.mode = AppMode::Browse;
+    }
 
-            if self.selected_index >= self.day_data.work_records.len() {
-                self.selected_index = self.day_data.work_records.len().saturating_sub(1);
+    pub fn handle_command_line_char(&mut self, c: char) {
+        self.command_line.push(c);
+    }
+
+    /// Delete the last character, leaving the command line when it's already empty
+    pub fn handle_command_line_backspace(&mut self) {
+        if self.command_line.pop().is_none() {
+            self.close_command_line();
+        }
+    }
+
+    /// Parse the command line and return to browse mode
+    ///
+    /// Going to a day or a row happens here, saving and quitting is left to the caller.
+    pub fn submit_command_line(&mut self) -> Result<ExCommand, String> {
+        use time::{OffsetDateTime, UtcOffset};
+
+        let local_offset = UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC);
+        let today = OffsetDateTime::now_utc().to_offset(local_offset).date();
+        let command = ExCommand::parse(&self.command_line, today);
+        self.close_command_line();
+        match command? {
+            ExCommand::Date(date) => {
+                if date != self.current_date {
+                    self.current_date = date;
+                    self.date_changed = true;
+                }
+                Ok(ExCommand::Date(date))
             }
+            ExCommand::Row(row) => {
+                self.go_to_row(row);
+                Ok(ExCommand::Row(row))
+            }
+            command => Ok(command),
         }
     }
 
@@ -475,34 +1000,31 @@ impl AppState {
         let now = OffsetDateTime::now_utc().to_offset(local_offset);
         let current_time = format!("{:02}:{:02}", now.hour(), now.minute());
 
-        let records = self.day_data.get_sorted_records();
-        if let Some(&record) = records.get(self.selected_index) {
-            let id = record.id;
-
-            if let Some(record_mut) = self.day_data.work_records.get_mut(&id) {
-                match self.edit_field {
-                    EditField::Start => {
-                        if let Ok(time_point) = current_time.parse() {
-                            record_mut.start = time_point;
-                            record_mut.update_duration();
-                        }
+        if let Some(id) = self.get_selected_record().map(|r| r.id)
+            && let Some(record_mut) = self.day_data.work_records.get_mut(&id)
+        {
+            match self.edit_field {
+                EditField::Start => {
+                    if let Ok(time_point) = current_time.parse() {
+                        record_mut.start = time_point;
+                        record_mut.update_duration();
                     }
-                    EditField::End => {
-                        if let Ok(time_point) = current_time.parse() {
-                            record_mut.end = time_point;
-                            record_mut.update_duration();
-                        }
+                }
+                EditField::End => {
+                    if let Ok(time_point) = current_time.parse() {
+                        record_mut.end = time_point;
+                        record_mut.update_duration();
                     }
-                    _ => {}
                 }
+                _ => {}
             }
         }
     }
 
     pub fn enter_visual_mode(&mut self) {
         self.mode = AppMode::Visual;
-        self.visual_start = self.selected_index;
-        self.visual_end = self.selected_index;
+        self.visual_start = self.selected_index();
+        self.visual_end = self.visual_start;
     }
 
     pub fn exit_visual_mode(&mut self) {
@@ -516,53 +1038,313 @@ impl AppState {
         index >= start && index <= end
     }
 
-    pub fn delete_visual_selection(&mut self) {
-        self.save_snapshot();
-
-        let records = self.day_data.get_sorted_records();
-        let start = self.visual_start.min(self.visual_end);
-        let end = self.visual_start.max(self.visual_end);
-
-        let ids_to_delete: Vec<u32> = records
+    /// Ids of the records in the visual selection, in table order
+    fn visual_selection_ids(&self) -> Vec<u32> {
+        self.visible_records()
             .iter()
             .enumerate()
-            .filter(|(i, _)| *i >= start && *i <= end)
+            .filter(|(i, _)| self.is_in_visual_selection(*i))
             .map(|(_, record)| record.id)
-            .collect();
+            .collect()
+    }
 
-        for id in ids_to_delete {
+    pub fn delete_visual_selection(&mut self) {
+        self.save_snapshot();
+
+        for id in self.visual_selection_ids() {
             self.day_data.remove_record(id);
         }
 
-        if self.selected_index >= self.day_data.work_records.len() {
-            self.selected_index = self.day_data.work_records.len().saturating_sub(1);
+        // The record after the deleted ones moves up into their place
+        self.keep_selection(self.visual_start.min(self.visual_end));
+
+        self.exit_visual_mode();
+    }
+
+    /// Rename the visual selection through the task picker
+    pub fn open_bulk_rename(&mut self) {
+        let ids = self.visual_selection_ids();
+        self.exit_visual_mode();
+        if ids.is_empty() {
+            return;
         }
+        self.input_buffer.clear();
+        self.task_picker_selected = 0;
+        self.task_picker_target = TaskPickerTarget::RenameRecords(ids);
+        self.mode = AppMode::TaskPicker;
+    }
 
+    /// Prompt for a description or time shift for the visual selection
+    pub fn open_bulk_edit(&mut self, field: BulkEditField) {
+        self.bulk_edit_ids = self.visual_selection_ids();
         self.exit_visual_mode();
+        if self.bulk_edit_ids.is_empty() {
+            return;
+        }
+
+        // Start from the records' description if they share one
+        let mut descriptions = self
+            .bulk_edit_ids
+            .iter()
+            .filter_map(|id| self.day_data.work_records.get(id))
+            .map(|r| r.description.as_str());
+        let first = descriptions.next().unwrap_or_default();
+        self.input_buffer = match field {
+            BulkEditField::Description if descriptions.all(|d| d == first) => first.to_string(),
+            _ => String::new(),
+        };
+
+        self.bulk_edit_field = field;
+        self.mode = AppMode::BulkEdit;
     }
 
-    fn save_snapshot(&mut self) {
-        self.history.push(self.day_data.clone());
+    pub fn close_bulk_edit(&mut self) {
+        self.input_buffer.clear();
+        self.bulk_edit_ids.clear();
+        self.mode = AppMode::Browse;
+    }
+
+    /// Number of records the bulk edit prompt applies to
+    pub fn bulk_edit_count(&self) -> usize {
+        self.bulk_edit_ids.len()
+    }
+
+    pub fn handle_bulk_edit_char(&mut self, c: char) {
+        self.input_buffer.push(c);
+    }
+
+    pub fn handle_bulk_edit_backspace(&mut self) {
+        self.input_buffer.pop();
+    }
+
+    /// Apply the prompt to all records as one undo step
+    ///
+    /// On an invalid shift the prompt stays open so the input can be corrected.
+    pub fn submit_bulk_edit(&mut self) -> Result<(), String> {
+        let mut day = self.day_data.clone();
+        match self.bulk_edit_field {
+            BulkEditField::Description => {
+                for id in &self.bulk_edit_ids {
+                    if let Some(record) = day.work_records.get_mut(id) {
+                        record.description = self.input_buffer.trim().to_string();
+                    }
+                }
+            }
+            BulkEditField::Shift => {
+                let minutes = crate::models::parse_offset(&self.input_buffer)
+                    .ok_or("Invalid shift (use e.g. +15, -30m or +1h)")?;
+                day.shift_records(&self.bulk_edit_ids, minutes)?;
+            }
+        }
+
+        self.save_snapshot();
+        self.day_data = day;
+        self.close_bulk_edit();
+        Ok(())
     }
 
-    pub fn undo(&mut self) {
-        if let Some(previous_state) = self.history.undo(self.day_data.clone()) {
-            self.day_data = previous_state;
+    /// Merge the visually selected records of one task into a single record
+    pub fn merge_visual_selection(&mut self) {
+        let ids = self.visual_selection_ids();
+        self.exit_visual_mode();
 
-            if self.selected_index >= self.day_data.work_records.len() {
-                self.selected_index = self.day_data.work_records.len().saturating_sub(1);
+        let mut day = self.day_data.clone();
+        match day.merge_records(&ids) {
+            Ok(id) => {
+                self.save_snapshot();
+                self.day_data = day;
+                self.select_record(id);
             }
+            Err(e) => self.last_error_message = Some(e),
+        }
+    }
+
+    /// Prompt for the time to split the selected record at
+    pub fn open_split(&mut self) {
+        if self.get_selected_record().is_some() {
+            self.input_buffer.clear();
+            self.mode = AppMode::Split;
         }
     }
 
-    pub fn redo(&mut self) {
-        if let Some(next_state) = self.history.redo(self.day_data.clone()) {
-            self.day_data = next_state;
+    pub fn close_split(&mut self) {
+        self.input_buffer.clear();
+        self.mode = AppMode::Browse;
+    }
+
+    pub fn handle_split_char(&mut self, c: char) {
+        self.input_buffer.push(c);
+    }
+
+    pub fn handle_split_backspace(&mut self) {
+        self.input_buffer.pop();
+    }
+
+    /// Split the selected record at the typed time ("HH:MM [name of the second part]")
+    ///
+    /// Selects the second part. On invalid input the prompt stays open.
+    pub fn submit_split(&mut self) -> Result<(), String> {
+        let id = self
+            .get_selected_record()
+            .map(|r| r.id)
+            .ok_or("No record selected")?;
+        let input = self.input_buffer.trim();
+        let (time, name) = input.split_once(' ').unwrap_or((input, ""));
+        let at = time
+            .parse()
+            .map_err(|_| "Invalid time format (use HH:MM)".to_string())?;
+        let name = Some(name.trim().to_string()).filter(|name| !name.is_empty());
+
+        let mut day = self.day_data.clone();
+        let second_id = day.split_record(id, at, name)?;
+        self.save_snapshot();
+        self.day_data = day;
+        self.select_record(second_id);
+        self.close_split();
+        Ok(())
+    }
 
-            if self.selected_index >= self.day_data.work_records.len() {
-                self.selected_index = self.day_data.work_records.len().saturating_sub(1);
+    /// Join the selected record with the `count` records after it, as one undo step
+    pub fn merge_with_next(&mut self, count: u32) {
+        let Some(mut id) = self.get_selected_record().map(|r| r.id) else {
+            return;
+        };
+        let mut day = self.day_data.clone();
+        for _ in 0..count {
+            match day.merge_with_next(id) {
+                Ok(merged) => id = merged,
+                Err(e) => {
+                    self.last_error_message = Some(e);
+                    return;
+                }
             }
         }
+        self.save_snapshot();
+        self.day_data = day;
+        self.select_record(id);
+    }
+
+    pub fn yank_selected_record(&mut self) {
+        if let Some(record) = self.get_selected_record() {
+            self.yanked = vec![record.clone()];
+        }
+    }
+
+    pub fn yank_visual_selection(&mut self) {
+        self.yanked = self
+            .visual_selection_ids()
+            .iter()
+            .filter_map(|id| self.day_data.work_records.get(id).cloned())
+            .collect();
+        self.exit_visual_mode();
+    }
+
+    /// Number of records that `p` would paste
+    pub fn yanked_count(&self) -> usize {
+        self.yanked.len()
+    }
+
+    /// Paste the yanked records `count` times into the viewed day, with their times and
+    /// new ids
+    pub fn paste_yanked(&mut self, count: u32) {
+        if self.yanked.is_empty() {
+            self.last_error_message = Some("Nothing to paste, yank records with y".to_string());
+            return;
+        }
+        let records: Vec<WorkRecord> = (0..count).flat_map(|_| self.yanked.clone()).collect();
+        self.paste_records(&records);
+    }
+
+    /// Copy all records of the day before the viewed one, as a starting point
+    pub fn duplicate_previous_day(&mut self, storage: &crate::storage::StorageManager) {
+        let Some(previous) = self.current_date.previous_day() else {
+            return;
+        };
+        match storage.load_range(previous, previous) {
+            Ok(days) => match days.first() {
+                Some(day) => {
+                    let records: Vec<WorkRecord> =
+                        day.get_sorted_records().into_iter().cloned().collect();
+                    self.paste_records(&records);
+                }
+                None => {
+                    self.last_error_message = Some("No records on the previous day".to_string())
+                }
+            },
+            Err(e) => self.last_error_message = Some(format!("Failed to load records: {}", e)),
+        }
+    }
+
+    /// Add copies of records as one undo step, selecting the first one
+    fn paste_records(&mut self, records: &[WorkRecord]) {
+        self.save_snapshot();
+        let ids = self.day_data.paste_records(records);
+
+        // The copies might not match the filter
+        self.clear_filter();
+        self.selected_id = ids.first().copied();
+    }
+
+    fn save_snapshot(&mut self) {
+        self.history.push(Snapshot {
+            day: self.day_data.clone(),
+            other_day: None,
+        });
+    }
+
+    /// Undo the last change, including what it did to another day's file
+    pub fn undo(&mut self, storage: &mut crate::storage::StorageManager) {
+        let Some(previous) = self.history.peek_undo() else {
+            return;
+        };
+        let Some(current) = self.current_snapshot(previous.other_day.clone(), storage) else {
+            return;
+        };
+        if let Some(previous) = self.history.undo(current) {
+            self.restore_snapshot(previous);
+        }
+    }
+
+    pub fn redo(&mut self, storage: &mut crate::storage::StorageManager) {
+        let Some(next) = self.history.peek_redo() else {
+            return;
+        };
+        let Some(current) = self.current_snapshot(next.other_day.clone(), storage) else {
+            return;
+        };
+        if let Some(next) = self.history.redo(current) {
+            self.restore_snapshot(next);
+        }
+    }
+
+    /// The viewed day as it is now, after applying `change` to the other day's file
+    ///
+    /// The snapshot holds the change that reverts it. Returns None if the other day couldn't
+    /// be changed.
+    fn current_snapshot(
+        &mut self,
+        change: Option<DayChange>,
+        storage: &mut crate::storage::StorageManager,
+    ) -> Option<Snapshot> {
+        let other_day = match change.map(|change| apply_day_change(&change, storage)) {
+            Some(Ok(revert)) => Some(revert),
+            Some(Err(e)) => {
+                self.last_error_message = Some(e);
+                return None;
+            }
+            None => None,
+        };
+        Some(Snapshot {
+            day: self.day_data.clone(),
+            other_day,
+        })
+    }
+
+    /// Go back to a snapshot of the viewed day; the caller saves it
+    fn restore_snapshot(&mut self, snapshot: Snapshot) {
+        let row = self.selected_index();
+        self.day_data = snapshot.day;
+        self.keep_selection(row);
     }
 
     pub fn open_command_palette(&mut self) {
@@ -627,7 +1409,7 @@ impl AppState {
             })
             .collect();
 
-        results.sort_by(|a, b| b.1.cmp(&a.1));
+        results.sort_by_key(|r| std::cmp::Reverse(r.1));
         results
     }
 
@@ -656,13 +1438,17 @@ impl AppState {
 
     pub fn load_new_day_data(&mut self, new_day_data: DayData) {
         self.day_data = new_day_data;
-        self.selected_index = 0;
-        self.history = History::new();
+        match self.pending_selection.take() {
+            Some(id) => self.select_record(id),
+            None => self.select_row(0),
+        }
+        self.history = UndoHistory::new();
         self.date_changed = false;
     }
 
     pub fn open_calendar(&mut self) {
         self.mode = AppMode::Calendar;
+        self.calendar_target = CalendarTarget::Navigate;
         self.calendar_selected_date = self.current_date;
         self.calendar_view_month = self.current_date.month();
         self.calendar_view_year = self.current_date.year();
@@ -670,6 +1456,25 @@ impl AppState {
 
     pub fn close_calendar(&mut self) {
         self.mode = AppMode::Browse;
+        self.calendar_target = CalendarTarget::Navigate;
+    }
+
+    /// Pick a date in the calendar to move the selected record to
+    pub fn open_move_to_date(&mut self) {
+        if let Some(id) = self.get_selected_record().map(|r| r.id) {
+            self.open_calendar();
+            self.calendar_target = CalendarTarget::MoveRecords(vec![id]);
+        }
+    }
+
+    /// Pick a date in the calendar to move the visual selection to
+    pub fn open_move_visual_selection(&mut self) {
+        let ids = self.visual_selection_ids();
+        self.exit_visual_mode();
+        if !ids.is_empty() {
+            self.open_calendar();
+            self.calendar_target = CalendarTarget::MoveRecords(ids);
+        }
     }
 
     pub fn calendar_navigate_left(&mut self) {
@@ -778,66 +1583,150 @@ impl AppState {
         }
     }
 
-    pub fn calendar_select_date(&mut self) {
-        self.current_date = self.calendar_selected_date;
-        self.date_changed = true;
+    pub fn calendar_select_date(&mut self, storage: &mut crate::storage::StorageManager) {
+        let target = std::mem::replace(&mut self.calendar_target, CalendarTarget::Navigate);
+        match target {
+            CalendarTarget::Navigate => {
+                self.current_date = self.calendar_selected_date;
+                self.date_changed = true;
+            }
+            CalendarTarget::MoveRecords(ids) => {
+                self.move_records_to(&ids, self.calendar_selected_date, storage)
+            }
+        }
         self.close_calendar();
     }
 
-    pub fn open_ticket_in_browser(&mut self) {
-        use crate::integrations::{build_url, detect_tracker, extract_ticket_from_name};
+    /// Move records of the viewed day into another day's file, as one undo step
+    ///
+    /// The other day is saved here, the caller saves the viewed day.
+    fn move_records_to(
+        &mut self,
+        ids: &[u32],
+        date: Date,
+        storage: &mut crate::storage::StorageManager,
+    ) {
+        if date == self.current_date {
+            self.last_error_message = Some("Pick another day to move the records to".to_string());
+            return;
+        }
+        let mut target = match storage.load_with_tracking(date) {
+            Ok(day) => day,
+            Err(e) => {
+                self.last_error_message = Some(format!("Failed to load records: {}", e));
+                return;
+            }
+        };
 
-        if let Some(record) = self.get_selected_record() {
-            if let Some(ticket_id) = extract_ticket_from_name(&record.name) {
-                if let Some(tracker_name) = detect_tracker(&ticket_id, &self.config) {
-                    match build_url(&ticket_id, &tracker_name, &self.config, false) {
-                        Ok(url) => {
-                            if let Err(e) = open_url_in_browser(&url) {
-                                self.last_error_message =
-                                    Some(format!("Failed to open browser: {}", e));
-                            }
-                        }
-                        Err(e) => {
-                            self.last_error_message = Some(format!("Failed to build URL: {}", e));
-                        }
-                    }
-                } else {
-                    self.last_error_message =
-                        Some("Could not detect tracker for ticket".to_string());
-                }
-            } else {
-                self.last_error_message = Some("No ticket found in task name".to_string());
+        let mut day = self.day_data.clone();
+        let moved = day.move_records(ids, &mut target);
+        if let Err(e) = storage.save(&target) {
+            self.last_error_message = Some(format!("Failed to save records: {}", e));
+            return;
+        }
+
+        let row = self.selected_index();
+        self.history.push(Snapshot {
+            day: std::mem::replace(&mut self.day_data, day),
+            other_day: Some(DayChange {
+                date,
+                remove: moved,
+                add: Vec::new(),
+            }),
+        });
+        self.keep_selection(row);
+    }
+
+    /// Show statistics for the days up to the viewed one
+    pub fn open_stats(&mut self, storage: &crate::storage::StorageManager) {
+        self.load_stats(storage);
+        if self.stats.is_some() {
+            self.mode = AppMode::Stats;
+        }
+    }
+
+    pub fn close_stats(&mut self) {
+        self.stats = None;
+        self.mode = AppMode::Browse;
+    }
+
+    /// Cover the next longer preset range
+    pub fn stats_longer_range(&mut self, storage: &crate::storage::StorageManager) {
+        if let Some(&days) = RANGE_PRESETS.iter().find(|&&days| days > self.stats_days) {
+            self.stats_days = days;
+            self.load_stats(storage);
+        }
+    }
+
+    /// Cover the next shorter preset range
+    pub fn stats_shorter_range(&mut self, storage: &crate::storage::StorageManager) {
+        if let Some(&days) = RANGE_PRESETS
+            .iter()
+            .rev()
+            .find(|&&days| days < self.stats_days)
+        {
+            self.stats_days = days;
+            self.load_stats(storage);
+        }
+    }
+
+    /// Compute statistics for the `stats_days` days up to the viewed day
+    fn load_stats(&mut self, storage: &crate::storage::StorageManager) {
+        let to = self.current_date;
+        let from = to.saturating_sub(time::Duration::days(self.stats_days as i64 - 1));
+        match storage.load_range(from, to) {
+            Ok(mut days) => {
+                // The viewed day may have changes that aren't saved yet
+                days.retain(|day| day.date != to);
+                days.push(self.day_data.clone());
+                self.stats = Some(Stats::compute(&days, from, to, &self.config));
             }
+            Err(e) => self.last_error_message = Some(format!("Failed to load records: {}", e)),
         }
     }
 
-    pub fn open_worklog_in_browser(&mut self) {
-        use crate::integrations::{build_url, detect_tracker, extract_ticket_from_name};
+    /// Open every ticket in the selected record's name
+    fn open_tickets_in_browser(&mut self, for_worklog: bool) {
+        use crate::integrations::{build_ticket_url, extract_tickets};
 
-        if let Some(record) = self.get_selected_record() {
-            if let Some(ticket_id) = extract_ticket_from_name(&record.name) {
-                if let Some(tracker_name) = detect_tracker(&ticket_id, &self.config) {
-                    match build_url(&ticket_id, &tracker_name, &self.config, true) {
-                        Ok(url) => {
-                            if let Err(e) = open_url_in_browser(&url) {
-                                self.last_error_message =
-                                    Some(format!("Failed to open browser: {}", e));
-                            }
-                        }
-                        Err(e) => {
-                            self.last_error_message = Some(format!("Failed to build URL: {}", e));
-                        }
+        let Some(record) = self.get_selected_record() else {
+            return;
+        };
+        let tickets = extract_tickets(&record.name, &self.config);
+        if tickets.is_empty() {
+            self.last_error_message = Some("No ticket found in task name".to_string());
+            return;
+        }
+
+        for ticket in &tickets {
+            if ticket.tracker.is_none() {
+                self.last_error_message = Some(format!(
+                    "Could not detect tracker for ticket {}",
+                    ticket.text
+                ));
+                continue;
+            }
+            match build_ticket_url(ticket, &self.config, for_worklog) {
+                Ok(url) => {
+                    if let Err(e) = open_url_in_browser(&url) {
+                        self.last_error_message = Some(format!("Failed to open browser: {}", e));
                     }
-                } else {
-                    self.last_error_message =
-                        Some("Could not detect tracker for ticket".to_string());
                 }
-            } else {
-                self.last_error_message = Some("No ticket found in task name".to_string());
+                Err(e) => {
+                    self.last_error_message = Some(format!("Failed to build URL: {}", e));
+                }
             }
         }
     }
 
+    pub fn open_ticket_in_browser(&mut self) {
+        self.open_tickets_in_browser(false);
+    }
+
+    pub fn open_worklog_in_browser(&mut self) {
+        self.open_tickets_in_browser(true);
+    }
+
     pub fn clear_error(&mut self) {
         self.last_error_message = None;
     }
@@ -865,8 +1754,82 @@ impl AppState {
         task_names
     }
 
+    /// Open the task picker to add a new record, e.g. from an assigned issue
+    pub fn open_issue_picker(&mut self) {
+        self.input_buffer.clear();
+        self.task_picker_selected = 0;
+        self.task_picker_target = TaskPickerTarget::NewRecord;
+        self.mode = AppMode::TaskPicker;
+    }
+
+    /// Task names of the current day followed by assigned issues not already used
+    pub fn get_task_picker_names(&self) -> Vec<String> {
+        let mut names = self.get_unique_task_names();
+        for issue in self.assigned_issues.values().flatten() {
+            let name = issue.task_name();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    pub fn is_assigned_issue(&self, name: &str) -> bool {
+        self.assigned_issues
+            .values()
+            .flatten()
+            .any(|issue| issue.task_name() == name)
+    }
+
+    /// Refetch assigned issues from the trackers in the background
+    ///
+    /// Cached issues stay listed (and are kept on failure) until the new ones arrive,
+    /// see [`AppState::receive_fetched`].
+    pub fn refresh_assigned_issues(&mut self) {
+        if self.loading_assigned_issues {
+            return;
+        }
+        self.loading_assigned_issues = true;
+
+        let config = self.config.clone();
+        let mut issues = self.assigned_issues.clone();
+        let sender = self.fetched.0.clone();
+        std::thread::spawn(move || {
+            let errors =
+                crate::integrations::assigned::refresh_assigned_issues(&config, &mut issues);
+            let _ = sender.send(Fetched::AssignedIssues { issues, errors });
+        });
+    }
+
+    fn store_assigned_issues(
+        &mut self,
+        issues: AssignedIssues,
+        errors: Vec<String>,
+        storage: &crate::storage::StorageManager,
+    ) {
+        self.assigned_issues = issues;
+        let _ = storage.save_assigned_issues(&self.assigned_issues);
+
+        // Remember the titles for ticket name lookups too
+        let mut titles = storage.load_ticket_titles().unwrap_or_default();
+        for (tracker, issues) in &self.assigned_issues {
+            for issue in issues {
+                titles.insert(format!("{}:{}", tracker, issue.ticket), issue.title.clone());
+            }
+        }
+        let _ = storage.save_ticket_titles(&titles);
+
+        self.task_picker_selected = 0;
+        if !errors.is_empty() {
+            self.last_error_message = Some(format!(
+                "Could not fetch assigned issues: {}",
+                errors.join("; ")
+            ));
+        }
+    }
+
     pub fn get_filtered_task_names(&self) -> Vec<String> {
-        let all_tasks = self.get_unique_task_names();
+        let all_tasks = self.get_task_picker_names();
         let filter = self.input_buffer.to_lowercase();
 
         if filter.is_empty() {
@@ -891,25 +1854,52 @@ impl AppState {
         }
     }
 
-    pub fn select_task_from_picker(&mut self) {
+    /// The highlighted task name, or the typed input if nothing matches
+    fn picked_task_name(&self) -> String {
         let filtered_tasks = self.get_filtered_task_names();
+        match filtered_tasks.get(self.task_picker_selected) {
+            Some(selected_name) => selected_name.clone(),
+            None => self.input_buffer.trim().to_string(),
+        }
+    }
+
+    pub fn select_task_from_picker(&mut self) {
+        let new_name = self.picked_task_name();
 
-        if filtered_tasks.is_empty() {
-            // No matches - use the typed input as-is (creating new task)
-            // input_buffer already contains the typed text
-        } else if let Some(selected_name) = filtered_tasks.get(self.task_picker_selected) {
-            // Select from filtered list
-            self.input_buffer = selected_name.clone();
+        if new_name.is_empty() {
+            return;
         }
 
         // Save the task name and return to Browse mode
-        if let Some(record) = self.get_selected_record() {
-            let record_id = record.id;
-            let new_name = self.input_buffer.trim().to_string();
+        match &self.task_picker_target {
+            TaskPickerTarget::NewRecord => {
+                // add_new_record takes the undo snapshot, so the rename is part of the same step
+                self.add_new_record();
+                if let Some(record) = self.get_selected_record() {
+                    let record_id = record.id;
+                    if let Some(work_record) = self.day_data.work_records.get_mut(&record_id) {
+                        work_record.name = new_name;
+                    }
+                }
+            }
+            TaskPickerTarget::RenameRecords(ids) => {
+                let ids = ids.clone();
+                self.save_snapshot();
+                for id in ids {
+                    if let Some(work_record) = self.day_data.work_records.get_mut(&id) {
+                        work_record.name = new_name.clone();
+                    }
+                }
+            }
+            TaskPickerTarget::Rename => {
+                if let Some(record) = self.get_selected_record() {
+                    let record_id = record.id;
 
-            self.save_snapshot();
-            if let Some(work_record) = self.day_data.work_records.get_mut(&record_id) {
-                work_record.name = new_name;
+                    self.save_snapshot();
+                    if let Some(work_record) = self.day_data.work_records.get_mut(&record_id) {
+                        work_record.name = new_name;
+                    }
+                }
             }
         }
 
@@ -917,6 +1907,24 @@ impl AppState {
         self.mode = AppMode::Browse;
     }
 
+    /// Start a session for the picked task name instead of editing records
+    pub fn start_timer_from_picker(
+        &mut self,
+        storage: &crate::storage::StorageManager,
+    ) -> Result<(), String> {
+        let name = self.picked_task_name();
+        if name.is_empty() {
+            return Err("Task name cannot be empty".to_string());
+        }
+
+        let timer = storage
+            .start_timer(name, None, None, None)
+            .map_err(|e| e.to_string())?;
+        self.active_timer = Some(timer);
+        self.close_task_picker();
+        Ok(())
+    }
+
     pub fn handle_task_picker_char(&mut self, c: char) {
         self.input_buffer.push(c);
         // Reset selection when typing
@@ -929,6 +1937,181 @@ impl AppState {
         self.task_picker_selected = 0;
     }
 
+    /// Open search mode over all stored days
+    pub fn open_search(&mut self, storage: &crate::storage::StorageManager) {
+        match storage.load_all() {
+            Ok((mut days, skipped)) => {
+                // The viewed day may have changes that aren't saved yet
+                days.retain(|day| day.date != self.current_date);
+                days.push(self.day_data.clone());
+                self.search_days = days;
+                if !skipped.is_empty() {
+                    self.last_error_message =
+                        Some(format!("Skipped unreadable days: {}", skipped.join("; ")));
+                }
+            }
+            Err(e) => {
+                self.last_error_message = Some(format!("Failed to load records: {}", e));
+                return;
+            }
+        }
+        self.input_buffer.clear();
+        self.search_results.clear();
+        self.search_selected = 0;
+        self.mode = AppMode::Search;
+    }
+
+    pub fn close_search(&mut self) {
+        self.input_buffer.clear();
+        self.search_days.clear();
+        self.search_results.clear();
+        self.mode = AppMode::Browse;
+    }
+
+    pub fn handle_search_char(&mut self, c: char) {
+        self.input_buffer.push(c);
+        self.update_search_results();
+    }
+
+    pub fn handle_search_backspace(&mut self) {
+        self.input_buffer.pop();
+        self.update_search_results();
+    }
+
+    fn update_search_results(&mut self) {
+        self.search_results = SearchQuery::parse(&self.input_buffer).search(&self.search_days);
+        self.search_selected = 0;
+    }
+
+    pub fn move_search_up(&mut self) {
+        self.search_selected = self.search_selected.saturating_sub(1);
+    }
+
+    pub fn move_search_down(&mut self) {
+        if self.search_selected + 1 < self.search_results.len() {
+            self.search_selected += 1;
+        }
+    }
+
+    /// Jump to the day of the highlighted result and select its record
+    pub fn select_search_result(&mut self) {
+        let Some(hit) = self.search_results.get(self.search_selected) else {
+            return;
+        };
+        let (date, id) = (hit.date, hit.record.id);
+
+        if date == self.current_date {
+            self.select_record(id);
+        } else {
+            self.pending_selection = Some(id);
+            self.current_date = date;
+            self.date_changed = true;
+        }
+        self.close_search();
+    }
+
+    pub fn open_filter(&mut self) {
+        self.mode = AppMode::Filter;
+    }
+
+    /// Keep the filter and return to the table
+    pub fn apply_filter(&mut self) {
+        self.mode = AppMode::Browse;
+    }
+
+    /// Drop the filter and return to the table
+    pub fn cancel_filter(&mut self) {
+        self.clear_filter();
+        self.mode = AppMode::Browse;
+    }
+
+    /// Show all records again, keeping the selected one selected
+    pub fn clear_filter(&mut self) {
+        if self.filter_input.is_empty() {
+            return;
+        }
+        self.selected_id = self.get_selected_record().map(|r| r.id);
+        self.filter_input.clear();
+        self.filter = RecordFilter::default();
+    }
+
+    pub fn handle_filter_char(&mut self, c: char) {
+        self.filter_input.push(c);
+        self.update_filter();
+    }
+
+    pub fn handle_filter_backspace(&mut self) {
+        self.filter_input.pop();
+        self.update_filter();
+    }
+
+    fn update_filter(&mut self) {
+        self.selected_id = self.get_selected_record().map(|r| r.id);
+        self.filter = RecordFilter::parse(&self.filter_input);
+        self.keep_selection(0);
+    }
+
+    pub fn open_quick_add(&mut self) {
+        self.input_buffer.clear();
+        self.mode = AppMode::QuickAdd;
+    }
+
+    pub fn close_quick_add(&mut self) {
+        self.input_buffer.clear();
+        self.mode = AppMode::Browse;
+    }
+
+    pub fn handle_quick_add_char(&mut self, c: char) {
+        self.input_buffer.push(c);
+    }
+
+    pub fn handle_quick_add_backspace(&mut self) {
+        self.input_buffer.pop();
+    }
+
+    /// Parse the quick-add input and add the resulting record
+    ///
+    /// Entries for the viewed day are added in memory (undoable, caller saves);
+    /// entries for another day are written to that day's file and the view jumps there.
+    /// On a parse error the prompt stays open so the input can be corrected.
+    pub fn submit_quick_add(
+        &mut self,
+        storage: &mut crate::storage::StorageManager,
+    ) -> Result<(), String> {
+        use crate::models::QuickEntry;
+        use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+        let local_offset = UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC);
+        let now = OffsetDateTime::now_utc().to_offset(local_offset);
+        let now = PrimitiveDateTime::new(now.date(), now.time());
+
+        let entry = QuickEntry::parse(&self.input_buffer, now, self.current_date)?;
+
+        if entry.date == self.current_date {
+            self.save_snapshot();
+            let id = self.day_data.next_id();
+            self.day_data.add_record(entry.into_record(id));
+
+            self.clear_filter();
+            self.selected_id = Some(id);
+        } else {
+            let date = entry.date;
+            let mut day_data = storage
+                .load_with_tracking(date)
+                .map_err(|e| e.to_string())?;
+            let id = day_data.next_id();
+            storage
+                .add_record(date, entry.into_record(id))
+                .map_err(|e| e.to_string())?;
+
+            self.current_date = date;
+            self.date_changed = true;
+        }
+
+        self.close_quick_add();
+        Ok(())
+    }
+
     /// Start a new timer with the current selected task
     pub fn start_timer_for_selected(
         &mut self,
@@ -965,7 +2148,7 @@ impl AppState {
                     match storage.load_with_tracking(self.current_date) {
                         Ok(new_day_data) => {
                             self.day_data = new_day_data;
-                            self.selected_index = 0;
+                            self.select_row(0);
                             Ok(())
                         }
                         Err(e) => Err(format!("Failed to reload day data: {}", e)),
@@ -1029,15 +2212,12 @@ impl AppState {
 
         // Check if day data file has been modified
         if let Ok(Some(new_data)) = storage.check_and_reload(self.current_date) {
+            // Stay on the same row if the selected record is gone
+            let row = self.selected_index();
             self.day_data = new_data;
+            self.keep_selection(row);
             self.last_file_modified = storage.get_last_modified(&self.current_date);
 
-            // Adjust selected_index if it's now out of bounds
-            let record_count = self.day_data.work_records.len();
-            if self.selected_index >= record_count && record_count > 0 {
-                self.selected_index = record_count - 1;
-            }
-
             changed = true;
         }
 
@@ -1111,6 +2291,540 @@ fn days_in_month(month: time::Month, year: i32) -> u8 {
     }
 }
 
+/// Apply a change to a stored day, returning the change that reverts it
+fn apply_day_change(
+    change: &DayChange,
+    storage: &mut crate::storage::StorageManager,
+) -> Result<DayChange, String> {
+    let mut day = storage
+        .load_with_tracking(change.date)
+        .map_err(|e| format!("Failed to load records: {}", e))?;
+    let removed = change
+        .remove
+        .iter()
+        .filter_map(|id| day.remove_record(*id))
+        .collect();
+    let added = change
+        .add
+        .iter()
+        .map(|record| {
+            let id = day.next_id();
+            day.add_record(WorkRecord {
+                id,
+                ..record.clone()
+            });
+            id
+        })
+        .collect();
+    storage
+        .save(&day)
+        .map_err(|e| format!("Failed to save records: {}", e))?;
+
+    Ok(DayChange {
+        date: change.date,
+        remove: added,
+        add: removed,
+    })
+}
+
 fn is_leap_year(year: i32) -> bool {
     (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SortOrder;
+    use crate::models::TimePoint;
+    use crate::storage::StorageManager;
+    use tempfile::TempDir;
+    use time::macros::date;
+
+    fn create_app() -> AppState {
+        let mut day = DayData::new(date!(2026 - 10 - 17));
+        for (id, name, start, end) in [(1, "Coding", 9, 10), (2, "Review", 10, 11)] {
+            day.add_record(WorkRecord::new(
+                id,
+                name.to_string(),
+                TimePoint::new(start, 0).unwrap(),
+                TimePoint::new(end, 0).unwrap(),
+            ));
+        }
+        let mut app = AppState::new(day);
+        // Tests don't depend on the user's config
+        app.config = Config::default();
+        app.last_error_message = None;
+        app
+    }
+
+    fn create_storage() -> (TempDir, StorageManager) {
+        let dir = TempDir::new().unwrap();
+        let storage = StorageManager::new_with_dir(dir.path().to_path_buf()).unwrap();
+        (dir, storage)
+    }
+
+    fn jira_config(base_url: &str) -> Config {
+        toml::from_str(&format!(
+            r#"
+[integrations.trackers.jira]
+enabled = true
+base_url = "{}"
+ticket_patterns = ["^PROJ-\\d+$"]
+sync = "jira"
+api_token = "secret"
+            "#,
+            base_url
+        ))
+        .unwrap()
+    }
+
+    /// Wait for background requests to report back
+    fn wait_for_fetches(app: &mut AppState, storage: &StorageManager) {
+        for _ in 0..500 {
+            app.receive_fetched(storage);
+            if app.fetching_title.is_none() && !app.loading_assigned_issues {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        panic!("background fetch did not finish");
+    }
+
+    #[test]
+    fn test_ticket_title_is_fetched_in_the_background() {
+        let (url, _requests) = crate::integrations::mock_server::serve(vec![(
+            200,
+            r#"{"fields":{"summary":"Fix login"}}"#,
+        )]);
+        let (_dir, storage) = create_storage();
+        let mut app = create_app();
+        app.config = jira_config(&url);
+        app.enter_edit_mode();
+        app.input_buffer = "PROJ-1".to_string();
+
+        assert!(app.offer_ticket_title(&storage));
+        assert_eq!(app.fetching_title.as_deref(), Some("PROJ-1"));
+        // Enter while the title is on its way saves the name as typed
+        assert!(!app.offer_ticket_title(&storage));
+
+        wait_for_fetches(&mut app, &storage);
+        assert_eq!(app.input_buffer, "PROJ-1 - Fix login");
+        assert_eq!(
+            storage.load_ticket_titles().unwrap()["jira:PROJ-1"],
+            "Fix login"
+        );
+
+        // Cached now: offered right away without a request
+        app.input_buffer = "PROJ-1".to_string();
+        assert!(app.offer_ticket_title(&storage));
+        assert_eq!(app.input_buffer, "PROJ-1 - Fix login");
+        assert!(app.fetching_title.is_none());
+    }
+
+    #[test]
+    fn test_late_ticket_title_does_not_overwrite_new_input() {
+        let (url, _requests) = crate::integrations::mock_server::serve(vec![(
+            200,
+            r#"{"fields":{"summary":"Fix login"}}"#,
+        )]);
+        let (_dir, storage) = create_storage();
+        let mut app = create_app();
+        app.config = jira_config(&url);
+        app.enter_edit_mode();
+        app.input_buffer = "PROJ-1".to_string();
+
+        assert!(app.offer_ticket_title(&storage));
+        app.handle_char_input('2');
+
+        wait_for_fetches(&mut app, &storage);
+        assert_eq!(app.input_buffer, "PROJ-12");
+    }
+
+    #[test]
+    fn test_assigned_issues_are_fetched_in_the_background() {
+        let (url, _requests) = crate::integrations::mock_server::serve(vec![(
+            200,
+            r#"{"issues":[{"key":"PROJ-5","fields":{"summary":"Ship it"}}]}"#,
+        )]);
+        let (_dir, storage) = create_storage();
+        let mut app = create_app();
+        app.config = jira_config(&url);
+        app.open_issue_picker();
+
+        app.refresh_assigned_issues();
+        assert!(app.loading_assigned_issues);
+        // A second Ctrl+R while loading doesn't start another request
+        app.refresh_assigned_issues();
+
+        wait_for_fetches(&mut app, &storage);
+        assert!(app.last_error_message.is_none());
+        assert!(
+            app.get_task_picker_names()
+                .contains(&"PROJ-5 - Ship it".to_string())
+        );
+        assert_eq!(storage.load_assigned_issues().unwrap()["jira"].len(), 1);
+        assert_eq!(
+            storage.load_ticket_titles().unwrap()["jira:PROJ-5"],
+            "Ship it"
+        );
+    }
+
+    fn add_record(app: &mut AppState, id: u32, name: &str, start: u8, end: u8) {
+        app.day_data.add_record(WorkRecord::new(
+            id,
+            name.to_string(),
+            TimePoint::new(start, 0).unwrap(),
+            TimePoint::new(end, 0).unwrap(),
+        ));
+    }
+
+    fn selected_name(app: &AppState) -> &str {
+        &app.get_selected_record().unwrap().name
+    }
+
+    #[test]
+    fn test_paste_selects_the_first_copy_and_undoes_in_one_step() {
+        let (_dir, mut storage) = create_storage();
+        let mut app = create_app();
+        app.move_selection_down();
+        app.enter_visual_mode();
+        app.move_selection_up();
+        app.yank_visual_selection();
+        assert_eq!(app.yanked_count(), 2);
+
+        // A filter that hides the copies is dropped
+        app.handle_filter_char('R');
+        app.paste_yanked(1);
+        assert!(!app.is_filtered());
+        assert_eq!(app.day_data.work_records.len(), 4);
+        let selected = app.get_selected_record().unwrap();
+        assert_eq!((selected.id, selected.name.as_str()), (3, "Coding"));
+
+        // The copy is gone again; the selection stays on its row
+        let row = app.selected_index();
+        app.undo(&mut storage);
+        assert_eq!(app.day_data.work_records.len(), 2);
+        assert_eq!(app.selected_index(), row);
+    }
+
+    #[test]
+    fn test_paste_with_nothing_yanked_changes_nothing() {
+        let (_dir, mut storage) = create_storage();
+        let mut app = create_app();
+        app.paste_yanked(1);
+        assert!(app.last_error_message.is_some());
+        assert_eq!(app.day_data.work_records.len(), 2);
+
+        // No undo step was recorded
+        app.day_data.remove_record(1);
+        app.undo(&mut storage);
+        assert_eq!(app.day_data.work_records.len(), 1);
+    }
+
+    #[test]
+    fn test_selection_follows_the_record_through_the_filter() {
+        let mut app = create_app();
+        app.move_selection_down();
+        for c in "rev".chars() {
+            app.handle_filter_char(c);
+        }
+        assert_eq!(app.selected_index(), 0);
+        assert_eq!(selected_name(&app), "Review");
+
+        // The selected record is filtered out: the first shown one is selected
+        for _ in 0..3 {
+            app.handle_filter_backspace();
+        }
+        for c in "cod".chars() {
+            app.handle_filter_char(c);
+        }
+        assert_eq!(selected_name(&app), "Coding");
+        app.cancel_filter();
+        assert_eq!(selected_name(&app), "Coding");
+        assert_eq!(app.selected_index(), 0);
+    }
+
+    #[test]
+    fn test_selection_follows_the_record_through_sort() {
+        let mut app = create_app();
+        add_record(&mut app, 3, "Admin", 11, 12);
+        app.move_selection_down();
+        assert_eq!(selected_name(&app), "Review");
+
+        app.config.table.sort = SortOrder::Name;
+        assert_eq!(selected_name(&app), "Review");
+        assert_eq!(app.selected_index(), 2);
+    }
+
+    #[test]
+    fn test_bulk_edit_applies_to_the_visual_selection_as_one_step() {
+        let (_dir, mut storage) = create_storage();
+        let mut app = create_app();
+        add_record(&mut app, 3, "Admin", 11, 12);
+        app.enter_visual_mode();
+        app.move_selection_down();
+        app.open_bulk_edit(BulkEditField::Description);
+        assert_eq!(app.bulk_edit_count(), 2);
+        for c in "Pairing".chars() {
+            app.handle_bulk_edit_char(c);
+        }
+        app.submit_bulk_edit().unwrap();
+        assert!(matches!(app.mode, AppMode::Browse));
+        let descriptions: Vec<&str> = app
+            .day_data
+            .get_sorted_records()
+            .iter()
+            .map(|r| r.description.as_str())
+            .collect();
+        assert_eq!(descriptions, vec!["Pairing", "Pairing", ""]);
+
+        // The selection is still on the second record
+        app.enter_visual_mode();
+        app.move_selection_down();
+        app.open_bulk_edit(BulkEditField::Shift);
+        assert_eq!(app.input_buffer, "");
+        app.input_buffer = "soon".to_string();
+        assert!(app.submit_bulk_edit().is_err());
+        assert!(matches!(app.mode, AppMode::BulkEdit));
+        app.input_buffer = "+30".to_string();
+        app.submit_bulk_edit().unwrap();
+        let starts: Vec<TimePoint> = app
+            .day_data
+            .get_sorted_records()
+            .iter()
+            .map(|r| r.start)
+            .collect();
+        assert_eq!(
+            starts,
+            vec![
+                TimePoint::new(9, 0).unwrap(),
+                TimePoint::new(10, 30).unwrap(),
+                TimePoint::new(11, 30).unwrap(),
+            ]
+        );
+
+        app.undo(&mut storage);
+        assert_eq!(
+            app.day_data.work_records[&2].start,
+            TimePoint::new(10, 0).unwrap()
+        );
+        assert_eq!(app.day_data.work_records[&2].description, "Pairing");
+        app.undo(&mut storage);
+        assert_eq!(app.day_data.work_records[&1].description, "");
+        assert_eq!(app.day_data.work_records[&2].description, "");
+    }
+
+    #[test]
+    fn test_split_selects_the_second_part() {
+        let mut app = create_app();
+        app.open_split();
+        app.input_buffer = "12:00".to_string();
+        assert!(app.submit_split().is_err());
+        assert!(matches!(app.mode, AppMode::Split));
+
+        app.input_buffer = "09:20 Meeting".to_string();
+        app.submit_split().unwrap();
+        assert!(matches!(app.mode, AppMode::Browse));
+        let selected = app.get_selected_record().unwrap();
+        assert_eq!(selected.name, "Meeting");
+        assert_eq!(selected.start, TimePoint::new(9, 20).unwrap());
+        assert_eq!(selected.end, TimePoint::new(10, 0).unwrap());
+        assert_eq!(
+            app.day_data.work_records[&1].end,
+            TimePoint::new(9, 20).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_merge_with_next_joins_as_one_step() {
+        let (_dir, mut storage) = create_storage();
+        let mut app = create_app();
+        add_record(&mut app, 3, "Admin", 12, 13);
+
+        // Stops at the gap before 12:00 without changing anything
+        app.merge_with_next(2);
+        assert!(app.last_error_message.take().is_some());
+        assert_eq!(app.day_data.work_records.len(), 3);
+
+        app.merge_with_next(1);
+        assert_eq!(app.day_data.work_records.len(), 2);
+        let selected = app.get_selected_record().unwrap();
+        assert_eq!(selected.name, "Coding");
+        assert_eq!(selected.end, TimePoint::new(11, 0).unwrap());
+
+        app.undo(&mut storage);
+        assert_eq!(app.day_data.work_records.len(), 3);
+    }
+
+    #[test]
+    fn test_nudge_moves_the_selected_column_by_the_step() {
+        let (_dir, mut storage) = create_storage();
+        let mut app = create_app();
+        app.nudge_selected_time(1);
+        assert!(app.last_error_message.take().is_some());
+
+        app.move_field_right();
+        app.nudge_selected_time(1);
+        assert_eq!(
+            app.day_data.work_records[&1].start,
+            TimePoint::new(9, 5).unwrap()
+        );
+        app.cycle_nudge_step();
+        app.nudge_selected_time(-2);
+        assert_eq!(
+            app.day_data.work_records[&1].start,
+            TimePoint::new(8, 35).unwrap()
+        );
+        assert_eq!(app.day_data.work_records[&1].total_minutes, 85);
+
+        app.undo(&mut storage);
+        assert_eq!(
+            app.day_data.work_records[&1].start,
+            TimePoint::new(9, 5).unwrap()
+        );
+
+        // With nudge_adjacent the touching record's start moves along
+        app.config.table.nudge_adjacent = true;
+        app.move_field_right();
+        app.nudge_selected_time(1);
+        assert_eq!(
+            app.day_data.work_records[&1].end,
+            TimePoint::new(10, 15).unwrap()
+        );
+        assert_eq!(
+            app.day_data.work_records[&2].start,
+            TimePoint::new(10, 15).unwrap()
+        );
+
+        app.undo(&mut storage);
+        assert_eq!(
+            app.day_data.work_records[&1].end,
+            TimePoint::new(10, 0).unwrap()
+        );
+        assert_eq!(
+            app.day_data.work_records[&2].start,
+            TimePoint::new(10, 0).unwrap()
+        );
+    }
+
+    fn names_on(storage: &mut StorageManager, date: Date) -> Vec<String> {
+        let mut names: Vec<String> = storage
+            .load_with_tracking(date)
+            .unwrap()
+            .work_records
+            .into_values()
+            .map(|r| r.name)
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_undo_and_redo_of_a_move_keep_later_changes_to_the_other_day() {
+        let (_dir, mut storage) = create_storage();
+        let mut app = create_app();
+        let target = date!(2026 - 10 - 18);
+
+        app.open_move_to_date();
+        app.calendar_selected_date = target;
+        app.calendar_select_date(&mut storage);
+        assert_eq!(app.day_data.work_records.len(), 1);
+        assert_eq!(names_on(&mut storage, target), vec!["Coding"]);
+
+        // Meanwhile the other day gets another record, e.g. from the CLI
+        let mut other = storage.load_with_tracking(target).unwrap();
+        let id = other.next_id();
+        other.add_record(WorkRecord::new(
+            id,
+            "Standup".to_string(),
+            TimePoint::new(8, 0).unwrap(),
+            TimePoint::new(8, 15).unwrap(),
+        ));
+        storage.save(&other).unwrap();
+
+        app.undo(&mut storage);
+        assert!(app.last_error_message.is_none());
+        assert_eq!(app.day_data.work_records.len(), 2);
+        assert_eq!(names_on(&mut storage, target), vec!["Standup"]);
+
+        app.redo(&mut storage);
+        assert_eq!(app.day_data.work_records.len(), 1);
+        assert_eq!(names_on(&mut storage, target), vec!["Coding", "Standup"]);
+
+        app.undo(&mut storage);
+        assert_eq!(names_on(&mut storage, target), vec!["Standup"]);
+        assert_eq!(app.day_data.work_records.len(), 2);
+    }
+
+    #[test]
+    fn test_undo_of_a_move_skips_records_already_gone_from_the_other_day() {
+        let (_dir, mut storage) = create_storage();
+        let mut app = create_app();
+        let target = date!(2026 - 10 - 18);
+
+        app.enter_visual_mode();
+        app.move_selection_down();
+        app.open_move_visual_selection();
+        app.calendar_selected_date = target;
+        app.calendar_select_date(&mut storage);
+        assert!(app.day_data.work_records.is_empty());
+
+        let mut other = storage.load_with_tracking(target).unwrap();
+        let review = other
+            .work_records
+            .values()
+            .find(|r| r.name == "Review")
+            .unwrap()
+            .id;
+        other.remove_record(review);
+        storage.save(&other).unwrap();
+
+        app.undo(&mut storage);
+        assert_eq!(app.day_data.work_records.len(), 2);
+        assert!(names_on(&mut storage, target).is_empty());
+    }
+
+    #[test]
+    fn test_counted_add_chains_records_in_one_step() {
+        let (_dir, mut storage) = create_storage();
+        let mut app = create_app();
+        app.move_selection_down();
+        app.add_breaks(3);
+        let breaks: Vec<(TimePoint, TimePoint)> = app
+            .day_data
+            .get_sorted_records()
+            .iter()
+            .filter(|r| r.name == "Break")
+            .map(|r| (r.start, r.end))
+            .collect();
+        assert_eq!(
+            breaks,
+            vec![
+                (
+                    TimePoint::new(11, 0).unwrap(),
+                    TimePoint::new(11, 15).unwrap()
+                ),
+                (
+                    TimePoint::new(11, 15).unwrap(),
+                    TimePoint::new(11, 30).unwrap()
+                ),
+                (
+                    TimePoint::new(11, 30).unwrap(),
+                    TimePoint::new(11, 45).unwrap()
+                ),
+            ]
+        );
+        assert_eq!(
+            app.get_selected_record().unwrap().end,
+            TimePoint::new(11, 45).unwrap()
+        );
+
+        app.yank_selected_record();
+        app.paste_yanked(2);
+        assert_eq!(app.day_data.work_records.len(), 7);
+
+        app.undo(&mut storage);
+        app.undo(&mut storage);
+        assert_eq!(app.day_data.work_records.len(), 2);
+    }
+}
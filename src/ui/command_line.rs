@@ -0,0 +1,87 @@
+//! The `:` command line of browse mode
+
+use crate::models::parse_date;
+use time::Date;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExCommand {
+    /// `:date 2026-10-01`, `:date yesterday`; today without a date
+    Date(Date),
+    /// `:w`, save the day
+    Write,
+    /// `:q`, `:wq`, `:x`; the day is saved on the way out
+    Quit,
+    /// `:12`, select a row counting from 1
+    Row(usize),
+}
+
+impl ExCommand {
+    /// Parse what was typed after `:`, resolving dates against `today`
+    pub fn parse(input: &str, today: Date) -> Result<Self, String> {
+        let input = input.trim();
+        let (name, argument) = input.split_once(' ').unwrap_or((input, ""));
+        let argument = argument.trim();
+
+        if let Ok(row) = name.parse::<usize>() {
+            return Ok(ExCommand::Row(row));
+        }
+        let command = match name {
+            "date" | "d" => return parse_date(argument, today).map(ExCommand::Date),
+            "w" | "write" => ExCommand::Write,
+            "q" | "quit" | "wq" | "x" => ExCommand::Quit,
+            "" => return Err("Type a command, e.g. :date 2026-10-01".to_string()),
+            _ => return Err(format!("Unknown command: {}", name)),
+        };
+        if argument.is_empty() {
+            Ok(command)
+        } else {
+            Err(format!(":{} takes no argument", name))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::date;
+
+    const TODAY: Date = date!(2026 - 10 - 17);
+
+    fn parse(input: &str) -> Result<ExCommand, String> {
+        ExCommand::parse(input, TODAY)
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(
+            parse("date 2026-10-01"),
+            Ok(ExCommand::Date(date!(2026 - 10 - 01)))
+        );
+        assert_eq!(
+            parse(" d yesterday "),
+            Ok(ExCommand::Date(date!(2026 - 10 - 16)))
+        );
+        assert_eq!(parse("date"), Ok(ExCommand::Date(TODAY)));
+        assert!(parse("date someday").is_err());
+    }
+
+    #[test]
+    fn test_write_quit_and_rows() {
+        assert_eq!(parse("w"), Ok(ExCommand::Write));
+        assert_eq!(parse("write"), Ok(ExCommand::Write));
+        assert_eq!(parse("q"), Ok(ExCommand::Quit));
+        assert_eq!(parse("wq"), Ok(ExCommand::Quit));
+        assert_eq!(parse("x"), Ok(ExCommand::Quit));
+        assert_eq!(parse("12"), Ok(ExCommand::Row(12)));
+    }
+
+    #[test]
+    fn test_rejects_unknown_input() {
+        assert_eq!(
+            parse("frobnicate"),
+            Err("Unknown command: frobnicate".into())
+        );
+        assert_eq!(parse("w file.json"), Err(":w takes no argument".into()));
+        assert!(parse("").is_err());
+    }
+}
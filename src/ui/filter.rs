@@ -0,0 +1,217 @@
+//! Live filter and sort order of the record table
+
+use crate::config::{Config, SortOrder};
+use crate::integrations::extract_tickets;
+use crate::models::{WorkRecord, parse_duration};
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+
+/// A parsed filter query
+///
+/// Words are fuzzy-matched against the task name and description. Some words are
+/// conditions instead: `>1h`, `<=30m` (duration), `ticket:PROJ` (a ticket starting with
+/// `PROJ`; `ticket:` alone for any ticket) and `#tag` (a `#tag` word). All have to hold.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecordFilter {
+    text: String,
+    conditions: Vec<Condition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    LongerThan(u32),
+    AtLeast(u32),
+    ShorterThan(u32),
+    AtMost(u32),
+    Ticket(String),
+    Tag(String),
+}
+
+impl RecordFilter {
+    pub fn parse(query: &str) -> Self {
+        let mut text = Vec::new();
+        let mut conditions = Vec::new();
+        for word in query.split_whitespace() {
+            match parse_condition(word) {
+                Some(condition) => conditions.push(condition),
+                None => text.push(word),
+            }
+        }
+        RecordFilter {
+            text: text.join(" "),
+            conditions,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty() && self.conditions.is_empty()
+    }
+
+    pub fn matches(&self, record: &WorkRecord, config: &Config) -> bool {
+        if !self.text.is_empty() {
+            let haystack = format!("{} {}", record.name, record.description);
+            if SkimMatcherV2::default()
+                .fuzzy_match(&haystack, &self.text)
+                .is_none()
+            {
+                return false;
+            }
+        }
+
+        self.conditions.iter().all(|condition| match condition {
+            Condition::LongerThan(minutes) => record.total_minutes > *minutes,
+            Condition::AtLeast(minutes) => record.total_minutes >= *minutes,
+            Condition::ShorterThan(minutes) => record.total_minutes < *minutes,
+            Condition::AtMost(minutes) => record.total_minutes <= *minutes,
+            Condition::Ticket(prefix) => extract_tickets(&record.name, config)
+                .iter()
+                .any(|ticket| ticket.key().to_lowercase().starts_with(prefix)),
+            Condition::Tag(tag) => record
+                .name
+                .split_whitespace()
+                .chain(record.description.split_whitespace())
+                .any(|word| word.to_lowercase() == *tag),
+        })
+    }
+}
+
+/// Sort records for the table, by start time where the order has ties
+pub fn sort_records(records: &mut [&WorkRecord], order: SortOrder, config: &Config) {
+    match order {
+        SortOrder::Start => records.sort_by_key(|r| (r.start, r.id)),
+        SortOrder::Duration => {
+            records.sort_by_key(|r| (std::cmp::Reverse(r.total_minutes), r.start, r.id))
+        }
+        SortOrder::Name => records.sort_by_cached_key(|r| (r.name.to_lowercase(), r.start, r.id)),
+        SortOrder::Ticket => records.sort_by_cached_key(|r| {
+            let ticket = extract_tickets(&r.name, config)
+                .first()
+                .map(|ticket| ticket.key().to_lowercase());
+            (ticket.is_none(), ticket, r.start, r.id)
+        }),
+    }
+}
+
+fn parse_condition(word: &str) -> Option<Condition> {
+    if let Some(prefix) = word.strip_prefix("ticket:") {
+        return Some(Condition::Ticket(prefix.to_lowercase()));
+    }
+    if word.len() > 1 && word.starts_with('#') && !word[1..].chars().all(|c| c.is_ascii_digit()) {
+        return Some(Condition::Tag(word.to_lowercase()));
+    }
+
+    let (make, duration): (fn(u32) -> Condition, &str) = if let Some(d) = word.strip_prefix(">=") {
+        (Condition::AtLeast, d)
+    } else if let Some(d) = word.strip_prefix("<=") {
+        (Condition::AtMost, d)
+    } else if let Some(d) = word.strip_prefix('>') {
+        (Condition::LongerThan, d)
+    } else if let Some(d) = word.strip_prefix('<') {
+        (Condition::ShorterThan, d)
+    } else {
+        return None;
+    };
+    parse_duration(duration).map(make)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TimePoint;
+
+    fn record_at(id: u32, name: &str, start: u32, minutes: u32) -> WorkRecord {
+        WorkRecord::new(
+            id,
+            name.to_string(),
+            TimePoint::from_minutes_since_midnight(start * 60).unwrap(),
+            TimePoint::from_minutes_since_midnight(start * 60 + minutes).unwrap(),
+        )
+    }
+
+    fn record(name: &str, minutes: u32, description: &str) -> WorkRecord {
+        let mut record = WorkRecord::new(
+            1,
+            name.to_string(),
+            TimePoint::new(9, 0).unwrap(),
+            TimePoint::from_minutes_since_midnight(9 * 60 + minutes).unwrap(),
+        );
+        record.description = description.to_string();
+        record
+    }
+
+    fn matches(query: &str, record: &WorkRecord) -> bool {
+        RecordFilter::parse(query).matches(record, &Config::default())
+    }
+
+    #[test]
+    fn test_parse_splits_conditions_from_text() {
+        let filter = RecordFilter::parse("code >1h ticket:PROJ rev #billable >soon");
+        assert_eq!(filter.text, "code rev >soon");
+        assert_eq!(
+            filter.conditions,
+            vec![
+                Condition::LongerThan(60),
+                Condition::Ticket("proj".to_string()),
+                Condition::Tag("#billable".to_string()),
+            ]
+        );
+        assert!(RecordFilter::parse("  ").is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_text() {
+        let review = record("PROJ-7 Code review", 30, "Login PR");
+        assert!(matches("cdrev", &review));
+        assert!(matches("login", &review));
+        assert!(!matches("deploy", &review));
+        assert!(matches("", &review));
+    }
+
+    #[test]
+    fn test_duration_conditions() {
+        let hour = record("Meeting", 60, "");
+        assert!(matches(">30m", &hour));
+        assert!(!matches(">1h", &hour));
+        assert!(matches(">=1h", &hour));
+        assert!(matches("<1h30m", &hour));
+        assert!(!matches("<1h", &hour));
+        assert!(matches("<=60m meet", &hour));
+    }
+
+    #[test]
+    fn test_ticket_and_tag_conditions() {
+        let ticket = record("PROJ-42 Fix login", 30, "Pairing #billable");
+        assert!(matches("ticket:PROJ", &ticket));
+        assert!(matches("ticket:proj-42", &ticket));
+        assert!(matches("ticket:", &ticket));
+        assert!(!matches("ticket:OPS", &ticket));
+        assert!(!matches("ticket:", &record("Standup", 15, "")));
+
+        assert!(matches("#billable", &ticket));
+        assert!(matches("#BILLABLE ticket:PROJ", &ticket));
+        assert!(!matches("#bill", &ticket));
+        // `#42` is an issue number, matched as text
+        assert!(matches("#42", &record("#42 docs", 15, "")));
+    }
+
+    #[test]
+    fn test_sort_records() {
+        let records = [
+            record_at(1, "standup", 9, 15),
+            record_at(2, "PROJ-9 Login", 10, 90),
+            record_at(3, "Break", 12, 30),
+            record_at(4, "OPS-1 Deploy", 13, 90),
+            record_at(5, "PROJ-9 Login", 15, 60),
+        ];
+        let sorted = |order| {
+            let mut refs: Vec<&WorkRecord> = records.iter().collect();
+            sort_records(&mut refs, order, &Config::default());
+            refs.iter().map(|r| r.id).collect::<Vec<u32>>()
+        };
+
+        assert_eq!(sorted(SortOrder::Start), vec![1, 2, 3, 4, 5]);
+        assert_eq!(sorted(SortOrder::Duration), vec![2, 4, 5, 3, 1]);
+        assert_eq!(sorted(SortOrder::Name), vec![3, 4, 2, 5, 1]);
+        assert_eq!(sorted(SortOrder::Ticket), vec![4, 2, 5, 1, 3]);
+    }
+}
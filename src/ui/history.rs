@@ -1,19 +1,66 @@
-use crate::models::DayData;
+use crate::models::{DayData, WorkRecord};
+use time::Date;
 
 const MAX_HISTORY_DEPTH: usize = 50;
 
-#[derive(Debug, Default)]
-pub struct History {
-    undo_stack: Vec<DayData>,
-    redo_stack: Vec<DayData>,
+/// State restored by one undo step
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    /// The viewed day
+    pub day: DayData,
+    /// Change to another day's file that goes with it, e.g. taking back moved records
+    pub other_day: Option<DayChange>,
 }
 
-impl History {
+/// Records to take out of and put into another day's file
+///
+/// Only these records are touched, so edits made to that day in the meantime survive an
+/// undo or redo.
+#[derive(Debug, Clone)]
+pub struct DayChange {
+    pub date: Date,
+    /// Ids of records to remove
+    pub remove: Vec<u32>,
+    /// Records to add, with new ids
+    pub add: Vec<WorkRecord>,
+}
+
+/// Undo history of whole day states
+#[allow(dead_code)]
+pub type History = UndoHistory<DayData>;
+
+/// Bounded undo and redo stacks of states of type `T`
+#[derive(Debug)]
+pub struct UndoHistory<T> {
+    undo_stack: Vec<T>,
+    redo_stack: Vec<T>,
+}
+
+impl<T> Default for UndoHistory<T> {
+    fn default() -> Self {
+        UndoHistory {
+            undo_stack: Vec::new(),
+            redo_stack: Vec::new(),
+        }
+    }
+}
+
+impl<T> UndoHistory<T> {
     pub fn new() -> Self {
         Self::default()
     }
 
-    pub fn push(&mut self, state: DayData) {
+    /// The state the next undo returns
+    pub fn peek_undo(&self) -> Option<&T> {
+        self.undo_stack.last()
+    }
+
+    /// The state the next redo returns
+    pub fn peek_redo(&self) -> Option<&T> {
+        self.redo_stack.last()
+    }
+
+    pub fn push(&mut self, state: T) {
         if self.undo_stack.len() >= MAX_HISTORY_DEPTH {
             self.undo_stack.remove(0);
         }
@@ -21,7 +68,7 @@ impl History {
         self.redo_stack.clear();
     }
 
-    pub fn undo(&mut self, current_state: DayData) -> Option<DayData> {
+    pub fn undo(&mut self, current_state: T) -> Option<T> {
         if let Some(previous_state) = self.undo_stack.pop() {
             self.redo_stack.push(current_state);
             Some(previous_state)
@@ -30,7 +77,7 @@ impl History {
         }
     }
 
-    pub fn redo(&mut self, current_state: DayData) -> Option<DayData> {
+    pub fn redo(&mut self, current_state: T) -> Option<T> {
         if let Some(next_state) = self.redo_stack.pop() {
             self.undo_stack.push(current_state);
             Some(next_state)
@@ -148,6 +195,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_peek_shows_next_state_without_taking_it() {
+        let mut history = History::new();
+        assert!(history.peek_undo().is_none());
+
+        history.push(create_day_with_record(1, "Task1"));
+        assert_eq!(history.peek_undo().unwrap().work_records[&1].name, "Task1");
+        assert_eq!(history.undo_stack.len(), 1);
+
+        history.undo(create_day_with_record(2, "Task2"));
+        assert!(history.peek_undo().is_none());
+        assert_eq!(history.peek_redo().unwrap().work_records[&2].name, "Task2");
+    }
+
     #[test]
     fn test_undo_empty_history() {
         let mut history = History::new();
@@ -1,5 +1,9 @@
 pub mod app_state;
+pub mod command_line;
+pub mod filter;
 pub mod history;
 pub mod render;
+pub mod stats;
+pub mod timeline;
 
 pub use app_state::{AppMode, AppState, EditField};
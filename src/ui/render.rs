@@ -1,5 +1,8 @@
+use crate::config::SortOrder;
 use crate::timer::{TimerState, TimerStatus};
 use crate::ui::AppState;
+use crate::ui::app_state::CalendarTarget;
+use crate::ui::timeline::{Bar as TimelineBar, Timeline};
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
@@ -76,8 +79,12 @@ pub fn render(frame: &mut Frame, app: &AppState) {
         };
 
         render_header(frame, header_chunk, app);
-        render_records(frame, middle_chunks[0], app);
-        render_grouped_totals(frame, middle_chunks[1], app);
+        if matches!(app.mode, crate::ui::AppMode::Stats) {
+            render_stats(frame, content_chunk, app);
+        } else {
+            render_day(frame, middle_chunks[0], app);
+            render_grouped_totals(frame, middle_chunks[1], app);
+        }
         render_footer(frame, footer_chunk, app);
     } else {
         // Original layout without timer
@@ -95,8 +102,12 @@ pub fn render(frame: &mut Frame, app: &AppState) {
         };
 
         render_header(frame, chunks[0], app);
-        render_records(frame, middle_chunks[0], app);
-        render_grouped_totals(frame, middle_chunks[1], app);
+        if matches!(app.mode, crate::ui::AppMode::Stats) {
+            render_stats(frame, chunks[1], app);
+        } else {
+            render_day(frame, middle_chunks[0], app);
+            render_grouped_totals(frame, middle_chunks[1], app);
+        }
         render_footer(frame, chunks[2], app);
     }
 
@@ -115,6 +126,26 @@ pub fn render(frame: &mut Frame, app: &AppState) {
         render_task_picker(frame, app);
     }
 
+    // Render search modal if active
+    if matches!(app.mode, crate::ui::AppMode::Search) {
+        render_search(frame, app);
+    }
+
+    // Render quick-add prompt if active
+    if matches!(app.mode, crate::ui::AppMode::QuickAdd) {
+        render_quick_add(frame, app);
+    }
+
+    // Render bulk edit prompt if active
+    if matches!(app.mode, crate::ui::AppMode::BulkEdit) {
+        render_bulk_edit(frame, app);
+    }
+
+    // Render split prompt if active
+    if matches!(app.mode, crate::ui::AppMode::Split) {
+        render_split(frame, app);
+    }
+
     // Render error modal if there's an error
     if app.last_error_message.is_some() {
         render_error_modal(frame, app);
@@ -173,8 +204,26 @@ fn render_header(frame: &mut Frame, area: Rect, app: &AppState) {
     frame.render_widget(total, chunks[1]);
 }
 
+/// Whether a task name contains a ticket, for the 🎫 badge
+fn has_ticket(name: &str, app: &AppState) -> bool {
+    !crate::integrations::extract_tickets(name, &app.config).is_empty()
+}
+
 fn render_records(frame: &mut Frame, area: Rect, app: &AppState) {
-    let records = app.day_data.get_sorted_records();
+    let filtering = matches!(app.mode, crate::ui::AppMode::Filter);
+    let area = if filtering || app.is_filtered() {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(5)])
+            .split(area);
+        render_filter_prompt(frame, chunks[0], app, filtering);
+        chunks[1]
+    } else {
+        area
+    };
+
+    let records = app.visible_records();
+    let selected_index = app.selected_index();
 
     // Calculate how many rows can fit in the visible area
     // Account for: borders (2) + header (2) + margin (1) = 5 lines
@@ -182,8 +231,8 @@ fn render_records(frame: &mut Frame, area: Rect, app: &AppState) {
 
     // Calculate scroll offset to keep selected item visible
     let scroll_offset = if records.len() > available_height {
-        if app.selected_index >= available_height {
-            app.selected_index.saturating_sub(available_height - 1)
+        if selected_index >= available_height {
+            selected_index.saturating_sub(available_height - 1)
         } else {
             0
         }
@@ -195,7 +244,7 @@ fn render_records(frame: &mut Frame, area: Rect, app: &AppState) {
         .iter()
         .enumerate()
         .map(|(i, record)| {
-            let is_selected = i == app.selected_index;
+            let is_selected = i == selected_index;
             let is_editing = matches!(app.mode, crate::ui::AppMode::Edit) && is_selected;
             let is_in_visual =
                 matches!(app.mode, crate::ui::AppMode::Visual) && app.is_in_visual_selection(i);
@@ -254,9 +303,7 @@ fn render_records(frame: &mut Frame, area: Rect, app: &AppState) {
 
                         // Extract and display ticket badge if present and config exists
                         let display = if app.config.has_integrations() {
-                            if crate::integrations::extract_ticket_from_name(&app.input_buffer)
-                                .is_some()
-                            {
+                            if has_ticket(&app.input_buffer, app) {
                                 format!("🎫 {} {}", icon, text_with_cursor)
                             } else {
                                 format!("{} {}", icon, text_with_cursor)
@@ -277,8 +324,7 @@ fn render_records(frame: &mut Frame, area: Rect, app: &AppState) {
 
                         // Extract and display ticket badge if present and config exists
                         let display = if app.config.has_integrations() {
-                            if crate::integrations::extract_ticket_from_name(&record.name).is_some()
-                            {
+                            if has_ticket(&record.name, app) {
                                 format!("🎫 {} {}", icon, record.name)
                             } else {
                                 format!("{} {}", icon, record.name)
@@ -316,8 +362,7 @@ fn render_records(frame: &mut Frame, area: Rect, app: &AppState) {
 
                         // Extract and display ticket badge if present and config exists
                         let name_with_badge = if app.config.has_integrations() {
-                            if crate::integrations::extract_ticket_from_name(&record.name).is_some()
-                            {
+                            if has_ticket(&record.name, app) {
                                 format!("🎫 {} {}", icon, record.name)
                             } else {
                                 format!("{} {}", icon, record.name)
@@ -346,7 +391,7 @@ fn render_records(frame: &mut Frame, area: Rect, app: &AppState) {
             } else {
                 // Extract and display ticket badge if present and config exists (non-editing mode)
                 let name_with_badge = if app.config.has_integrations() {
-                    if crate::integrations::extract_ticket_from_name(&record.name).is_some() {
+                    if has_ticket(&record.name, app) {
                         format!("🎫 {} {}", icon, record.name)
                     } else {
                         format!("{} {}", icon, record.name)
@@ -463,7 +508,7 @@ fn render_records(frame: &mut Frame, area: Rect, app: &AppState) {
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(app.theme.active_border))
-            .title("📊 Work Records")
+            .title(records_title(app, records.len()))
             .title_style(
                 Style::default()
                     .fg(app.theme.highlight_text)
@@ -473,14 +518,60 @@ fn render_records(frame: &mut Frame, area: Rect, app: &AppState) {
 
     // Use stateful rendering to handle scrolling
     let mut table_state = TableState::default()
-        .with_selected(Some(app.selected_index))
+        .with_selected(Some(selected_index))
         .with_offset(scroll_offset);
 
     frame.render_stateful_widget(table, area, &mut table_state);
 }
 
+/// Table title, with the number of shown records when filtered and the sort order
+fn records_title(app: &AppState, shown: usize) -> String {
+    let mut title = "📊 Work Records".to_string();
+    if app.is_filtered() {
+        title.push_str(&format!(
+            " ({} of {})",
+            shown,
+            app.day_data.work_records.len()
+        ));
+    }
+    if app.config.table.sort != SortOrder::Start {
+        title.push_str(&format!(" ↓ {}", app.config.table.sort.as_str()));
+    }
+    title
+}
+
+fn render_filter_prompt(frame: &mut Frame, area: Rect, app: &AppState, editing: bool) {
+    let color = if editing {
+        app.theme.warning
+    } else {
+        app.theme.info
+    };
+    let text = if editing {
+        format!("{}▏", app.filter_input)
+    } else {
+        app.filter_input.clone()
+    };
+    let prompt = Paragraph::new(text)
+        .style(Style::default().fg(app.theme.primary_text))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(color))
+                .title("🔎 Filter")
+                .title_style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+                .padding(Padding::horizontal(1)),
+        );
+    frame.render_widget(prompt, area);
+}
+
 fn render_grouped_totals(frame: &mut Frame, area: Rect, app: &AppState) {
-    let grouped = app.day_data.get_grouped_totals();
+    let grouped = app.visible_grouped_totals();
+    let summary_title = if app.is_filtered() {
+        "📈 Summary (filtered)"
+    } else {
+        "📈 Summary"
+    };
 
     if grouped.is_empty() {
         let paragraph = Paragraph::new("No records yet")
@@ -491,7 +582,7 @@ fn render_grouped_totals(frame: &mut Frame, area: Rect, app: &AppState) {
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
                     .border_style(Style::default().fg(app.theme.warning))
-                    .title("📈 Summary")
+                    .title(summary_title)
                     .title_style(
                         Style::default()
                             .fg(app.theme.warning)
@@ -548,7 +639,7 @@ fn render_grouped_totals(frame: &mut Frame, area: Rect, app: &AppState) {
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(app.theme.warning))
-            .title("📈 Summary")
+            .title(summary_title)
             .title_style(
                 Style::default()
                     .fg(app.theme.warning)
@@ -559,23 +650,401 @@ fn render_grouped_totals(frame: &mut Frame, area: Rect, app: &AppState) {
     frame.render_widget(table, area);
 }
 
+/// Most timeline lanes drawn; further overlapping records share the last one
+const MAX_TIMELINE_LANES: usize = 4;
+
+/// The record table, with the timeline below it when shown
+fn render_day(frame: &mut Frame, area: Rect, app: &AppState) {
+    if !app.show_timeline {
+        render_records(frame, area, app);
+        return;
+    }
+
+    let timeline = build_timeline(app);
+    // Borders, hour labels, lanes and the summary line
+    let lanes = timeline.lanes.len().clamp(1, MAX_TIMELINE_LANES) as u16;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(6), Constraint::Length(lanes + 4)])
+        .split(area);
+
+    render_records(frame, chunks[0], app);
+    render_timeline(frame, chunks[1], app, &timeline);
+}
+
+fn build_timeline(app: &AppState) -> Timeline {
+    let mut bars: Vec<TimelineBar> = app
+        .day_data
+        .work_records
+        .values()
+        .map(TimelineBar::for_record)
+        .collect();
+
+    // The running timer, from its start to now
+    if let Some(timer) = &app.active_timer
+        && timer.start_time.date() == app.current_date
+    {
+        let start = u32::from(timer.start_time.hour()) * 60 + u32::from(timer.start_time.minute());
+        let elapsed = (calculate_timer_elapsed(timer).as_secs() / 60) as u32;
+        bars.push(TimelineBar {
+            id: None,
+            start,
+            end: (start + elapsed.max(1)).min(24 * 60),
+        });
+    }
+
+    Timeline::build(bars, &app.config.timeline)
+}
+
+fn render_timeline(frame: &mut Frame, area: Rect, app: &AppState, timeline: &Timeline) {
+    use ratatui::text::{Line, Span};
+
+    let width = area.width.saturating_sub(4);
+    let blank = (' ', Style::default());
+    let selected_id = app.get_selected_record().map(|r| r.id);
+
+    // Hour labels, skipping those that would run into the previous one
+    let mut axis = vec![blank; width as usize];
+    let mut free_from = 0;
+    for hour in timeline.from / 60..=timeline.to / 60 {
+        let column = timeline.column(hour * 60, width) as usize;
+        let label = format!("{:02}", hour % 24);
+        if column < free_from || column + label.len() > axis.len() {
+            continue;
+        }
+        for (i, c) in label.chars().enumerate() {
+            axis[column + i] = (c, Style::default().fg(app.theme.secondary_text));
+        }
+        free_from = column + label.len() + 1;
+    }
+
+    let mut rows = vec![axis];
+    let lane_count = timeline.lanes.len().clamp(1, MAX_TIMELINE_LANES);
+    for lane in 0..lane_count {
+        let mut cells = vec![blank; width as usize];
+        if lane == 0 {
+            for &(start, end) in &timeline.gaps {
+                let (from, to) = timeline_columns(timeline, start, end, width);
+                for cell in &mut cells[from..to] {
+                    *cell = ('░', Style::default().fg(app.theme.warning));
+                }
+            }
+        }
+
+        let bars = if lane + 1 == lane_count {
+            timeline
+                .lanes
+                .iter()
+                .skip(lane)
+                .flatten()
+                .collect::<Vec<_>>()
+        } else {
+            timeline.lanes.get(lane).into_iter().flatten().collect()
+        };
+        for bar in bars {
+            let (from, to) = timeline_columns(timeline, bar.start, bar.end, width);
+            let (label, style) = match bar.id {
+                None => (
+                    "▶".to_string(),
+                    Style::default()
+                        .bg(app.theme.success)
+                        .fg(app.theme.edit_bg)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Some(id) => {
+                    let name = app
+                        .day_data
+                        .work_records
+                        .get(&id)
+                        .map(|r| r.name.clone())
+                        .unwrap_or_default();
+                    let style = if Some(id) == selected_id {
+                        Style::default()
+                            .bg(app.theme.highlight_text)
+                            .fg(app.theme.edit_bg)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().bg(app.theme.info).fg(app.theme.edit_bg)
+                    };
+                    (name, style)
+                }
+            };
+            let mut label = label.chars();
+            for cell in &mut cells[from..to] {
+                *cell = (label.next().unwrap_or(' '), style);
+            }
+        }
+
+        for &(start, end) in &timeline.overlaps {
+            let (from, to) = timeline_columns(timeline, start, end, width);
+            for cell in &mut cells[from..to] {
+                if cell.1.bg.is_some() {
+                    cell.1 = cell.1.bg(app.theme.error);
+                }
+            }
+        }
+        rows.push(cells);
+    }
+
+    let mut lines: Vec<Line> = rows
+        .into_iter()
+        .map(|cells| {
+            // One span per run of equally styled cells
+            let mut spans: Vec<Span> = Vec::new();
+            let mut run = String::new();
+            let mut run_style = Style::default();
+            for (c, style) in cells {
+                if style != run_style && !run.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut run), run_style));
+                }
+                run_style = style;
+                run.push(c);
+            }
+            spans.push(Span::styled(run, run_style));
+            Line::from(spans)
+        })
+        .collect();
+
+    let logged: u32 = app
+        .day_data
+        .work_records
+        .values()
+        .map(|r| r.total_minutes)
+        .sum();
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("Logged {}", format_hours(logged)),
+            Style::default().fg(app.theme.success),
+        ),
+        Span::styled(" · ", Style::default().fg(app.theme.secondary_text)),
+        Span::styled(
+            format!("░ Gaps {}", format_hours(timeline.gap_minutes())),
+            Style::default().fg(app.theme.warning),
+        ),
+        Span::styled(" · ", Style::default().fg(app.theme.secondary_text)),
+        Span::styled(
+            format!("Overlaps {}", format_hours(timeline.overlap_minutes())),
+            Style::default().fg(app.theme.error),
+        ),
+    ]));
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(app.theme.inactive_border))
+            .title("🕒 Timeline")
+            .title_style(
+                Style::default()
+                    .fg(app.theme.info)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .padding(Padding::horizontal(1)),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+/// Columns a stretch covers, at least one so short records stay visible
+fn timeline_columns(timeline: &Timeline, start: u32, end: u32, width: u16) -> (usize, usize) {
+    let from = timeline.column(start, width).min(width.saturating_sub(1)) as usize;
+    let to = (timeline.column(end, width) as usize).max(from + 1);
+    (from, to.min(width as usize))
+}
+
+fn format_hours(minutes: u32) -> String {
+    format!("{}h {:02}m", minutes / 60, minutes % 60)
+}
+
+fn stats_block<'a>(title: &'a str, app: &AppState) -> Block<'a> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(app.theme.warning))
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(app.theme.warning)
+                .add_modifier(Modifier::BOLD),
+        )
+}
+
+fn render_stats(frame: &mut Frame, area: Rect, app: &AppState) {
+    use ratatui::widgets::{Bar, BarChart, BarGroup};
+
+    let Some(stats) = &app.stats else {
+        return;
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4),
+            Constraint::Min(8),
+            Constraint::Length(12),
+        ])
+        .split(area);
+
+    // Key figures
+    let longest = match stats.longest_streak {
+        (0, _) => "none".to_string(),
+        (days, Some(start)) => format!("{} days from {}", days, start),
+        (days, None) => format!("{} days", days),
+    };
+    let busiest: Vec<String> = stats
+        .busiest_weekdays()
+        .iter()
+        .take(3)
+        .map(|(day, _)| day.to_string()[..3].to_string())
+        .collect();
+    let figures = [
+        format!(
+            "Total {}  |  Average {} per day worked  |  {} of {} days worked",
+            format_hours(stats.total_minutes),
+            format_hours(stats.average_minutes()),
+            stats.days_worked,
+            app.stats_days,
+        ),
+        format!(
+            "Longest streak {}  |  Current streak {} days  |  Busiest days {}",
+            longest,
+            stats.current_streak,
+            if busiest.is_empty() {
+                "-".to_string()
+            } else {
+                busiest.join(", ")
+            },
+        ),
+    ];
+    let title = format!("📊 Statistics {} – {}", stats.from, stats.to);
+    let summary = Paragraph::new(figures.join("\n"))
+        .style(Style::default().fg(app.theme.primary_text))
+        .alignment(Alignment::Center)
+        .block(stats_block(&title, app));
+    frame.render_widget(summary, chunks[0]);
+
+    // Hours per day, one bar each; day numbers and values only show if bars are wide enough
+    let count = stats.daily.len().max(1) as u16;
+    let inner_width = chunks[1].width.saturating_sub(2);
+    let (bar_width, bar_gap) = if inner_width >= count * 2 {
+        ((inner_width + 1) / count - 1, 1)
+    } else {
+        (1, 0)
+    };
+    let bars: Vec<Bar> = stats
+        .daily
+        .iter()
+        .map(|(date, minutes)| {
+            let bar = Bar::default()
+                .value(*minutes as u64)
+                .text_value(format!("{:.1}", *minutes as f64 / 60.0));
+            match bar_width >= 2 {
+                true => bar.label(date.day().to_string().into()),
+                false => bar,
+            }
+        })
+        .collect();
+    let chart_title = format!("📈 Hours per day (last {} days)", stats.daily.len());
+    let chart = BarChart::default()
+        .block(stats_block(&chart_title, app))
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(bar_gap)
+        .bar_style(Style::default().fg(app.theme.info))
+        .value_style(
+            Style::default()
+                .fg(app.theme.highlight_text)
+                .bg(app.theme.info),
+        )
+        .label_style(Style::default().fg(app.theme.secondary_text));
+    frame.render_widget(chart, chunks[1]);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(30),
+            Constraint::Percentage(40),
+            Constraint::Percentage(30),
+        ])
+        .split(chunks[2]);
+
+    // Time per weekday
+    let weekday_bars: Vec<Bar> = stats
+        .weekdays
+        .iter()
+        .enumerate()
+        .map(|(index, minutes)| {
+            let day = time::Weekday::Monday.nth_next(index as u8);
+            Bar::default()
+                .value(*minutes as u64)
+                .label(day.to_string()[..3].to_string().into())
+                .text_value(format_hours(*minutes))
+        })
+        .collect();
+    let weekdays = BarChart::default()
+        .block(stats_block("📅 By weekday", app))
+        .direction(Direction::Horizontal)
+        .data(BarGroup::default().bars(&weekday_bars))
+        .bar_width(1)
+        .bar_gap(0)
+        .bar_style(Style::default().fg(app.theme.badge))
+        .value_style(
+            Style::default()
+                .fg(app.theme.highlight_text)
+                .bg(app.theme.badge),
+        )
+        .label_style(Style::default().fg(app.theme.secondary_text));
+    frame.render_widget(weekdays, columns[0]);
+
+    // Top tasks and time per tracker/ticket prefix
+    for (totals, column, title, header) in [
+        (&stats.top_tasks, columns[1], "🏆 Top tasks", "Task"),
+        (&stats.ticket_groups, columns[2], "🎫 By tracker", "Tracker"),
+    ] {
+        let rows: Vec<Row> = totals
+            .iter()
+            .map(|(name, minutes)| {
+                Row::new(vec![
+                    Cell::from(name.clone()),
+                    Cell::from(format_hours(*minutes)).style(
+                        Style::default()
+                            .fg(app.theme.badge)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                ])
+            })
+            .collect();
+        let table = Table::new(rows, [Constraint::Min(10), Constraint::Length(8)])
+            .header(
+                Row::new(vec![Cell::from(header), Cell::from("Total")]).style(
+                    Style::default()
+                        .fg(app.theme.warning)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            )
+            .block(stats_block(title, app));
+        frame.render_widget(table, column);
+    }
+}
+
 fn render_footer(frame: &mut Frame, area: Rect, app: &AppState) {
     // Build help text for Browse mode conditionally
     let browse_help = if app.config.has_integrations() {
-        "↑/↓: Row | ←/→: Field | [/]: Day | C: Calendar | Enter: Edit | c: Change | n: New | b: Break | d: Delete | v: Visual | t: Now | T: Ticket | L: Worklog | S: Session Start/Stop | P: Pause | ?: Help | q: Quit"
+        "↑/↓: Row | gg/G: First/last | ←/→: Field | [/]: Day | C: Calendar | Enter: Edit | c: Change | n: New | I: From issue | a: Quick add | b: Break | dd: Delete | .: Repeat | v: Visual | m: Move to date | x: Split | J: Join next | y/p: Yank/Paste | Y: Copy previous day | +/-: Nudge time | *: Nudge step | t: Now | T: Ticket | L: Worklog | S: Session Start/Stop | P: Pause | o: Sort | w: Timeline | f: Filter | /: Search | D: Stats | :: Command | ?: Help | q: Quit"
     } else {
-        "↑/↓: Row | ←/→: Field | [/]: Day | C: Calendar | Enter: Edit | c: Change | n: New | b: Break | d: Delete | v: Visual | t: Now | S: Session Start/Stop | P: Pause | ?: Help | q: Quit"
+        "↑/↓: Row | gg/G: First/last | ←/→: Field | [/]: Day | C: Calendar | Enter: Edit | c: Change | n: New | I: From issue | a: Quick add | b: Break | dd: Delete | .: Repeat | v: Visual | m: Move to date | x: Split | J: Join next | y/p: Yank/Paste | Y: Copy previous day | +/-: Nudge time | *: Nudge step | t: Now | S: Session Start/Stop | P: Pause | o: Sort | w: Timeline | f: Filter | /: Search | D: Stats | :: Command | ?: Help | q: Quit"
     };
 
+    let command_line = format!(":{}▏", app.command_line);
     let (help_text, mode_color, mode_label) = match app.mode {
         crate::ui::AppMode::Browse => (browse_help, app.theme.info, "BROWSE"),
+        crate::ui::AppMode::CommandLine => (command_line.as_str(), app.theme.info, "COMMAND"),
         crate::ui::AppMode::Edit => (
             "Tab: Next field | Enter: Save | Esc: Cancel",
             app.theme.warning,
             "EDIT",
         ),
         crate::ui::AppMode::Visual => (
-            "↑/↓: Extend selection | d: Delete | Esc: Exit visual",
+            "↑/↓: Extend selection | c: Rename | e: Description | s: Shift time | J: Merge | y: Yank | m: Move to date | d: Delete | Esc: Exit visual",
             app.theme.badge,
             "VISUAL",
         ),
@@ -590,21 +1059,55 @@ fn render_footer(frame: &mut Frame, area: Rect, app: &AppState) {
             "CALENDAR",
         ),
         crate::ui::AppMode::TaskPicker => (
-            "Type: Filter/Create | ↑/↓: Navigate | Enter: Select | Esc: Cancel",
+            "Type: Filter/Create | ↑/↓: Navigate | Enter: Select | Ctrl+T: Start session | Ctrl+R: Fetch issues | Esc: Cancel",
             app.theme.info,
             "TASK PICKER",
         ),
+        crate::ui::AppMode::QuickAdd => (
+            "Type: Entry | Enter: Add | Esc: Cancel",
+            app.theme.success,
+            "QUICK ADD",
+        ),
+        crate::ui::AppMode::Filter => (
+            "Type: Filter (text, >1h, <30m, ticket:PROJ, #tag) | ↑/↓: Row | Enter: Keep | Esc: Clear",
+            app.theme.warning,
+            "FILTER",
+        ),
+        crate::ui::AppMode::Search => (
+            "Type: Search | ↑/↓: Navigate | Enter: Go to record | Esc: Cancel",
+            app.theme.info,
+            "SEARCH",
+        ),
+        crate::ui::AppMode::Split => (
+            "Type: HH:MM [name of second part] | Enter: Split | Esc: Cancel",
+            app.theme.badge,
+            "SPLIT",
+        ),
+        crate::ui::AppMode::BulkEdit => (
+            "Type: Value | Enter: Apply to all | Esc: Cancel",
+            app.theme.badge,
+            "BULK EDIT",
+        ),
+        crate::ui::AppMode::Stats => (
+            "[/]: Shorter/longer range | Esc/q: Back",
+            app.theme.warning,
+            "STATS",
+        ),
     };
 
+    let (text_color, alignment) = match app.mode {
+        crate::ui::AppMode::CommandLine => (app.theme.primary_text, Alignment::Left),
+        _ => (app.theme.secondary_text, Alignment::Center),
+    };
     let footer = Paragraph::new(help_text)
-        .style(Style::default().fg(app.theme.secondary_text))
-        .alignment(Alignment::Center)
+        .style(Style::default().fg(text_color))
+        .alignment(alignment)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
                 .border_style(Style::default().fg(mode_color))
-                .title(format!("⌨  {} MODE", mode_label))
+                .title(footer_title(app, mode_label))
                 .title_style(Style::default().fg(mode_color).add_modifier(Modifier::BOLD))
                 .padding(Padding::horizontal(1)),
         );
@@ -612,6 +1115,32 @@ fn render_footer(frame: &mut Frame, area: Rect, app: &AppState) {
     frame.render_widget(footer, area);
 }
 
+fn footer_title(app: &AppState, mode_label: &str) -> String {
+    let mut title = format!("⌨  {} MODE", mode_label);
+    if matches!(app.mode, crate::ui::AppMode::Browse)
+        && matches!(
+            app.edit_field,
+            crate::ui::EditField::Start | crate::ui::EditField::End
+        )
+    {
+        title.push_str(&format!(" · +/- {}m", app.nudge_step));
+    }
+    if app.yanked_count() > 0 {
+        title.push_str(&format!(" · {} yanked", app.yanked_count()));
+    }
+    if app.loading_assigned_issues {
+        title.push_str(" · loading issues…");
+    }
+    if app.fetching_title.is_some() {
+        title.push_str(" · fetching title…");
+    }
+    let pending = app.pending_keys();
+    if !pending.is_empty() {
+        title.push_str(&format!(" · {}", pending));
+    }
+    title
+}
+
 fn render_command_palette(frame: &mut Frame, app: &AppState) {
     use ratatui::widgets::Clear;
 
@@ -788,10 +1317,21 @@ fn render_calendar(frame: &mut Frame, app: &AppState) {
         Month::December => "December",
     };
 
-    let header_text = format!(
-        "📅  {} {}  [< prev] [next >]",
-        month_name, app.calendar_view_year
-    );
+    let header_text = match &app.calendar_target {
+        CalendarTarget::MoveRecords(ids) => format!(
+            "📅  Move {} to: {} {}  [< prev] [next >]",
+            match ids.len() {
+                1 => "1 record".to_string(),
+                n => format!("{} records", n),
+            },
+            month_name,
+            app.calendar_view_year
+        ),
+        CalendarTarget::Navigate => format!(
+            "📅  {} {}  [< prev] [next >]",
+            month_name, app.calendar_view_year
+        ),
+    };
     let header = Paragraph::new(header_text)
         .style(
             Style::default()
@@ -1023,6 +1563,152 @@ fn is_leap_year_render(year: i32) -> bool {
     (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
 }
 
+fn render_quick_add(frame: &mut Frame, app: &AppState) {
+    use ratatui::text::Line;
+    use ratatui::widgets::Clear;
+
+    // Create a centered modal
+    let area = frame.size();
+    let width = area.width.min(80);
+    let height = 6;
+    let x = (area.width.saturating_sub(width)) / 2;
+    let y = (area.height.saturating_sub(height)) / 2;
+
+    let modal_area = Rect {
+        x,
+        y,
+        width,
+        height,
+    };
+
+    // Clear the background
+    frame.render_widget(Clear, modal_area);
+
+    let (input_text, input_style) = if app.input_buffer.is_empty() {
+        (
+            "yesterday 2pm-3:30pm PROJ-42 code review".to_string(),
+            Style::default().fg(app.theme.secondary_text),
+        )
+    } else {
+        (
+            format!("{}▏", app.input_buffer),
+            Style::default().fg(app.theme.primary_text),
+        )
+    };
+
+    let lines = vec![
+        Line::from(input_text).style(input_style),
+        Line::from(""),
+        Line::from("Range (9-10:30am), duration (1h30m ending now), date (yesterday, monday)")
+            .style(Style::default().fg(app.theme.secondary_text)),
+    ];
+
+    let prompt = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(app.theme.active_border))
+            .title("➕ Quick Add")
+            .title_style(
+                Style::default()
+                    .fg(app.theme.active_border)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(app.theme.edit_bg)),
+    );
+
+    frame.render_widget(prompt, modal_area);
+}
+
+fn render_bulk_edit(frame: &mut Frame, app: &AppState) {
+    use crate::ui::app_state::BulkEditField;
+
+    let (title, placeholder, hint) = match app.bulk_edit_field {
+        BulkEditField::Description => (
+            format!("📝 Description of {} records", app.bulk_edit_count()),
+            "Description",
+            "Replaces the description of every selected record (empty clears it)",
+        ),
+        BulkEditField::Shift => (
+            format!("⇅ Shift {} records", app.bulk_edit_count()),
+            "+15",
+            "Minutes later (+15, 1h) or earlier (-30m), keeping the durations",
+        ),
+    };
+    render_prompt(frame, app, &title, placeholder, hint);
+}
+
+fn render_split(frame: &mut Frame, app: &AppState) {
+    let Some(record) = app.get_selected_record() else {
+        return;
+    };
+    let title = format!(
+        "✂ Split {} ({} - {})",
+        record.name, record.start, record.end
+    );
+    render_prompt(
+        frame,
+        app,
+        &title,
+        "HH:MM",
+        "Time to split at, optionally followed by a new name for the second part",
+    );
+}
+
+/// One-line input modal with a hint below it
+fn render_prompt(frame: &mut Frame, app: &AppState, title: &str, placeholder: &str, hint: &str) {
+    use ratatui::text::Line;
+    use ratatui::widgets::Clear;
+
+    let area = frame.size();
+    let width = area.width.min(80);
+    let height = 6;
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(width)) / 2,
+        y: (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, modal_area);
+
+    let (input_text, input_style) = if app.input_buffer.is_empty() {
+        (
+            placeholder.to_string(),
+            Style::default().fg(app.theme.secondary_text),
+        )
+    } else {
+        (
+            format!("{}▏", app.input_buffer),
+            Style::default().fg(app.theme.primary_text),
+        )
+    };
+
+    let lines = vec![
+        Line::from(input_text).style(input_style),
+        Line::from(""),
+        Line::from(hint).style(Style::default().fg(app.theme.secondary_text)),
+    ];
+
+    let prompt = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(app.theme.badge))
+            .title(title.to_string())
+            .title_style(
+                Style::default()
+                    .fg(app.theme.badge)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .padding(Padding::horizontal(1))
+            .style(Style::default().bg(app.theme.edit_bg)),
+    );
+
+    frame.render_widget(prompt, modal_area);
+}
+
 fn render_error_modal(frame: &mut Frame, app: &AppState) {
     use ratatui::text::Line;
     use ratatui::widgets::Clear;
@@ -1092,11 +1778,140 @@ fn render_error_modal(frame: &mut Frame, app: &AppState) {
     frame.render_widget(help, chunks[1]);
 }
 
+fn render_search(frame: &mut Frame, app: &AppState) {
+    use ratatui::widgets::Clear;
+
+    let area = frame.size();
+    let width = area.width.saturating_sub(4).min(100);
+    let height = area.height.saturating_sub(4).min(24);
+    let modal_area = Rect {
+        x: (area.width.saturating_sub(width)) / 2,
+        y: (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, modal_area);
+    let bg_block = Block::default().style(Style::default().bg(app.theme.selected_inactive_bg));
+    frame.render_widget(bg_block, modal_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(3)])
+        .split(modal_area);
+
+    let (input_display, input_style) = if app.input_buffer.is_empty() {
+        (
+            "Task name, description or ticket (e.g. PROJ-88)".to_string(),
+            Style::default().fg(app.theme.secondary_text),
+        )
+    } else {
+        (
+            app.input_buffer.clone(),
+            Style::default()
+                .fg(app.theme.primary_text)
+                .add_modifier(Modifier::BOLD),
+        )
+    };
+    let input = Paragraph::new(input_display).style(input_style).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(app.theme.warning))
+            .title("🔍 Search all days")
+            .title_style(
+                Style::default()
+                    .fg(app.theme.warning)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .style(Style::default().bg(app.theme.selected_inactive_bg))
+            .padding(Padding::horizontal(1)),
+    );
+    frame.render_widget(input, chunks[0]);
+
+    let results_block = |title: String| {
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(app.theme.info))
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(app.theme.info)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .style(Style::default().bg(app.theme.selected_inactive_bg))
+    };
+
+    if app.search_results.is_empty() {
+        let message = if app.input_buffer.trim().is_empty() {
+            "Type to search"
+        } else {
+            "No matching records"
+        };
+        let empty = Paragraph::new(message)
+            .style(Style::default().fg(app.theme.secondary_text))
+            .alignment(Alignment::Center)
+            .block(results_block("Results".to_string()));
+        frame.render_widget(empty, chunks[1]);
+        return;
+    }
+
+    let rows: Vec<Row> = app
+        .search_results
+        .iter()
+        .map(|hit| {
+            let record = &hit.record;
+            let name = if record.description.is_empty() {
+                record.name.clone()
+            } else {
+                format!("{} — {}", record.name, record.description)
+            };
+            Row::new(vec![
+                Cell::from(hit.date.to_string()).style(Style::default().fg(app.theme.info)),
+                Cell::from(format!("{}-{}", record.start, record.end)),
+                Cell::from(record.format_duration()).style(Style::default().fg(app.theme.badge)),
+                Cell::from(name).style(Style::default().fg(app.theme.primary_text)),
+            ])
+        })
+        .collect();
+
+    let total: u32 = app
+        .search_results
+        .iter()
+        .map(|hit| hit.record.total_minutes)
+        .sum();
+    let title = format!(
+        "Results ({}, {}h {:02}m)",
+        app.search_results.len(),
+        total / 60,
+        total % 60
+    );
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(11),
+            Constraint::Length(7),
+            Constraint::Min(10),
+        ],
+    )
+    .highlight_style(
+        Style::default()
+            .bg(app.theme.selected_bg)
+            .add_modifier(Modifier::BOLD),
+    )
+    .block(results_block(title));
+
+    let mut table_state = TableState::default().with_selected(Some(app.search_selected));
+    frame.render_stateful_widget(table, chunks[1], &mut table_state);
+}
+
 fn render_task_picker(frame: &mut Frame, app: &AppState) {
     use ratatui::widgets::Clear;
 
     let filtered_tasks = app.get_filtered_task_names();
-    let all_tasks = app.get_unique_task_names();
+    let all_tasks = app.get_task_picker_names();
 
     // Create a smaller centered modal (mini-picker style)
     let area = frame.size();
@@ -1130,10 +1945,20 @@ fn render_task_picker(frame: &mut Frame, app: &AppState) {
         .split(modal_area);
 
     // Render header with help text
-    let header_text = if app.input_buffer.is_empty() {
-        "Select existing task or type new name"
-    } else {
-        "Type to filter, or create new task"
+    let header_text = match &app.task_picker_target {
+        _ if app.loading_assigned_issues => "Loading assigned issues…".to_string(),
+        crate::ui::app_state::TaskPickerTarget::NewRecord => {
+            "Pick a task or assigned issue for a new record".to_string()
+        }
+        crate::ui::app_state::TaskPickerTarget::RenameRecords(ids) => {
+            format!("Pick or type a new name for {} records", ids.len())
+        }
+        crate::ui::app_state::TaskPickerTarget::Rename if app.input_buffer.is_empty() => {
+            "Select existing task or type new name".to_string()
+        }
+        crate::ui::app_state::TaskPickerTarget::Rename => {
+            "Type to filter, or create new task".to_string()
+        }
     };
 
     let header = Paragraph::new(header_text)
@@ -1236,7 +2061,9 @@ fn render_task_picker(frame: &mut Frame, app: &AppState) {
                 };
 
                 // Add icon based on task type
-                let icon = if name.to_lowercase().contains("break") {
+                let icon = if app.is_assigned_issue(name) {
+                    "🎫"
+                } else if name.to_lowercase().contains("break") {
                     "☕"
                 } else if name.to_lowercase().contains("meeting") {
                     "👥"
@@ -0,0 +1,276 @@
+//! Statistics over a range of days, for the stats dashboard
+
+use crate::config::Config;
+use crate::integrations::{TicketRef, extract_tickets};
+use crate::models::DayData;
+use std::collections::HashMap;
+use time::{Date, Duration, Weekday};
+
+/// Range lengths the dashboard steps through with `[` and `]`
+pub const RANGE_PRESETS: [u32; 5] = [7, 30, 90, 180, 365];
+
+/// Days shown in the daily chart
+pub const CHART_DAYS: u32 = 30;
+
+/// How many tasks and ticket groups are listed
+const TOP_COUNT: usize = 10;
+
+/// Group of records without a ticket in the per-tracker totals
+pub const NO_TICKET: &str = "No ticket";
+
+/// Aggregates of the records between `from` and `to` (inclusive)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub from: Date,
+    pub to: Date,
+    pub total_minutes: u32,
+    /// Days with at least one record
+    pub days_worked: u32,
+    /// Total minutes per weekday, Monday first
+    pub weekdays: [u32; 7],
+    /// Days worked per weekday, Monday first
+    pub weekday_counts: [u32; 7],
+    /// Longest run of consecutive days worked, with its first day
+    pub longest_streak: (u32, Option<Date>),
+    /// Run of days worked ending at `to` (or the day before, if `to` has no records yet)
+    pub current_streak: u32,
+    /// Most-tracked task names with their minutes, busiest first
+    pub top_tasks: Vec<(String, u32)>,
+    /// Minutes per tracker or ticket prefix, busiest first
+    pub ticket_groups: Vec<(String, u32)>,
+    /// Minutes on each of the last `CHART_DAYS` days up to `to`, oldest first
+    pub daily: Vec<(Date, u32)>,
+}
+
+impl Stats {
+    /// Compute statistics for `days` (in any order, outside days are ignored)
+    pub fn compute(days: &[DayData], from: Date, to: Date, config: &Config) -> Self {
+        let mut per_day: HashMap<Date, u32> = HashMap::new();
+        let mut tasks: HashMap<String, u32> = HashMap::new();
+        let mut groups: HashMap<String, u32> = HashMap::new();
+
+        for day in days.iter().filter(|d| d.date >= from && d.date <= to) {
+            for record in day.work_records.values() {
+                *per_day.entry(day.date).or_insert(0) += record.total_minutes;
+                *tasks.entry(record.name.clone()).or_insert(0) += record.total_minutes;
+                let group = match extract_tickets(&record.name, config).first() {
+                    Some(ticket) => ticket_group(ticket),
+                    None => NO_TICKET.to_string(),
+                };
+                *groups.entry(group).or_insert(0) += record.total_minutes;
+            }
+        }
+
+        let mut weekdays = [0; 7];
+        let mut weekday_counts = [0; 7];
+        for (date, minutes) in &per_day {
+            let index = date.weekday().number_days_from_monday() as usize;
+            weekdays[index] += minutes;
+            weekday_counts[index] += 1;
+        }
+
+        let chart_start = (to - Duration::days(CHART_DAYS as i64 - 1)).max(from);
+        let daily = dates(chart_start, to)
+            .map(|date| (date, per_day.get(&date).copied().unwrap_or(0)))
+            .collect();
+
+        let current_streak = {
+            let worked = |date: &Date| per_day.contains_key(date);
+            let end = match worked(&to) {
+                true => Some(to),
+                false => to.previous_day(),
+            };
+            std::iter::successors(end, |date| date.previous_day())
+                .take_while(|date| *date >= from && worked(date))
+                .count() as u32
+        };
+
+        Stats {
+            from,
+            to,
+            total_minutes: per_day.values().sum(),
+            days_worked: per_day.len() as u32,
+            weekdays,
+            weekday_counts,
+            longest_streak: longest_streak(&per_day, from, to),
+            current_streak,
+            top_tasks: busiest(tasks),
+            ticket_groups: busiest(groups),
+            daily,
+        }
+    }
+
+    /// Average minutes on days worked
+    pub fn average_minutes(&self) -> u32 {
+        self.total_minutes
+            .checked_div(self.days_worked)
+            .unwrap_or_default()
+    }
+
+    /// Weekdays ordered by total time, busiest first, skipping days never worked
+    pub fn busiest_weekdays(&self) -> Vec<(Weekday, u32)> {
+        let mut weekdays: Vec<(Weekday, u32)> = (0..7)
+            .filter(|&i| self.weekdays[i] > 0)
+            .map(|i| (Weekday::Monday.nth_next(i as u8), self.weekdays[i]))
+            .collect();
+        weekdays
+            .sort_by_key(|&(day, minutes)| (std::cmp::Reverse(minutes), day.number_from_monday()));
+        weekdays
+    }
+}
+
+/// Group a ticket by its key prefix (`PROJ` for `PROJ-123`), or by its tracker for
+/// tickets without one (`#45`)
+fn ticket_group(ticket: &TicketRef) -> String {
+    match ticket.ticket.rsplit_once('-') {
+        Some((prefix, number))
+            if !prefix.is_empty() && number.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            prefix.to_string()
+        }
+        _ => ticket
+            .tracker
+            .clone()
+            .unwrap_or_else(|| ticket.ticket.clone()),
+    }
+}
+
+fn dates(from: Date, to: Date) -> impl Iterator<Item = Date> {
+    std::iter::successors(Some(from), |date| date.next_day()).take_while(move |date| *date <= to)
+}
+
+fn longest_streak(per_day: &HashMap<Date, u32>, from: Date, to: Date) -> (u32, Option<Date>) {
+    let mut best = (0, None);
+    let mut run: (u32, Option<Date>) = (0, None);
+    for date in dates(from, to) {
+        if per_day.contains_key(&date) {
+            run = (run.0 + 1, run.1.or(Some(date)));
+            if run.0 > best.0 {
+                best = run;
+            }
+        } else {
+            run = (0, None);
+        }
+    }
+    best
+}
+
+/// Sort totals by minutes (descending) then name, keeping the top `TOP_COUNT`
+fn busiest(totals: HashMap<String, u32>) -> Vec<(String, u32)> {
+    let mut totals: Vec<(String, u32)> = totals.into_iter().collect();
+    totals.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    totals.truncate(TOP_COUNT);
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{TimePoint, WorkRecord};
+    use time::macros::date;
+
+    fn day(date: Date, records: &[(&str, u8, u8)]) -> DayData {
+        let mut day = DayData::new(date);
+        for (name, start, end) in records {
+            let id = day.next_id();
+            day.add_record(WorkRecord::new(
+                id,
+                name.to_string(),
+                TimePoint::new(*start, 0).unwrap(),
+                TimePoint::new(*end, 0).unwrap(),
+            ));
+        }
+        day
+    }
+
+    fn sample() -> Stats {
+        // Thu 1st to Wed 14th; worked Thu-Fri, Mon-Wed (streak of 3), Sat 10th and Wed 14th
+        let days = vec![
+            day(date!(2026 - 10 - 01), &[("PROJ-1 Login", 9, 12)]),
+            day(date!(2026 - 10 - 02), &[("PROJ-1 Login", 9, 10)]),
+            day(date!(2026 - 10 - 05), &[("PROJ-2 Search", 9, 11)]),
+            day(
+                date!(2026 - 10 - 06),
+                &[("Standup", 9, 10), ("OPS-7 Deploy", 10, 14)],
+            ),
+            day(date!(2026 - 10 - 07), &[("Standup", 9, 10)]),
+            day(date!(2026 - 10 - 10), &[("#45 Docs", 9, 10)]),
+            day(date!(2026 - 10 - 14), &[("Standup", 9, 10)]),
+            // Outside the range
+            day(date!(2026 - 09 - 30), &[("Standup", 9, 17)]),
+        ];
+        let config: Config = toml::from_str(
+            r#"
+            [integrations.trackers.github]
+            enabled = true
+            ticket_patterns = ["^#\\d+$"]
+            "#,
+        )
+        .unwrap();
+        Stats::compute(&days, date!(2026 - 10 - 01), date!(2026 - 10 - 14), &config)
+    }
+
+    #[test]
+    fn test_totals_and_average() {
+        let stats = sample();
+        assert_eq!(stats.total_minutes, 14 * 60);
+        assert_eq!(stats.days_worked, 7);
+        assert_eq!(stats.average_minutes(), 120);
+    }
+
+    #[test]
+    fn test_weekdays_and_streaks() {
+        let stats = sample();
+        // Mon 2h, Tue 5h, Wed 2h (two days), Thu 3h, Fri 1h, Sat 1h
+        assert_eq!(stats.weekdays, [120, 300, 120, 180, 60, 60, 0]);
+        assert_eq!(stats.weekday_counts[2], 2);
+        assert_eq!(
+            stats.busiest_weekdays()[..3],
+            [
+                (Weekday::Tuesday, 300),
+                (Weekday::Thursday, 180),
+                (Weekday::Monday, 120)
+            ]
+        );
+
+        assert_eq!(stats.longest_streak, (3, Some(date!(2026 - 10 - 05))));
+        assert_eq!(stats.current_streak, 1);
+    }
+
+    #[test]
+    fn test_top_tasks_and_ticket_groups() {
+        let stats = sample();
+        assert_eq!(stats.top_tasks[0], ("OPS-7 Deploy".to_string(), 240));
+        assert_eq!(stats.top_tasks[1], ("PROJ-1 Login".to_string(), 240));
+        assert_eq!(stats.top_tasks[2], ("Standup".to_string(), 180));
+
+        assert_eq!(
+            stats.ticket_groups,
+            vec![
+                ("PROJ".to_string(), 360),
+                ("OPS".to_string(), 240),
+                (NO_TICKET.to_string(), 180),
+                ("github".to_string(), 60),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_daily_covers_range_up_to_chart_days() {
+        let stats = sample();
+        assert_eq!(stats.daily.len(), 14);
+        assert_eq!(stats.daily[0], (date!(2026 - 10 - 01), 180));
+        assert_eq!(stats.daily[2], (date!(2026 - 10 - 03), 0));
+
+        let long = Stats::compute(
+            &[],
+            date!(2026 - 01 - 01),
+            date!(2026 - 10 - 14),
+            &Config::default(),
+        );
+        assert_eq!(long.daily.len(), CHART_DAYS as usize);
+        assert_eq!(long.daily.last().unwrap().0, date!(2026 - 10 - 14));
+        assert_eq!(long.longest_streak, (0, None));
+        assert_eq!(long.average_minutes(), 0);
+    }
+}
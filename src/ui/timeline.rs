@@ -0,0 +1,211 @@
+//! Layout of the timeline pane: the day's records as bars on a time axis
+
+use crate::config::{TimelineAxis, TimelineConfig};
+use crate::models::WorkRecord;
+
+const DAY_MINUTES: u32 = 24 * 60;
+
+/// A stretch of the day in minutes since midnight, end exclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bar {
+    /// Record drawn, `None` for the running timer
+    pub id: Option<u32>,
+    pub start: u32,
+    pub end: u32,
+}
+
+impl Bar {
+    /// A record's bar, cut at midnight for records running into the next day
+    pub fn for_record(record: &WorkRecord) -> Self {
+        let start = record.start.to_minutes_since_midnight();
+        Bar {
+            id: Some(record.id),
+            start,
+            end: (start + record.total_minutes).min(DAY_MINUTES),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeline {
+    /// Start of the axis, on a whole hour
+    pub from: u32,
+    /// End of the axis, on a whole hour
+    pub to: u32,
+    /// Bars by start, split into lanes so bars in one lane don't overlap
+    pub lanes: Vec<Vec<Bar>>,
+    /// Unlogged stretches between the first and the last bar
+    pub gaps: Vec<(u32, u32)>,
+    /// Stretches covered by more than one bar
+    pub overlaps: Vec<(u32, u32)>,
+}
+
+impl Timeline {
+    pub fn build(mut bars: Vec<Bar>, config: &TimelineConfig) -> Self {
+        bars.sort_by_key(|bar| (bar.start, bar.end));
+
+        let mut lanes: Vec<Vec<Bar>> = Vec::new();
+        for bar in &bars {
+            match lanes
+                .iter_mut()
+                .find(|lane| lane.last().is_some_and(|last| last.end <= bar.start))
+            {
+                Some(lane) => lane.push(*bar),
+                None => lanes.push(vec![*bar]),
+            }
+        }
+
+        let mut coverage = vec![0u8; DAY_MINUTES as usize];
+        for bar in &bars {
+            for count in &mut coverage[bar.start as usize..bar.end as usize] {
+                *count = count.saturating_add(1);
+            }
+        }
+        let first = bars.iter().map(|bar| bar.start).min();
+        let last = bars.iter().map(|bar| bar.end).max();
+        let (gaps, overlaps) = match (first, last) {
+            (Some(first), Some(last)) => (
+                stretches(&coverage, first, last, |count| count == 0),
+                stretches(&coverage, first, last, |count| count > 1),
+            ),
+            _ => (Vec::new(), Vec::new()),
+        };
+
+        let (from, to) = match config.axis {
+            TimelineAxis::Full => (0, DAY_MINUTES),
+            TimelineAxis::Working => {
+                let from = (config.day_start * 60).min(first.unwrap_or(DAY_MINUTES) / 60 * 60);
+                let to = (config.day_end * 60)
+                    .max(last.unwrap_or(0).div_ceil(60) * 60)
+                    .min(DAY_MINUTES);
+                if from < to {
+                    (from, to)
+                } else {
+                    (0, DAY_MINUTES)
+                }
+            }
+        };
+
+        Timeline {
+            from,
+            to,
+            lanes,
+            gaps,
+            overlaps,
+        }
+    }
+
+    /// Column of a minute on an axis `width` columns wide
+    pub fn column(&self, minute: u32, width: u16) -> u16 {
+        let minute = minute.clamp(self.from, self.to);
+        ((minute - self.from) * u32::from(width) / (self.to - self.from)) as u16
+    }
+
+    pub fn gap_minutes(&self) -> u32 {
+        self.gaps.iter().map(|(start, end)| end - start).sum()
+    }
+
+    pub fn overlap_minutes(&self) -> u32 {
+        self.overlaps.iter().map(|(start, end)| end - start).sum()
+    }
+}
+
+/// Maximal runs between `from` and `to` whose coverage matches
+fn stretches(coverage: &[u8], from: u32, to: u32, matches: impl Fn(u8) -> bool) -> Vec<(u32, u32)> {
+    let mut runs = Vec::new();
+    let mut run_start = None;
+    for minute in from..to {
+        match (matches(coverage[minute as usize]), run_start) {
+            (true, None) => run_start = Some(minute),
+            (false, Some(start)) => {
+                runs.push((start, minute));
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = run_start {
+        runs.push((start, to));
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(id: u32, start: u32, end: u32) -> Bar {
+        Bar {
+            id: Some(id),
+            start,
+            end,
+        }
+    }
+
+    #[test]
+    fn test_lanes_gaps_and_overlaps() {
+        let timeline = Timeline::build(
+            vec![
+                bar(3, 13 * 60, 14 * 60),
+                bar(1, 9 * 60, 10 * 60),
+                bar(2, 9 * 60 + 30, 11 * 60),
+                bar(4, 14 * 60, 15 * 60),
+            ],
+            &TimelineConfig::default(),
+        );
+
+        let ids = |lane: &Vec<Bar>| lane.iter().map(|b| b.id.unwrap()).collect::<Vec<_>>();
+        assert_eq!(
+            timeline.lanes.iter().map(ids).collect::<Vec<_>>(),
+            vec![vec![1, 3, 4], vec![2]]
+        );
+        assert_eq!(timeline.gaps, vec![(11 * 60, 13 * 60)]);
+        assert_eq!(timeline.overlaps, vec![(9 * 60 + 30, 10 * 60)]);
+        assert_eq!(timeline.gap_minutes(), 120);
+        assert_eq!(timeline.overlap_minutes(), 30);
+    }
+
+    #[test]
+    fn test_working_axis_widens_to_records() {
+        let config = TimelineConfig::default();
+        let timeline = Timeline::build(vec![bar(1, 9 * 60, 12 * 60)], &config);
+        assert_eq!((timeline.from, timeline.to), (8 * 60, 18 * 60));
+
+        let timeline = Timeline::build(
+            vec![bar(1, 6 * 60 + 45, 7 * 60), bar(2, 19 * 60, 19 * 60 + 10)],
+            &config,
+        );
+        assert_eq!((timeline.from, timeline.to), (6 * 60, 20 * 60));
+
+        let timeline = Timeline::build(Vec::new(), &config);
+        assert_eq!((timeline.from, timeline.to), (8 * 60, 18 * 60));
+        assert!(timeline.lanes.is_empty() && timeline.gaps.is_empty());
+
+        let full = TimelineConfig {
+            axis: TimelineAxis::Full,
+            ..config
+        };
+        let timeline = Timeline::build(vec![bar(1, 9 * 60, 12 * 60)], &full);
+        assert_eq!((timeline.from, timeline.to), (0, DAY_MINUTES));
+    }
+
+    #[test]
+    fn test_column() {
+        let timeline = Timeline::build(Vec::new(), &TimelineConfig::default());
+        assert_eq!(timeline.column(8 * 60, 100), 0);
+        assert_eq!(timeline.column(13 * 60, 100), 50);
+        assert_eq!(timeline.column(18 * 60, 100), 100);
+        assert_eq!(timeline.column(23 * 60, 100), 100);
+    }
+
+    #[test]
+    fn test_record_bar_stops_at_midnight() {
+        let record = WorkRecord::new(
+            1,
+            "Deploy".to_string(),
+            crate::models::TimePoint::new(23, 0).unwrap(),
+            crate::models::TimePoint::new(1, 0).unwrap(),
+        );
+        assert_eq!(Bar::for_record(&record), bar(1, 23 * 60, DAY_MINUTES));
+    }
+}